@@ -0,0 +1,362 @@
+use std::convert::TryInto;
+use std::collections::{BTreeMap, BTreeSet};
+use bbs::rust_bbs::{
+  rust_bls_generate_g2_key,
+  rust_bls_public_key_to_bbs_key,
+  rust_bls_public_key_cache_clear,
+  rust_bbs_sign,
+  rust_bbs_verify,
+  rust_bbs_create_proof,
+  rust_bbs_verify_proof,
+  rust_bbs_blind_signature_commitment,
+  rust_bbs_blind_sign,
+  rust_bbs_unblind_signature,
+};
+use bbs_plus::prelude::*;
+use bbs_plus::{pm_hidden_raw, pm_revealed_raw, FR_COMPRESSED_SIZE, G1_COMPRESSED_SIZE};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use serde_json::{Value, json};
+
+const SEED: &str = "H297BpoOgkfpXcxr1fJyQRiNx1+ZekeQ+OU/AYV/lVxaPXXhFBIbxeIU8kIAAX68cwQ=";
+const NONCE: &str = "bm9uY2U=";
+const MESSAGE_COUNTS: [usize; 4] = [1, 10, 100, 1000];
+
+/// Keys, messages and signature for a given message count, in both native and JSON form.
+/// Every "crypto" bench calls the `bbs` crate directly, whilst every "wrapper" bench goes
+/// through the JSON context & base64 encoding used by the C, Node and Java wrappers.
+struct Fixture {
+  message_count: usize,
+  dpk: DeterministicPublicKey,
+  secret_key: SecretKey,
+  public_key: PublicKey,
+  messages: Vec<SignatureMessage>,
+  signature: Signature,
+  bls_public_key_b64: String,
+  secret_key_b64: String,
+  public_key_b64: String,
+  messages_b64: Vec<String>,
+  signature_b64: String,
+}
+
+impl Fixture {
+  fn new(message_count: usize) -> Self {
+    let key = parse(rust_bls_generate_g2_key(json!({ "seed": SEED })).unwrap());
+    let bls_public_key_b64 = key["public_key"].as_str().unwrap().to_string();
+    let secret_key_b64 = key["secret_key"].as_str().unwrap().to_string();
+
+    let dpk_bytes = base64::decode(&bls_public_key_b64).unwrap();
+    let dpk = DeterministicPublicKey::from(*array_ref::<DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE>(&dpk_bytes));
+    let secret_key = SecretKey::from(*array_ref::<FR_COMPRESSED_SIZE>(&base64::decode(&secret_key_b64).unwrap()));
+    let public_key = dpk.to_public_key(message_count).unwrap();
+
+    let messages_b64: Vec<String> = (0..message_count)
+      .map(|i| base64::encode(format!("message{}", i)))
+      .collect();
+    let messages: Vec<SignatureMessage> = messages_b64
+      .iter()
+      .map(|m| SignatureMessage::hash(base64::decode(m).unwrap()))
+      .collect();
+
+    let signature = Signature::new(messages.as_slice(), &secret_key, &public_key).unwrap();
+
+    Self {
+      message_count,
+      dpk,
+      secret_key,
+      public_key_b64: base64::encode(public_key.to_bytes_compressed_form()),
+      public_key,
+      messages,
+      signature_b64: base64::encode(signature.to_bytes_compressed_form()),
+      signature,
+      bls_public_key_b64,
+      secret_key_b64,
+      messages_b64,
+    }
+  }
+
+  /// Reveal every other message
+  fn revealed(&self) -> BTreeSet<usize> {
+    (0..self.message_count).step_by(2).collect()
+  }
+
+  fn create_proof_context(&self) -> Value {
+    json!({
+      "signature": self.signature_b64,
+      "public_key": self.public_key_b64,
+      "messages": self.messages_b64,
+      "revealed": self.revealed().into_iter().collect::<Vec<usize>>(),
+      "nonce": NONCE,
+    })
+  }
+
+  fn verify_proof_context(&self) -> Value {
+    let proof = parse(rust_bbs_create_proof(self.create_proof_context()).unwrap());
+    let revealed_messages: Vec<&String> = self.revealed()
+      .into_iter()
+      .map(|i| &self.messages_b64[i])
+      .collect();
+
+    json!({
+      "proof": proof["proof"],
+      "public_key": self.public_key_b64,
+      "messages": revealed_messages,
+      "nonce": NONCE,
+    })
+  }
+
+  /// Blind the first message, leaving the rest known to the signer
+  fn commitment_context(&self) -> Value {
+    json!({
+      "public_key": self.public_key_b64,
+      "messages": [self.messages_b64[0]],
+      "blinded": [0],
+      "nonce": NONCE,
+    })
+  }
+
+  fn known_messages(&self) -> BTreeMap<usize, SignatureMessage> {
+    (1..self.message_count).map(|i| (i, self.messages[i])).collect()
+  }
+
+  fn blind_sign_context(&self, commitment: &Value) -> Value {
+    json!({
+      "commitment": commitment["commitment"],
+      "public_key": self.public_key_b64,
+      "secret_key": self.secret_key_b64,
+      "messages": self.messages_b64[1..].to_vec(),
+      "known": (1..self.message_count).collect::<Vec<usize>>(),
+    })
+  }
+}
+
+fn parse(output: String) -> Value {
+  serde_json::from_str(&output).unwrap()
+}
+
+fn array_ref<const N: usize>(bytes: &[u8]) -> &[u8; N] {
+  bytes[..N].try_into().unwrap()
+}
+
+fn crypto_create_proof(fixture: &Fixture, nonce: &[u8]) -> PoKOfSignatureProof {
+  let revealed = fixture.revealed();
+  let proof_messages: Vec<ProofMessage> = fixture.messages
+    .iter()
+    .enumerate()
+    .map(|(i, m)| if revealed.contains(&i) { pm_revealed_raw!(*m) } else { pm_hidden_raw!(*m) })
+    .collect();
+
+  let pok = PoKOfSignature::init(&fixture.signature, &fixture.public_key, proof_messages.as_slice()).unwrap();
+  let mut challenge_bytes = pok.to_bytes();
+  challenge_bytes.extend_from_slice(&ProofNonce::hash(nonce).to_bytes_compressed_form());
+  pok.gen_proof(&ProofChallenge::hash(&challenge_bytes)).unwrap()
+}
+
+fn bench_keygen(c: &mut Criterion) {
+  let mut group = c.benchmark_group("keygen");
+
+  group.bench_function("crypto", |b| {
+    b.iter(|| DeterministicPublicKey::new(Some(KeyGenOption::UseSeed(SEED.as_bytes().to_vec()))))
+  });
+  group.bench_function("wrapper", |b| {
+    b.iter(|| rust_bls_generate_g2_key(json!({ "seed": SEED })).unwrap())
+  });
+
+  group.finish();
+}
+
+fn bench_to_public_key(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("to_public_key");
+  group.sample_size(10);
+
+  for fixture in fixtures {
+    let context = json!({ "public_key": fixture.bls_public_key_b64, "message_count": fixture.message_count });
+
+    group.bench_with_input(BenchmarkId::new("crypto", fixture.message_count), fixture, |b, fixture| {
+      b.iter(|| fixture.dpk.to_public_key(fixture.message_count).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("wrapper", fixture.message_count), &context, |b, context| {
+      b.iter(|| {
+        // measure derivation rather than the public key cache
+        rust_bls_public_key_cache_clear();
+        rust_bls_public_key_to_bbs_key(context.clone()).unwrap()
+      })
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_sign(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("sign");
+  group.sample_size(10);
+
+  for fixture in fixtures {
+    let context = json!({
+      "secret_key": fixture.secret_key_b64,
+      "public_key": fixture.public_key_b64,
+      "messages": fixture.messages_b64,
+    });
+
+    group.bench_with_input(BenchmarkId::new("crypto", fixture.message_count), fixture, |b, fixture| {
+      b.iter(|| Signature::new(fixture.messages.as_slice(), &fixture.secret_key, &fixture.public_key).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("wrapper", fixture.message_count), &context, |b, context| {
+      b.iter(|| rust_bbs_sign(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_verify(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("verify");
+  group.sample_size(10);
+
+  for fixture in fixtures {
+    let context = json!({
+      "signature": fixture.signature_b64,
+      "public_key": fixture.public_key_b64,
+      "messages": fixture.messages_b64,
+    });
+
+    group.bench_with_input(BenchmarkId::new("crypto", fixture.message_count), fixture, |b, fixture| {
+      b.iter(|| fixture.signature.verify(fixture.messages.as_slice(), &fixture.public_key).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("wrapper", fixture.message_count), &context, |b, context| {
+      b.iter(|| rust_bbs_verify(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_create_proof(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("create_proof");
+  group.sample_size(10);
+
+  for fixture in fixtures {
+    let context = fixture.create_proof_context();
+    let nonce = base64::decode(NONCE).unwrap();
+
+    group.bench_with_input(BenchmarkId::new("crypto", fixture.message_count), fixture, |b, fixture| {
+      b.iter(|| crypto_create_proof(fixture, nonce.as_slice()))
+    });
+    group.bench_with_input(BenchmarkId::new("wrapper", fixture.message_count), &context, |b, context| {
+      b.iter(|| rust_bbs_create_proof(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_verify_proof(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("verify_proof");
+  group.sample_size(10);
+
+  for fixture in fixtures {
+    let context = fixture.verify_proof_context();
+    let nonce = ProofNonce::hash(base64::decode(NONCE).unwrap());
+    let proof = crypto_create_proof(fixture, &base64::decode(NONCE).unwrap());
+    let proof_request = ProofRequest {
+      revealed_messages: fixture.revealed(),
+      verification_key: fixture.public_key.clone(),
+    };
+    let signature_proof = SignatureProof {
+      revealed_messages: fixture.revealed().into_iter().map(|i| (i, fixture.messages[i])).collect(),
+      proof,
+    };
+
+    group.bench_with_input(BenchmarkId::new("crypto", fixture.message_count), &signature_proof, |b, signature_proof| {
+      b.iter(|| Verifier::verify_signature_pok(&proof_request, signature_proof, &nonce).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("wrapper", fixture.message_count), &context, |b, context| {
+      b.iter(|| rust_bbs_verify_proof(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_blind_signature_commitment(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("blind_signature_commitment");
+  group.sample_size(10);
+
+  for fixture in fixtures {
+    let context = fixture.commitment_context();
+    let nonce = ProofNonce::hash(base64::decode(NONCE).unwrap());
+    let blinded: BTreeMap<usize, SignatureMessage> = vec![(0, fixture.messages[0])].into_iter().collect();
+
+    group.bench_with_input(BenchmarkId::new("crypto", fixture.message_count), fixture, |b, fixture| {
+      b.iter(|| Prover::new_blind_signature_context(&fixture.public_key, &blinded, &nonce).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("wrapper", fixture.message_count), &context, |b, context| {
+      b.iter(|| rust_bbs_blind_signature_commitment(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_blind_sign(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("blind_sign");
+  group.sample_size(10);
+
+  for fixture in fixtures {
+    let commitment = parse(rust_bbs_blind_signature_commitment(fixture.commitment_context()).unwrap());
+    let context = fixture.blind_sign_context(&commitment);
+    let commitment_bytes = base64::decode(commitment["commitment"].as_str().unwrap()).unwrap();
+    let commitment = Commitment::from(*array_ref::<G1_COMPRESSED_SIZE>(&commitment_bytes));
+    let known = fixture.known_messages();
+
+    group.bench_with_input(BenchmarkId::new("crypto", fixture.message_count), fixture, |b, fixture| {
+      b.iter(|| BlindSignature::new(&commitment, &known, &fixture.secret_key, &fixture.public_key).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("wrapper", fixture.message_count), &context, |b, context| {
+      b.iter(|| rust_bbs_blind_sign(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_unblind_signature(c: &mut Criterion, fixtures: &[Fixture]) {
+  let mut group = c.benchmark_group("unblind_signature");
+
+  // unblinding is independent of the message count
+  let fixture = &fixtures[0];
+  let commitment = parse(rust_bbs_blind_signature_commitment(fixture.commitment_context()).unwrap());
+  let blind_signature = parse(rust_bbs_blind_sign(fixture.blind_sign_context(&commitment)).unwrap());
+  let context = json!({
+    "blind_signature": blind_signature["blind_signature"],
+    "blinding_factor": commitment["blinding_factor"],
+  });
+  let blind_signature = BlindSignature::from(*array_ref::<SIGNATURE_COMPRESSED_SIZE>(&base64::decode(blind_signature["blind_signature"].as_str().unwrap()).unwrap()));
+  let blinding_factor_bytes = base64::decode(commitment["blinding_factor"].as_str().unwrap()).unwrap();
+  let blinding_factor = SignatureBlinding::from(*array_ref::<FR_COMPRESSED_SIZE>(&blinding_factor_bytes));
+
+  group.bench_function("crypto", |b| {
+    b.iter(|| blind_signature.to_unblinded(&blinding_factor))
+  });
+  group.bench_function("wrapper", |b| {
+    b.iter(|| rust_bbs_unblind_signature(context.clone()).unwrap())
+  });
+
+  group.finish();
+}
+
+fn bench_operations(c: &mut Criterion) {
+  let fixtures: Vec<Fixture> = MESSAGE_COUNTS.iter().map(|n| Fixture::new(*n)).collect();
+
+  bench_keygen(c);
+  bench_to_public_key(c, &fixtures);
+  bench_sign(c, &fixtures);
+  bench_verify(c, &fixtures);
+  bench_create_proof(c, &fixtures);
+  bench_verify_proof(c, &fixtures);
+  bench_blind_signature_commitment(c, &fixtures);
+  bench_blind_sign(c, &fixtures);
+  bench_unblind_signature(c, &fixtures);
+}
+
+criterion_group!(benches, bench_operations);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+use bbs::rust_bbs::{
+  rust_bls_generate_g2_key,
+  rust_bls_public_key_to_bbs_key,
+  rust_bls_sign,
+  rust_bls_create_proof,
+  rust_bls_verify_proof,
+  rust_bls_public_key_cache_warm,
+  rust_bls_public_key_cache_clear,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use serde_json::{Value, json};
+
+const SEED: &str = "H297BpoOgkfpXcxr1fJyQRiNx1+ZekeQ+OU/AYV/lVxaPXXhFBIbxeIU8kIAAX68cwQ=";
+
+fn parse(output: String) -> Value {
+  serde_json::from_str(&output).unwrap()
+}
+
+/// Build a `rust_bls_verify_proof` context for `message_count` messages, revealing all of them
+fn verify_proof_context(message_count: usize) -> Value {
+  let key = parse(rust_bls_generate_g2_key(json!({ "seed": SEED })).unwrap());
+  let messages: Vec<String> = (0..message_count)
+    .map(|i| base64::encode(format!("message{}", i)))
+    .collect();
+  let revealed: Vec<usize> = (0..message_count).collect();
+
+  let signature = parse(rust_bls_sign(json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+  })).unwrap());
+
+  let proof = parse(rust_bls_create_proof(json!({
+    "signature": signature["signature"],
+    "public_key": key["public_key"],
+    "messages": messages,
+    "revealed": revealed,
+    "nonce": "bm9uY2U=",
+  })).unwrap());
+
+  json!({
+    "proof": proof["proof"],
+    "public_key": key["public_key"],
+    "messages": messages,
+    "nonce": "bm9uY2U=",
+  })
+}
+
+fn bench_bls_public_key_to_bbs_key(c: &mut Criterion) {
+  let mut group = c.benchmark_group("rust_bls_public_key_to_bbs_key");
+  let key = parse(rust_bls_generate_g2_key(json!({ "seed": SEED })).unwrap());
+
+  for message_count in [1usize, 10, 100] {
+    let context = json!({
+      "public_key": key["public_key"],
+      "message_count": message_count,
+    });
+
+    group.bench_with_input(BenchmarkId::new("uncached", message_count), &context, |b, context| {
+      b.iter(|| {
+        rust_bls_public_key_cache_clear();
+        rust_bls_public_key_to_bbs_key(context.clone()).unwrap()
+      })
+    });
+
+    group.bench_with_input(BenchmarkId::new("cached", message_count), &context, |b, context| {
+      b.iter(|| rust_bls_public_key_to_bbs_key(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+fn bench_bls_verify_proof(c: &mut Criterion) {
+  let mut group = c.benchmark_group("rust_bls_verify_proof");
+
+  for message_count in [1usize, 10, 100] {
+    let context = verify_proof_context(message_count);
+
+    group.bench_with_input(BenchmarkId::new("uncached", message_count), &context, |b, context| {
+      b.iter(|| {
+        rust_bls_public_key_cache_clear();
+        rust_bls_verify_proof(context.clone()).unwrap()
+      })
+    });
+
+    rust_bls_public_key_cache_warm(json!({
+      "public_key": context["public_key"],
+      "message_counts": [message_count],
+    })).unwrap();
+
+    group.bench_with_input(BenchmarkId::new("cached", message_count), &context, |b, context| {
+      b.iter(|| rust_bls_verify_proof(context.clone()).unwrap())
+    });
+  }
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_bls_public_key_to_bbs_key, bench_bls_verify_proof);
+criterion_main!(benches);
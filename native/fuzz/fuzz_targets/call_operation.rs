@@ -0,0 +1,17 @@
+//! Any operation by its wrapper name, from `<operation>\0<context>` with a JSON or CBOR context.
+#![no_main]
+
+use bbs::rust_bbs::rust_call_cbor;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let split = match data.iter().position(|byte| *byte == 0) {
+    Some(split) => split,
+    None => return,
+  };
+  match std::str::from_utf8(&data[..split]) {
+    // limits are global, and raising them would only slow the other inputs down
+    Ok("bbs_set_limits") | Err(_) => {},
+    Ok(operation) => { rust_call_cbor(operation, &data[split + 1..]); },
+  }
+});
@@ -0,0 +1,26 @@
+//! Raw proof bytes, as decoded by the BBS+ and IETF BBS proof verifications.
+#![no_main]
+
+use bbs::rust_bbs::{rust_bbs_ietf_verify_proof, rust_bls_verify_proof};
+use libfuzzer_sys::fuzz_target;
+use serde_json::json;
+
+// the G2 key of the known-answer fixtures, which signed the proofs of the seed corpus
+const PUBLIC_KEY: &str = "mQuET5RMyLJ0xj++btQzD4ccKcgMcjpwdK6Jf9FNo9Bn3IlECaT/DEZ7vhkioolqFWWoJhweVvEpgJNUxFzMdbS8eawvdRtnBWmvRfjMmSECIxSJ8HP94l7AtpZJybrw";
+
+fuzz_target!(|data: &[u8]| {
+  // the envelope, revealed bitvector and proof of knowledge of a versioned or legacy proof
+  let proof = base64::encode(data);
+  let _ = rust_bls_verify_proof(json!({
+    "public_key": PUBLIC_KEY,
+    "proof": proof,
+    "messages": [base64::encode("Alice"), base64::encode("GB")],
+    "nonce": base64::encode("nonce"),
+  }));
+  let _ = rust_bbs_ietf_verify_proof(json!({
+    "public_key": PUBLIC_KEY,
+    "proof": proof,
+    "messages": [base64::encode("Alice"), base64::encode("GB")],
+    "revealed": [0, 2],
+  }));
+});
@@ -0,0 +1,18 @@
+//! Raw signature and blind signature bytes, as decoded by verification and unblinding.
+#![no_main]
+
+use bbs::rust_bbs::{rust_bbs_ietf_verify, rust_bbs_unblind_signature, rust_bls_verify};
+use libfuzzer_sys::fuzz_target;
+use serde_json::json;
+
+// the G2 key and a blinding factor of the known-answer fixtures
+const PUBLIC_KEY: &str = "mQuET5RMyLJ0xj++btQzD4ccKcgMcjpwdK6Jf9FNo9Bn3IlECaT/DEZ7vhkioolqFWWoJhweVvEpgJNUxFzMdbS8eawvdRtnBWmvRfjMmSECIxSJ8HP94l7AtpZJybrw";
+const BLINDING_FACTOR: &str = "PEx1zzY2/BbbcD6hw94FAjG87/Oz9a1fXUajSAtbn/M=";
+
+fuzz_target!(|data: &[u8]| {
+  let signature = base64::encode(data);
+  let messages = [base64::encode("Alice"), base64::encode("1990-01-01"), base64::encode("GB")];
+  let _ = rust_bls_verify(json!({ "public_key": PUBLIC_KEY, "signature": signature, "messages": messages }));
+  let _ = rust_bbs_ietf_verify(json!({ "public_key": PUBLIC_KEY, "signature": signature, "messages": messages }));
+  let _ = rust_bbs_unblind_signature(json!({ "blind_signature": signature, "blinding_factor": BLINDING_FACTOR }));
+});
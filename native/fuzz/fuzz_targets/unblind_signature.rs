@@ -0,0 +1,11 @@
+//! Unblinding a blind signature, from a JSON or CBOR context.
+#![no_main]
+
+use bbs::rust_bbs::{parse_context, rust_bbs_unblind_signature};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(context) = parse_context(data) {
+    let _ = rust_bbs_unblind_signature(context);
+  }
+});
@@ -0,0 +1,12 @@
+//! Blind signature commitment verification, from a JSON or CBOR context.
+#![no_main]
+
+use bbs::rust_bbs::{parse_context, rust_bbs_verify_blind_signature_proof, rust_bls_verify_blind_signature_proof};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(context) = parse_context(data) {
+    let _ = rust_bbs_verify_blind_signature_proof(context.clone());
+    let _ = rust_bls_verify_blind_signature_proof(context);
+  }
+});
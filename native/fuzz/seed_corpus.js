@@ -0,0 +1,51 @@
+/*
+ * Write the seed corpus of each fuzz target from the known-answer fixtures in native/tests/fixtures,
+ * into native/fuzz/corpus/<target>, named by the SHA-1 of their content as libFuzzer does.
+ *
+ *   node native/fuzz/seed_corpus.js
+ */
+
+const crypto = require('crypto')
+const fs = require('fs')
+const path = require('path')
+
+const fixturesDir = path.join(__dirname, '..', 'tests', 'fixtures')
+const corpusDir = path.join(__dirname, 'corpus')
+
+const fixtures = fs.readdirSync(fixturesDir)
+  .filter(name => name.endsWith('.json'))
+  .flatMap(name => JSON.parse(fs.readFileSync(path.join(fixturesDir, name), 'utf8')))
+
+const write = (target, input) => {
+  const dir = path.join(corpusDir, target)
+  fs.mkdirSync(dir, { recursive: true })
+  const name = crypto.createHash('sha1').update(input).digest('hex')
+  fs.writeFileSync(path.join(dir, name), input)
+}
+
+// the fixtures of an operation, on either the bbs_ or bls_ public key
+const contexts = operation => fixtures
+  .filter(fixture => fixture.operation.replace(/^bls_/, 'bbs_') === operation)
+  .map(fixture => fixture.context)
+
+// the base64 byte properties of fixture contexts and results
+const bytes = (property, ...operations) => fixtures
+  .filter(fixture => operations.includes(fixture.operation))
+  .flatMap(fixture => [ fixture.context[property], (fixture.result || {})[property] ])
+  .filter(value => typeof value === 'string')
+  .map(value => Buffer.from(value, 'base64'))
+
+for (const fixture of fixtures) {
+  write('call_operation', Buffer.concat([ Buffer.from(fixture.operation), Buffer.from([ 0 ]), Buffer.from(JSON.stringify(fixture.context)) ]))
+}
+for (const target of [ 'verify_proof', 'verify_blind_signature_proof', 'unblind_signature', 'verify_presentation' ]) {
+  for (const context of contexts(`bbs_${target}`)) {
+    write(target, JSON.stringify(context))
+  }
+}
+for (const proof of bytes('proof', 'bbs_verify_proof', 'bls_verify_proof', 'bbs_ietf_verify_proof')) {
+  write('proof_bytes', proof)
+}
+for (const signature of [ ...bytes('signature', 'bls_verify', 'bbs_ietf_verify'), ...bytes('blind_signature', 'bbs_unblind_signature') ]) {
+  write('signature_bytes', signature)
+}
@@ -1,5 +1,7 @@
 #[macro_use]
 extern crate arrayref;
+#[macro_use]
+extern crate lazy_static;
 
-mod rust_bbs;
+pub mod rust_bbs;
 pub mod wrappers;
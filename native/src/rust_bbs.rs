@@ -1,22 +1,181 @@
 #[macro_use]
 mod macros;
+mod key_export;
+mod bbs_2023;
+pub mod bbs_plus_backend;
+mod cbor;
+mod cose;
+pub mod curve;
+#[cfg(feature = "blst")]
+mod curve_blst;
+mod document;
+mod encoding;
+mod hash_to_curve;
+pub mod ietf_bbs;
+mod json_schema;
+mod jwk;
+mod jwp;
+mod keystore;
+mod limits;
+mod multikey;
+mod nquads;
+mod presentation;
+mod proof_envelope;
+mod proof_verification;
+mod public_key_cache;
+mod rdfc;
+mod requests;
+mod responses;
+mod rng;
+mod schema;
+mod secret;
+mod varint;
 
 use std::collections::{BTreeMap,BTreeSet};
+use std::convert::TryFrom;
+use serde::Serialize;
 use serde_json::{Value, json};
-use bbs::prelude::*;
-use bbs::errors::BBSError;
-use bbs::{
-  FR_COMPRESSED_SIZE,
-  pm_revealed_raw,
-  pm_hidden_raw,
+use bbs_plus::prelude::*;
+use bbs_plus::errors::BBSError;
+use bbs_plus::FR_COMPRESSED_SIZE;
+use rand::prelude::*;
+use cbor::{context_from_cbor, result_to_cbor, error_to_cbor};
+use cose::{Algorithm, Credential, decode_context_credential, encode_credential, encode_presentation};
+use curve::{CurveBackend, DefaultBackend, DefaultG1, DefaultG2, DefaultScalar, Group, Scalar, OKM_LENGTH};
+use document::{MessageFormat, document_messages, disclosed_document};
+use encoding::{BYTE_PROPERTIES, JWP_BYTE_PROPERTIES, take_encoding};
+use json_schema::{JsonSchema, SCHEMA_DIALECT};
+use limits::{LIMIT_EXCEEDED, LIMIT_EXCEEDED_CODE, check_context_size, limits, set_limits};
+use requests::{
+  check_request,
+  Claims,
+  DeterministicSeed,
+  MessageSelector,
+  PublicKeyInput,
+  Secret,
+  SecretKeyInput,
+  Bbs2023CreateBaseProofRequest,
+  Bbs2023CreateDerivedProofRequest,
+  Bbs2023VerifyDerivedProofRequest,
+  BbsBlindSignRequest,
+  BbsBlindSignatureCommitmentRequest,
+  BbsCreatePresentationRequest,
+  BbsCreateProofRequest,
+  BbsSignRequest,
+  BbsVerifyBlindSignatureProofRequest,
+  BbsVerifyPresentationRequest,
+  BbsVerifyProofRequest,
+  BbsVerifyRequest,
+  BlsBlindSignRequest,
+  BlsBlindSignatureCommitmentRequest,
+  BlsCreatePresentationRequest,
+  BlsCreateProofRequest,
+  BlsSignRequest,
+  BlsVerifyBlindSignatureProofRequest,
+  BlsVerifyPresentationRequest,
+  BlsVerifyProofRequest,
+  BlsVerifyRequest,
+  CoseCredentialRequest,
+  CosePresentationRequest,
+  ExportEncryptedKeyRequest,
+  GenerateKeyRequest,
+  IetfCreateProofRequest,
+  IetfSignRequest,
+  IetfVerifyProofRequest,
+  IetfVerifyRequest,
+  ImportEncryptedKeyRequest,
+  JwkToKeyRequest,
+  JwpIssueRequest,
+  JwpPresentRequest,
+  JwpVerifyRequest,
+  KeyToJwkRequest,
+  KeystoreImportRequest,
+  KeystoreKeyRequest,
+  MultikeyToPublicKeyRequest,
+  PublicKeyCacheWarmRequest,
+  PublicKeyEncodingRequest,
+  PublicKeyToBbsKeyRequest,
+  RdfCanonizeRequest,
+  ResolveDidKeyRequest,
+  SchemaHashRequest,
+  SecretKeyToBbsKeyRequest,
+  SetLimitsRequest,
+  UnblindSignatureRequest,
 };
-use pairing_plus::{
-    bls12_381::{Bls12, Fr, G1, G2},
-    hash_to_field::BaseFromRO,
-    serdes::SerDes,
-    CurveProjective,
+use responses::{
+  BaseProofResponse,
+  BlindSignatureCommitmentResponse,
+  BlindSignatureResponse,
+  BlindedKeyPairResponse,
+  CanonicalDocumentResponse,
+  CoseCredentialResponse,
+  CosePresentationResponse,
+  DerivedProofResponse,
+  DidKeyResponse,
+  EncryptedKeyResponse,
+  ImportedKeyResponse,
+  JwkKeyResponse,
+  JwkResponse,
+  JwpResponse,
+  JwpVerifyResponse,
+  KeyPairResponse,
+  KeystoreDestroyResponse,
+  LimitsResponse,
+  MultikeyPublicKeyResponse,
+  MultikeyResponse,
+  PresentationResponse,
+  PresentationVerifyResponse,
+  ProofResponse,
+  ProofVerificationResponse,
+  PublicKeyCacheResponse,
+  PublicKeyResponse,
+  ResolvedDidKeyResponse,
+  SchemaHashResponse,
+  SignatureResponse,
+  StoredKeyResponse,
+  VerifiedResponse,
 };
-use rand::prelude::*;
+use presentation::{PRESENTATION_VERSION, Presentation, create_presentation, parse_presentation};
+use proof_envelope::{Ciphersuite, PROOF_VERSION, PROOF_VERSIONS, PROOF_VERSION_LEGACY, PROOF_VERSION_2, decode_proof, encode_proof};
+use proof_verification::{ProofFailure, ProofVerification, error_detail};
+use schema::{
+  SCHEMA_HASH_INDEX,
+  Schema,
+  signed_claim_messages,
+  revealed_claim_messages,
+  reveal_claim_indices,
+  blinded_claims,
+  known_claims,
+  claims_by_name,
+};
+use public_key_cache::{PUBLIC_KEY_CACHE_CAPACITY, cached_public_key, warm_public_key_cache, clear_public_key_cache, public_key_cache_len};
+use key_export::{
+  ARGON2_MAX_M_COST,
+  ARGON2_MAX_P_COST,
+  ARGON2_MAX_T_COST,
+  ENCRYPTED_KEY_VERSION,
+  EncryptedKey,
+  KeyType,
+  encrypt_key,
+  decrypt_key,
+};
+use jwk::{Jwk, JwkCurve, base64url_encode, jwk_public_key, take_jwk_secret_key};
+use jwp::{Jwp, Serialization, protected_header, parse_protected_header};
+use keystore::{insert_key, with_key, get_public_key, remove_key};
+use multikey::{MULTICODEC_BLS12_381_G1_PUB, encode_multikey, decode_multikey, encode_did_key, is_did_key, resolve_did_key};
+use secret::{decode_secret, encode_secret, secret_to_json};
+use rng::context_rng;
+use zeroize::{Zeroize, Zeroizing};
+
+/// BBS+ public key and signature on the curve backend
+type BbsPublicKey = bbs_plus_backend::PublicKey<DefaultBackend>;
+type BbsSignature = bbs_plus_backend::Signature<DefaultBackend>;
+
+/// Secret key, blinding factor or seed bytes, wiped when dropped
+type SecretBytes = Zeroizing<Vec<u8>>;
+
+/// Error for a public key given alongside a secret key, or key handle, that isn't its own
+const PUBLIC_KEY_MISMATCH: &str = "Invalid 'public_key', expected the public key of 'secret_key'";
 
 const BLINDING_G1: &[u8] = &[
     185, 201, 5, 142, 138, 68, 184, 112, 20, 249, 139, 228, 225, 129, 141, 183, 24, 248, 178, 213,
@@ -35,8 +194,8 @@ const BLINDING_G2: &[u8] = &[
 /// and public key `w` = `g2` ^ `x` * `blinding_g2` ^ `r`
 /// `seed`: `ArrayBuffer` [opt]
 /// `return` Object { publicKey: `ArrayBuffer`, secretKey: `ArrayBuffer`, blindingFactor: `ArrayBuffer` }
-fn bls_generate_blinded_g2_key(ikm: Option<Vec<u8>>) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
-    let (r, pk, sk) = bls_generate_keypair::<G2>(ikm, Some(BLINDING_G2));
+fn bls_generate_blinded_g2_key<R: RngCore + CryptoRng>(ikm: Option<SecretBytes>, rng: &mut R) -> (SecretBytes, Vec<u8>, SecretBytes) {
+    let (r, pk, sk) = bls_generate_keypair::<DefaultG2, R>(ikm, Some(BLINDING_G2), rng);
     (r.unwrap(), pk, sk)
 }
 
@@ -44,8 +203,8 @@ fn bls_generate_blinded_g2_key(ikm: Option<Vec<u8>>) -> (Vec<u8>, Vec<u8>, Vec<u
 /// and public key `w` = `g1` ^ `x` * `blinding_g1` ^ `r`
 /// `seed`: `ArrayBuffer` [opt]
 /// `return` Object { publicKey: `ArrayBuffer`, secretKey: `ArrayBuffer`, blindingFactor: `ArrayBuffer` }
-fn bls_generate_blinded_g1_key(ikm: Option<Vec<u8>>) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
-    let (r, pk, sk) = bls_generate_keypair::<G1>(ikm, Some(BLINDING_G1));
+fn bls_generate_blinded_g1_key<R: RngCore + CryptoRng>(ikm: Option<SecretBytes>, rng: &mut R) -> (SecretBytes, Vec<u8>, SecretBytes) {
+    let (r, pk, sk) = bls_generate_keypair::<DefaultG1, R>(ikm, Some(BLINDING_G1), rng);
     (r.unwrap(), pk, sk)
 }
 
@@ -53,8 +212,8 @@ fn bls_generate_blinded_g1_key(ikm: Option<Vec<u8>>) -> (Vec<u8>, Vec<u8>, Vec<u
 /// and public key `w` = `g2` ^ `x`
 /// `seed`: `ArrayBuffer` [opt]
 /// `return`: Object { publicKey: `ArrayBuffer`, secretKey: `ArrayBuffer` }
-fn bls_generate_g2_key(ikm: Option<Vec<u8>>) -> (Vec<u8>, Vec<u8>) {
-    let (_, pk, sk) = bls_generate_keypair::<G2>(ikm, None);
+fn bls_generate_g2_key<R: RngCore + CryptoRng>(ikm: Option<SecretBytes>, rng: &mut R) -> (Vec<u8>, SecretBytes) {
+    let (_, pk, sk) = bls_generate_keypair::<DefaultG2, R>(ikm, None, rng);
     (pk, sk)
 }
 
@@ -62,71 +221,94 @@ fn bls_generate_g2_key(ikm: Option<Vec<u8>>) -> (Vec<u8>, Vec<u8>) {
 /// and public key `w` = `g1` ^ `x`
 /// `seed`: `ArrayBuffer` [opt]
 /// `return`: Object { publicKey: `ArrayBuffer`, secretKey: `ArrayBuffer` }
-fn bls_generate_g1_key(ikm: Option<Vec<u8>>) -> (Vec<u8>, Vec<u8>) {
-    let (_, pk, sk) = bls_generate_keypair::<G1>(ikm, None);
+fn bls_generate_g1_key<R: RngCore + CryptoRng>(ikm: Option<SecretBytes>, rng: &mut R) -> (Vec<u8>, SecretBytes) {
+    let (_, pk, sk) = bls_generate_keypair::<DefaultG1, R>(ikm, None, rng);
     (pk, sk)
 }
 
-fn bls_generate_keypair<G: CurveProjective<Engine = Bls12, Scalar = Fr> + SerDes>(
-    ikm: Option<Vec<u8>>,
+fn bls_generate_keypair<G: Group, R: RngCore + CryptoRng>(
+    ikm: Option<SecretBytes>,
     blinded: Option<&[u8]>,
-) -> (Option<Vec<u8>>, Vec<u8>, Vec<u8>) {
+    rng: &mut R,
+) -> (Option<SecretBytes>, Vec<u8>, SecretBytes) {
     let passed_seed = ikm.is_some();
     let seed = ikm.unwrap_or_else(|| {
-        let mut rng = thread_rng();
-        let mut seed_data = vec![0u8, 32];
+        let mut seed_data = Zeroizing::new(vec![0u8; 32]);
         rng.fill_bytes(seed_data.as_mut_slice());
         seed_data
     });
 
-    let sk = gen_sk(seed.as_slice());
-    let mut pk = G::one();
-    pk.mul_assign(sk);
+    let mut sk: G::Scalar = gen_sk(seed.as_slice());
+    let mut pk = G::generator().mul(&sk);
 
     let r = match blinded {
         Some(g) => {
-            let mut data = g.to_vec();
-            let mut gg = g;
+            let mut data = Zeroizing::new(Vec::with_capacity(g.len() + seed.len().max(32)));
+            data.extend_from_slice(g);
             if passed_seed {
                 data.extend_from_slice(seed.as_slice());
             } else {
-                let mut rng = thread_rng();
-                let mut blinding_factor = vec![0u8, 32];
+                let mut blinding_factor = Zeroizing::new(vec![0u8; 32]);
                 rng.fill_bytes(blinding_factor.as_mut_slice());
                 data.extend_from_slice(blinding_factor.as_slice());
             }
-            let mut blinding_g = G::deserialize(&mut gg, true).unwrap();
-            let r = gen_sk(data.as_slice());
-            blinding_g.mul_assign(r);
-            pk.add_assign(&blinding_g);
-            let mut r_bytes = Vec::new();
-            r.serialize(&mut r_bytes, true).unwrap();
+            let blinding_g = G::from_compressed(g).unwrap();
+            let mut r: G::Scalar = gen_sk(data.as_slice());
+            pk = pk.add(&blinding_g.mul(&r));
+            let r_bytes = Zeroizing::new(r.to_bytes().to_vec());
+            r.zeroize();
             Some(r_bytes)
         }
         None => None,
     };
 
-    let mut sk_bytes = Vec::new();
-    let mut pk_bytes = Vec::new();
-    sk.serialize(&mut sk_bytes, true).unwrap();
-    pk.serialize(&mut pk_bytes, true).unwrap();
+    let sk_bytes = Zeroizing::new(sk.to_bytes().to_vec());
+    sk.zeroize();
 
-    (r, pk_bytes, sk_bytes)
+    (r, pk.to_compressed(), sk_bytes)
 }
 
-fn gen_sk(msg: &[u8]) -> Fr {
-    use sha2::digest::generic_array::{typenum::U48, GenericArray};
+fn gen_sk<S: Scalar>(msg: &[u8]) -> S {
     const SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
     // copy of `msg` with appended zero byte
-    let mut msg_prime = Vec::<u8>::with_capacity(msg.as_ref().len() + 1);
+    let mut msg_prime = Zeroizing::new(Vec::<u8>::with_capacity(msg.as_ref().len() + 1));
     msg_prime.extend_from_slice(msg.as_ref());
     msg_prime.extend_from_slice(&[0]);
     // `result` has enough length to hold the output from HKDF expansion
-    let mut result = GenericArray::<u8, U48>::default();
+    let mut result = [0u8; OKM_LENGTH];
     assert!(hkdf::Hkdf::<sha2::Sha256>::new(Some(SALT), &msg_prime[..])
         .expand(&[0, 48], &mut result)
         .is_ok());
-    Fr::from_okm(&result)
+    let sk = S::from_okm(&result);
+    result.zeroize();
+    sk
+}
+
+/// Serialize a generated BLS key pair, keeping secret values out of intermediate `json!` copies
+fn bls_key_to_json(
+  pk_bytes: &[u8],
+  sk_bytes: &[u8],
+  bf_bytes: Option<&[u8]>,
+) -> serde_json::Result<String> {
+  #[derive(Serialize)]
+  struct BlsKey<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blinding_factor: Option<&'a str>,
+    public_key: &'a str,
+    secret_key: &'a str,
+  }
+
+  let public_key = base64::encode(pk_bytes);
+  let secret_key = encode_secret(sk_bytes);
+  let blinding_factor = bf_bytes.map(encode_secret);
+
+  let key = BlsKey {
+    blinding_factor: blinding_factor.as_ref().map(|bf| bf.as_str()),
+    public_key: &public_key,
+    secret_key: &secret_key,
+  };
+  let values = [public_key.as_str(), secret_key.as_str(), key.blinding_factor.unwrap_or_default()];
+  secret_to_json(&key, &values)
 }
 
 #[allow(dead_code)]
@@ -166,27 +348,30 @@ pub fn rust_bbs_signature_size() -> i32 {
 
 #[allow(dead_code)]
 pub fn rust_bls_generate_blinded_g1_key(
-  context_json: Value
+  mut context_json: Value
 ) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_generate_blinded_g1_key);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<GenerateKeyRequest>(context_json)?;
+
+  let mut rng = context_rng(&request.deterministic_seed, "bls_generate_blinded_g1_key")?;
+
   // convert seed base64 string to slice
-  let (bf_bytes, pk_bytes, sk_bytes) = match context_json["seed"].as_str() {
+  let (bf_bytes, pk_bytes, sk_bytes) = match request.seed {
     Some(seed) => {
-      match base64::decode(seed) {
-        Ok(seed_bytes) => bls_generate_blinded_g1_key(Some(seed_bytes)),
-        Err(_) => { handle_err!("Failed decoding base64 for: 'seed'"); }
+      match decode_secret(&seed.0) {
+        Ok(seed_bytes) => bls_generate_blinded_g1_key(Some(seed_bytes), &mut rng),
+        Err(_) => { handle_err!("Failed decoding base64 for: 'seed'"); },
       }
     },
-    None => bls_generate_blinded_g1_key(None)
+    None => bls_generate_blinded_g1_key(None, &mut rng)
   };
 
-  let blinded_g1_key = json!({
-    "public_key": base64::encode(pk_bytes.as_slice()),
-    "secret_key": base64::encode(sk_bytes.as_slice()),
-    "blinding_factor": base64::encode(bf_bytes.as_slice()),
-  });
-
-  // Serialize blinded G1 key to a JSON string
-  match serde_json::to_string(&blinded_g1_key) {
+  // Serialize Blinded G1 key to a JSON string
+  match bls_key_to_json(pk_bytes.as_slice(), sk_bytes.as_slice(), Some(bf_bytes.as_slice())) {
     Ok(json_string) => Ok(json_string),
     Err(_) => { handle_err!("Failed to stringify Blinded G1 key"); },
   }
@@ -194,27 +379,30 @@ pub fn rust_bls_generate_blinded_g1_key(
 
 #[allow(dead_code)]
 pub fn rust_bls_generate_blinded_g2_key(
-  context_json: Value
+  mut context_json: Value
 ) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_generate_blinded_g2_key);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<GenerateKeyRequest>(context_json)?;
+
+  let mut rng = context_rng(&request.deterministic_seed, "bls_generate_blinded_g2_key")?;
+
   // convert seed base64 string to slice
-  let (bf_bytes, pk_bytes, sk_bytes) = match context_json["seed"].as_str() {
+  let (bf_bytes, pk_bytes, sk_bytes) = match request.seed {
     Some(seed) => {
-      match base64::decode(seed) {
-        Ok(seed_bytes) => bls_generate_blinded_g2_key(Some(seed_bytes)),
+      match decode_secret(&seed.0) {
+        Ok(seed_bytes) => bls_generate_blinded_g2_key(Some(seed_bytes), &mut rng),
         Err(_) => { handle_err!("Failed decoding base64 for: 'seed'"); },
       }
     },
-    None => bls_generate_blinded_g2_key(None)
+    None => bls_generate_blinded_g2_key(None, &mut rng)
   };
 
-  let blinded_g2_key = json!({
-    "public_key": base64::encode(pk_bytes.as_slice()),
-    "secret_key": base64::encode(sk_bytes.as_slice()),
-    "blinding_factor": base64::encode(bf_bytes.as_slice()),
-  });
-
-  // Serialize blinded G2 key to a JSON string
-  match serde_json::to_string(&blinded_g2_key) {
+  // Serialize Blinded G2 key to a JSON string
+  match bls_key_to_json(pk_bytes.as_slice(), sk_bytes.as_slice(), Some(bf_bytes.as_slice())) {
     Ok(json_string) => Ok(json_string),
     Err(_) => { handle_err!("Failed to stringify Blinded G2 key"); },
   }
@@ -222,26 +410,30 @@ pub fn rust_bls_generate_blinded_g2_key(
 
 #[allow(dead_code)]
 pub fn rust_bls_generate_g1_key(
-  context_json: Value
+  mut context_json: Value
 ) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_generate_g1_key);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<GenerateKeyRequest>(context_json)?;
+
+  let mut rng = context_rng(&request.deterministic_seed, "bls_generate_g1_key")?;
+
   // convert seed base64 string to slice
-  let (pk_bytes, sk_bytes) = match context_json["seed"].as_str() {
+  let (pk_bytes, sk_bytes) = match request.seed {
     Some(seed) => {
-      match base64::decode(seed) {
-        Ok(seed_bytes) => bls_generate_g1_key(Some(seed_bytes)),
+      match decode_secret(&seed.0) {
+        Ok(seed_bytes) => bls_generate_g1_key(Some(seed_bytes), &mut rng),
         Err(_) => { handle_err!("Failed decoding base64 for: 'seed'"); },
       }
     },
-    None => bls_generate_g1_key(None)
+    None => bls_generate_g1_key(None, &mut rng)
   };
 
-  let g1_key = json!({
-    "public_key": base64::encode(pk_bytes.as_slice()),
-    "secret_key": base64::encode(sk_bytes.as_slice()),
-  });
-
-  // Serialize blinded G1 key to a JSON string
-  match serde_json::to_string(&g1_key) {
+  // Serialize G1 key to a JSON string
+  match bls_key_to_json(pk_bytes.as_slice(), sk_bytes.as_slice(), None) {
     Ok(json_string) => Ok(json_string),
     Err(_) => { handle_err!("Failed to stringify G1 key"); },
   }
@@ -249,26 +441,30 @@ pub fn rust_bls_generate_g1_key(
 
 #[allow(dead_code)]
 pub fn rust_bls_generate_g2_key(
-  context_json: Value
+  mut context_json: Value
 ) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_generate_g2_key);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<GenerateKeyRequest>(context_json)?;
+
+  let mut rng = context_rng(&request.deterministic_seed, "bls_generate_g2_key")?;
+
   // convert seed base64 string to slice
-  let (pk_bytes, sk_bytes) = match context_json["seed"].as_str() {
+  let (pk_bytes, sk_bytes) = match request.seed {
     Some(seed) => {
-      match base64::decode(seed) {
-        Ok(seed_bytes) => bls_generate_g2_key(Some(seed_bytes)),
+      match decode_secret(&seed.0) {
+        Ok(seed_bytes) => bls_generate_g2_key(Some(seed_bytes), &mut rng),
         Err(_) => { handle_err!("Failed decoding base64 for: 'seed'"); },
       }
     },
-    None => bls_generate_g2_key(None)
+    None => bls_generate_g2_key(None, &mut rng)
   };
 
-  let g2_key = json!({
-    "public_key": base64::encode(pk_bytes.as_slice()),
-    "secret_key": base64::encode(sk_bytes.as_slice()),
-  });
-
-  // Serialize blinded G1 key to a JSON string
-  match serde_json::to_string(&g2_key) {
+  // Serialize G2 key to a JSON string
+  match bls_key_to_json(pk_bytes.as_slice(), sk_bytes.as_slice(), None) {
     Ok(json_string) => Ok(json_string),
     Err(_) => { handle_err!("Failed to stringify G2 key"); },
   }
@@ -276,42 +472,25 @@ pub fn rust_bls_generate_g2_key(
 
 #[allow(dead_code)]
 pub fn rust_bls_secret_key_to_bbs_key(
-  context_json: Value
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get message count
-  let message_count = match context_json["message_count"].as_u64() {
-    Some(message_count) => message_count,
-    None => { handle_err!("Property not set: 'message_count'"); }
-  };
-
-  // convert 'secret_key' base64 string to `SecretKey` instance
-  let secret_key;
-  match context_json["secret_key"].as_str() {
-    Some(secret_key_b64) => {
-      let secret_key_bytes = base64::decode(secret_key_b64).unwrap().to_vec();
-      secret_key = SecretKey::from(*array_ref![
-        secret_key_bytes,
-        0,
-        FR_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'secret_key'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_secret_key_to_bbs_key);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<SecretKeyToBbsKeyRequest>(context_json)?;
+
+  // get `SecretKey` by 'key_handle', or from 'secret_key' base64 string
+  let secret_key = signing_key(request.key_handle, request.secret_key)?;
 
   // convert secret key to deterministic public key
-  let (dpk, _) = DeterministicPublicKey::new(Some(KeyGenOption::FromSecretKey(secret_key)));
+  let dpk = secret_key.public_key()?;
 
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
-  }
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, request.message_count as usize)?;
 
-  let pk_bytes = pk.to_bytes_compressed_form();
+  let pk_bytes = pk.to_bytes();
 
   let bbs_key = json!({
     "public_key": base64::encode(pk_bytes.as_slice())
@@ -326,39 +505,29 @@ pub fn rust_bls_secret_key_to_bbs_key(
 
 #[allow(dead_code)]
 pub fn rust_bls_public_key_to_bbs_key(
-  context_json: Value
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get message count
-  let message_count = match context_json["message_count"].as_u64() {
-    Some(message_count) => message_count,
-    None => { handle_err!("Property not set: 'message_count'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_public_key_to_bbs_key);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<PublicKeyToBbsKeyRequest>(context_json)?;
+
+  // get message count, by default that of the 'schema'
+  let message_count = match (request.message_count, &request.schema) {
+    (Some(message_count), _) => message_count as usize,
+    (None, None) => { handle_err!("Property not set: 'message_count'"); },
+    (None, Some(schema)) => Schema::from_json(schema)?.message_count(),
   };
 
-  // convert 'public_key' base64 string to `SecretKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
-  }
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(&request.public_key)?;
 
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
-  }
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, message_count)?;
 
-  let pk_bytes = pk.to_bytes_compressed_form();
+  let pk_bytes = pk.to_bytes();
 
   let bbs_key = json!({
     "public_key": base64::encode(pk_bytes.as_slice())
@@ -372,1045 +541,2804 @@ pub fn rust_bls_public_key_to_bbs_key(
 }
 
 #[allow(dead_code)]
-pub fn rust_bbs_sign(
-  context_json: Value
+pub fn rust_bls_public_key_cache_warm(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert 'secret_key' base64 string to `SecretKey` instance
-  let secret_key;
-  match context_json["secret_key"].as_str() {
-    Some(secret_key_b64) => {
-      let secret_key_bytes = base64::decode(secret_key_b64).unwrap().to_vec();
-      secret_key = SecretKey::from(*array_ref![
-        secret_key_bytes,
-        0,
-        FR_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'secret_key'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_public_key_cache_warm);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<PublicKeyCacheWarmRequest>(context_json)?;
 
-  // convert 'public_key' base64 string to `PublicKey` instance
-  let public_key = match context_json["public_key"].as_str() {
-    Some(public_key) => PublicKey::from_bytes_compressed_form(base64::decode(public_key).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'public_key'"); }
-  };
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(&request.public_key)?;
 
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
+  // map `message_counts` to Vec
+  let mut message_counts = Vec::with_capacity(request.message_counts.len());
+  for message_count in request.message_counts {
+    if message_count == 0 {
+      handle_err!("Invalid value for 'message_counts'. Must be integers greater than 0");
+    }
+    message_counts.push(message_count as usize);
   }
 
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
-  };
-
-  // convert messages base64 string to array of `SignatureMessage` instances
-  let mut messages = Vec::new();
-
-  for i in 0..messages_array.len() {
-    // add message to Vec
-    messages.push(SignatureMessage::hash(base64::decode(messages_array[i].as_str().unwrap()).unwrap().as_slice()));
+  if warm_public_key_cache(&dpk, message_counts.as_slice()).is_err() {
+    handle_err!("Failed to convert to BBS public key");
   }
 
-  // Serialize `Signature` to a JSON string
-  let signature = match Signature::new(messages.as_slice(), &secret_key, &public_key) {
-    Ok(signature) => signature,
-    Err(_) => { handle_err!("Failed to sign messages"); }
-  };
-
-  let bbs_signature = json!({
-    "signature": base64::encode(signature.to_bytes_compressed_form())
+  let cache_outcome = json!({
+    "cache_size": public_key_cache_len(),
   });
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&bbs_signature) {
+  match serde_json::to_string(&cache_outcome) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify BBS Signature"); },
+    Err(_) => { handle_err!("Failed to stringify public key cache outcome"); },
   }
 }
 
 #[allow(dead_code)]
-pub fn rust_bls_sign(
+pub fn rust_bls_public_key_cache_clear() -> i32 {
+  clear_public_key_cache() as i32
+}
+
+/// Set the global limits on the input of later calls, returning the limits in effect
+#[allow(dead_code)]
+pub fn rust_bbs_set_limits(
   mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
-  };
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_set_limits);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<SetLimitsRequest>(context_json)?;
+
+  // limits that aren't set are kept
+  let mut new_limits = limits();
+  let properties = [
+    ("max_message_count", request.max_message_count, &mut new_limits.max_message_count),
+    ("max_message_size", request.max_message_size, &mut new_limits.max_message_size),
+    ("max_context_size", request.max_context_size, &mut new_limits.max_context_size),
+    ("max_batch_size", request.max_batch_size, &mut new_limits.max_batch_size),
+  ];
+  for (property, value, limit) in properties {
+    match value {
+      Some(0) => { handle_err!(format!("Invalid '{}', expected a positive integer", property)); },
+      Some(value) => *limit = usize::try_from(value).unwrap_or(usize::MAX),
+      None => (),
+    }
+  }
+  set_limits(new_limits);
 
-  let message_count = messages_array.len() as i64;
-  
-  // convert 'public_key' base64 string to `DeterministicPublicKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
+  // Serialize return object to JSON string
+  match serde_json::to_string(&new_limits) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify limits"); },
   }
+}
 
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
+#[allow(dead_code)]
+pub fn rust_bls_keystore_import(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_keystore_import);
   }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<KeystoreImportRequest>(context_json)?;
+
+  // a given 'public_key', or that of a JWK 'secret_key', is checked to be the secret key's
+  let public_key = given_public_key(request.public_key.as_ref(), Some(&request.secret_key))?;
+
+  // convert base64 or JWK 'secret_key' to `SecretKey` instance
+  let secret_key = fixed_value(&secret_key_bytes(request.secret_key, "secret_key")?, "secret_key", FR_COMPRESSED_SIZE)?;
+  let key_handle = insert_key(secret_key)?;
+
+  let stored_public_key = stored_public_key(key_handle)?;
+  if public_key.is_some_and(|public_key| public_key != stored_public_key.to_bytes_compressed_form()) {
+    remove_key(key_handle);
+    handle_err!(PUBLIC_KEY_MISMATCH);
   }
 
-  let pk_bytes = pk.to_bytes_compressed_form();
-  
-  context_json["public_key"] = Value::String(base64::encode(pk_bytes.as_slice()));
-  
-  rust_bbs_sign(context_json)
+  stored_key_to_json(key_handle)
 }
 
 #[allow(dead_code)]
-pub fn rust_bbs_create_proof(
-  context_json: Value
+pub fn rust_bls_keystore_generate(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert 'signature' base64 string to `Signature` instance
-  let signature;
-  match context_json["signature"].as_str() {
-    Some(signature_b64) => {
-      let signature_b64 = base64::decode(signature_b64).unwrap().to_vec();
-      signature = Signature::from(*array_ref![
-        signature_b64,
-        0,
-        SIGNATURE_COMPRESSED_SIZE
-      ]);
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_keystore_generate);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<GenerateKeyRequest>(context_json)?;
+
+  let mut rng = context_rng(&request.deterministic_seed, "bls_keystore_generate")?;
+
+  // convert seed base64 string to slice
+  let (_, sk_bytes) = match request.seed {
+    Some(seed) => {
+      match decode_secret(&seed.0) {
+        Ok(seed_bytes) => bls_generate_g2_key(Some(seed_bytes), &mut rng),
+        Err(_) => { handle_err!("Failed decoding base64 for: 'seed'"); },
+      }
     },
-    None => { handle_err!("Property not set: 'signature'"); }
+    None => bls_generate_g2_key(None, &mut rng)
   };
 
-  // convert 'public_key' base64 string to `PublicKey` instance
-  let public_key = match context_json["public_key"].as_str() {
-    Some(public_key) => PublicKey::from_bytes_compressed_form(base64::decode(public_key).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'public_key'"); }
-  };
+  let secret_key = SecretKey::from(*array_ref![sk_bytes, 0, FR_COMPRESSED_SIZE]);
 
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
+  stored_key_to_json(insert_key(secret_key)?)
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_keystore_public_key(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_keystore_public_key);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<KeystoreKeyRequest>(context_json)?;
 
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
-  };
+  let stored_public_key = stored_public_key(request.key_handle)?;
 
-  // map `revealed` serde array values to Vec
-  let revealed_indices: Vec<i64> = match context_json["revealed"].as_array() {
-    Some(revealed) => revealed.into_iter().map(|b| match b.as_i64() {
-      Some(index) => index,
-      None => -1,
-    }).collect(),
-    None => { handle_err!("Property not set: 'revealed'"); }
-  };
+  let public_key = json!({
+    "public_key": base64::encode(stored_public_key.to_bytes_compressed_form().as_slice()),
+  });
 
-  let message_count = messages_array.len() as i64;
+  // Serialize return object to JSON string
+  match serde_json::to_string(&public_key) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify public key"); },
+  }
+}
 
-  let mut revealed = BTreeSet::new();
-  for i in 0..revealed_indices.len() {
-    let index = revealed_indices[i];
-    if index < 0 {
-      handle_err!(format!(
-        "Invalid index for 'revealed'. Must be integer between {} and {}",
-        0,
-        message_count
-      ));
-    }
-    if index > message_count {
-      handle_err!(format!(
-        "Index for 'revealed' is out of bounds. Must be between {} and {}: found {}",
-        0,
-        message_count,
-        index
-      ));
-    }
-    revealed.insert(index as usize);
+#[allow(dead_code)]
+pub fn rust_bls_keystore_destroy(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_keystore_destroy);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<KeystoreKeyRequest>(context_json)?;
 
-  let mut messages = Vec::new();
-  for i in 0..messages_array.len() {
-    let message = SignatureMessage::hash(base64::decode(messages_array[i].as_str().unwrap()).unwrap().as_slice());
+  let destroy_outcome = json!({
+    "destroyed": remove_key(request.key_handle),
+  });
 
-    if revealed.contains(&i) {
-      messages.push(
-        pm_revealed_raw!(message)
-      );
-    } else {
-      messages.push(pm_hidden_raw!(message));
-    }
+  // Serialize return object to JSON string
+  match serde_json::to_string(&destroy_outcome) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify destroy outcome"); },
   }
+}
 
-  // convert nonce base64 string to `ProofNonce` instance
-  let nonce = match context_json["nonce"].as_str() {
-    Some(nonce) => Some(base64::decode(nonce).unwrap()),
-    None => None
-  };
-
-  // check public key is valid
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
+#[allow(dead_code)]
+pub fn rust_bls_export_encrypted_key(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_export_encrypted_key);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<ExportEncryptedKeyRequest>(context_json)?;
 
-  let mut bitvector = (messages.len() as u16).to_be_bytes().to_vec();
-  bitvector.append(&mut revealed_to_bitvector(messages.len(), &revealed));
-  
-  let pok = match PoKOfSignature::init(
-    &signature,
-    &public_key,
-    &messages.as_slice()
-  ) {
-    Ok(pok) => pok,
-    Err(error) => {
-      handle_err!(format!("Failed generating proof of knowledge: {}", error));
-    }
+  // get key type
+  let key_type = match KeyType::from_name(&request.key_type) {
+    Some(key_type) => key_type,
+    None => { handle_err!(format!("Unsupported key type: {}", request.key_type)); }
   };
 
-  let mut challenge_bytes = pok.to_bytes();
-  if let Some(b) = nonce {
-    challenge_bytes.extend_from_slice(&ProofNonce::hash(b.as_slice()).to_bytes_compressed_form());
-  } else {
-    challenge_bytes.extend_from_slice(&[0u8; FR_COMPRESSED_SIZE]);
+  // convert base64, JWK or did:key 'public_key' to bytes
+  let public_key = public_key_bytes(&request.public_key)?;
+
+  // secret key, followed by blinding factor for blinded keys
+  let mut secret = Zeroizing::new(Vec::with_capacity(key_type.secret_size()));
+  secret.extend_from_slice(&scalar_secret(secret_key_bytes(request.secret_key, "secret_key")?, "secret_key")?);
+  if key_type.is_blinded() {
+    let blinding_factor = match &request.blinding_factor {
+      Some(blinding_factor) => secret_bytes(blinding_factor, "blinding_factor")?,
+      None => { handle_err!("Property not set: 'blinding_factor'"); }
+    };
+    secret.extend_from_slice(&scalar_secret(blinding_factor, "blinding_factor")?);
   }
 
-  let challenge_hash = ProofChallenge::hash(&challenge_bytes);
-  let pok = match pok.gen_proof(&challenge_hash) {
-    Ok(proof) => {
-      bitvector.extend_from_slice(proof.to_bytes_compressed_form().as_slice());
-      bitvector
-    },
-    Err(_) => { handle_err!("Failed generating proof"); }
-  };
-  
-  let proof = json!({
-    "proof": base64::encode(pok)
-  });
+  let password = password(&request.password)?;
+
+  let mut rng = context_rng(&request.deterministic_seed, "bls_export_encrypted_key")?;
+  let encrypted_key = encrypt_key(key_type, &public_key, &secret, password.as_bytes(), &mut rng)?;
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&proof) {
+  match serde_json::to_string(&encrypted_key) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify BBS Proof"); },
+    Err(_) => { handle_err!("Failed to stringify encrypted key"); },
   }
 }
 
 #[allow(dead_code)]
-pub fn rust_bls_create_proof(
+pub fn rust_bls_import_encrypted_key(
   mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
-  };
-
-  let message_count = messages_array.len() as i64;
-  
-  // convert 'public_key' base64 string to `DeterministicPublicKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_import_encrypted_key);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<ImportEncryptedKeyRequest>(context_json)?;
 
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
+  #[derive(Serialize)]
+  struct ImportedKey<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blinding_factor: Option<&'a str>,
+    key_type: &'a str,
+    public_key: &'a str,
+    secret_key: &'a str,
   }
 
-  let pk_bytes = pk.to_bytes_compressed_form();
-  
-  context_json["public_key"] = Value::String(base64::encode(pk_bytes.as_slice()));
+  let password = password(&request.password)?;
+
+  let encrypted_key: EncryptedKey = match serde_json::from_value(Value::Object(request.encrypted_key)) {
+    Ok(encrypted_key) => encrypted_key,
+    Err(error) => { handle_err!(format!("Invalid encrypted key: {}", error)); }
+  };
+
+  let (key_type, secret) = decrypt_key(&encrypted_key, password.as_bytes())?;
+
+  let secret_key = encode_secret(&secret[..FR_COMPRESSED_SIZE]);
+  let blinding_factor = if key_type.is_blinded() {
+    Some(encode_secret(&secret[FR_COMPRESSED_SIZE..]))
+  } else {
+    None
+  };
+
+  let imported_key = ImportedKey {
+    blinding_factor: blinding_factor.as_ref().map(|bf| bf.as_str()),
+    key_type: key_type.name(),
+    public_key: &encrypted_key.public_key,
+    secret_key: &secret_key,
+  };
+  let values = [
+    imported_key.blinding_factor.unwrap_or_default(),
+    imported_key.key_type,
+    imported_key.public_key,
+    imported_key.secret_key,
+  ];
 
-  rust_bbs_create_proof(context_json)
+  // Serialize return object to JSON string
+  match secret_to_json(&imported_key, &values) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify imported key"); },
+  }
 }
 
 #[allow(dead_code)]
-pub fn rust_bbs_verify_proof(
-  context_json: Value
+pub fn rust_bls_key_to_jwk(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert proof base64 string to `Proofproof` instance
-  let proof = match context_json["proof"].as_str() {
-    Some(proof) => base64::decode(proof).unwrap(),
-    None => { handle_err!("Property not set: 'proof'"); }
-  };
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_key_to_jwk);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<KeyToJwkRequest>(context_json)?;
 
-  // convert nonce base64 string to `ProofNonce` instance
-  let nonce = match context_json["nonce"].as_str() {
-    Some(nonce) => Some(base64::decode(nonce).unwrap()),
-    None => None
-  };
+  // convert 'public_key' base64 string to bytes
+  let public_key = decode_base64(&request.public_key, "public_key")?;
 
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
+  let curve = match JwkCurve::from_public_key(&public_key) {
+    Some(curve) => curve,
+    None => { handle_err!(format!("Invalid length for: 'public_key', expected {} or {} bytes", G1_COMPRESSED_SIZE, G2_COMPRESSED_SIZE)); }
   };
 
-  // convert messages base64 string to array of `SignatureMessage` instances
-  let mut messages = Vec::new();
+  // private JWK where 'secret_key' is set
+  let d = match &request.secret_key {
+    Some(secret_key) => Some(Zeroizing::new(base64url_encode(scalar_secret(secret_bytes(secret_key, "secret_key")?, "secret_key")?.as_slice()))),
+    None => None,
+  };
 
-  for i in 0..messages_array.len() {
-    // add message to Vec
-    messages.push(SignatureMessage::hash(base64::decode(messages_array[i].as_str().unwrap()).unwrap().as_slice()));
-  }
-  
-  // convert public key base64 string to `PublicKey` instance
-  let public_key = match context_json["public_key"].as_str() {
-    Some(public_key) => PublicKey::from_bytes_compressed_form(base64::decode(public_key).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'public_key'"); }
+  let x = base64url_encode(&public_key);
+  let jwk = Jwk {
+    kty: "OKP",
+    crv: curve.name(),
+    x: &x,
+    d: d.as_ref().map(|d| d.as_str()),
   };
+  let values = [jwk.kty, jwk.crv, jwk.x, jwk.d.unwrap_or_default()];
 
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
+  // Serialize return object to JSON string
+  match secret_to_json(&jwk, &values) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify JWK"); },
   }
+}
 
-  let message_count = u16::from_be_bytes(*array_ref![proof, 0, 2]) as usize;
-  let bitvector_length = (message_count / 8) + 1;
-  let offset = 2 + bitvector_length;
-  let revealed = bitvector_to_revealed(&proof[2..offset]);
+#[allow(dead_code)]
+pub fn rust_bls_jwk_to_key(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_jwk_to_key);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<JwkToKeyRequest>(context_json)?;
 
-  if messages.len() != revealed.len() {
-    handle_err!(format!("Given messages count ({}) is different from revealed messages count ({}) for this proof", messages.len(), revealed.len()));
+  #[derive(Serialize)]
+  struct BlsKey<'a> {
+    public_key: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret_key: Option<&'a str>,
   }
 
-  let proof = match PoKOfSignatureProof::from_bytes_compressed_form(&proof[offset..]) {
-    Ok(proof) => proof,
-    Err(error) => {
-      handle_err!(format!("Failed generating proof of knowledge: {}", error));
-    }
+  let mut jwk = match request.jwk {
+    SecretKeyInput::Jwk(jwk) => jwk,
+    SecretKeyInput::Encoded(_) => { handle_err!("Property must be a JWK object: 'jwk'"); }
   };
 
-  let nonce = match nonce {
-    Some(ref s) => ProofNonce::hash(s.as_slice()),
-    None => ProofNonce::from([0u8; FR_COMPRESSED_SIZE]),
+  let public_key = base64::encode(jwk_public_key(&jwk.0)?);
+  let secret_key = if jwk.0["d"].is_null() {
+    None
+  } else {
+    Some(encode_secret(&take_jwk_secret_key(&mut jwk.0)?))
   };
-  
-  let proof_request = ProofRequest {
-    revealed_messages: revealed.clone(),
-    verification_key: public_key,
+
+  let key = BlsKey {
+    public_key: &public_key,
+    secret_key: secret_key.as_ref().map(|sk| sk.as_str()),
   };
+  let values = [key.public_key, key.secret_key.unwrap_or_default()];
 
-  let revealed = revealed.iter().collect::<Vec<&usize>>();
-  let mut revealed_messages = BTreeMap::new();
-  for i in 0..revealed.len() {
-    revealed_messages.insert(*revealed[i], messages[i].clone());
+  // Serialize return object to JSON string
+  match secret_to_json(&key, &values) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify BLS key"); },
   }
+}
 
-  let signature_proof = SignatureProof {
-    revealed_messages,
-    proof,
-  };
+#[allow(dead_code)]
+pub fn rust_bls_public_key_to_multikey(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_public_key_to_multikey);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<PublicKeyEncodingRequest>(context_json)?;
 
-  let verified = match Verifier::verify_signature_pok(
-    &proof_request,
-    &signature_proof,
-    &nonce,
-  ) {
-    Ok(_) => true,
-    Err(_) => false
-  };
+  let public_key = public_key_bytes(&request.public_key)?;
 
-  let verify_outcome = json!({
-    "verified": verified,
+  let multikey = json!({
+    "public_key_multibase": encode_multikey(&public_key)?,
   });
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&verify_outcome) {
+  match serde_json::to_string(&multikey) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify BBS Proof Verification"); },
+    Err(_) => { handle_err!("Failed to stringify Multikey"); },
   }
 }
 
 #[allow(dead_code)]
-pub fn rust_bls_verify_proof(
+pub fn rust_bls_multikey_to_public_key(
   mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert proof base64 string to `Proofproof` instance
-  let proof = match context_json["proof"].as_str() {
-    Some(proof) => base64::decode(proof).unwrap(),
-    None => { handle_err!("Property not set: 'proof'"); }
-  };
-  let message_count = u16::from_be_bytes(*array_ref![proof, 0, 2]) as usize;
-  
-  // convert 'public_key' base64 string to `DeterministicPublicKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_multikey_to_public_key);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<MultikeyToPublicKeyRequest>(context_json)?;
 
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
-  }
+  let (code, public_key) = decode_multikey(&request.public_key_multibase)?;
 
-  let pk_bytes = pk.to_bytes_compressed_form();
-  
-  context_json["public_key"] = Value::String(base64::encode(pk_bytes.as_slice()));
+  let key = json!({
+    "key_type": if code == MULTICODEC_BLS12_381_G1_PUB { "g1" } else { "g2" },
+    "public_key": base64::encode(public_key.as_slice()),
+  });
 
-  rust_bbs_verify_proof(context_json)
+  // Serialize return object to JSON string
+  match serde_json::to_string(&key) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify public key"); },
+  }
 }
 
 #[allow(dead_code)]
-pub fn rust_bbs_blind_signature_commitment(
-  context_json: Value
+pub fn rust_bls_public_key_to_did_key(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert public key base64 string to `PublicKey` instance
-  let public_key = match context_json["public_key"].as_str() {
-    Some(public_key) => PublicKey::from_bytes_compressed_form(base64::decode(public_key).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'public_key'"); }
-  };
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_public_key_to_did_key);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<PublicKeyEncodingRequest>(context_json)?;
 
-  // get `blinded` values as array
-  let blinded = match context_json["blinded"].as_array() {
-    Some(blinded) => blinded,
-    None => { handle_err!("Property not set: 'blinded'"); }
-  };
+  let public_key = public_key_bytes(&request.public_key)?;
+  let did = encode_did_key(&public_key)?;
+  let key_id = format!("{}#{}", did, encode_multikey(&public_key)?);
 
-  // get `messages` values as array
-  let messages_to_blind = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
-  };
+  let did_key = json!({
+    "did": did,
+    "key_id": key_id,
+  });
 
-  if blinded.len() != messages_to_blind.len() {
-    handle_err!(format!(
-      "hidden length is not the same as messages length: {} != {}",
-      blinded.len(),
-      messages_to_blind.len()
-    ));
-  }
-
-  // convert nonce base64 string to `ProofNonce` instance
-  let nonce = match context_json["nonce"].as_str() {
-    Some(nonce) => ProofNonce::hash(base64::decode(nonce).unwrap().as_slice()),
-    None => ProofNonce::hash(b"bbs+rustffiwrapper".to_vec())
-  };
-  
-  // convert messages base64 string to array of `SignatureMessage` instances
-  let mut messages = BTreeMap::new();
-  let message_count = public_key.message_count() as u64;
-
-  for i in 0..blinded.len() {
-      let index = blinded[i].as_u64().unwrap();
-      if index > message_count {
-        handle_err!(format!(
-          "Index is out of bounds. Must be between {} and {}: found {}",
-          0,
-          public_key.message_count(),
-          index
-        ));
-      }
-
-      // add message to tree map
-      messages.insert(index as usize, SignatureMessage::hash(base64::decode(messages_to_blind[i].as_str().unwrap()).unwrap().as_slice()));
+  // Serialize return object to JSON string
+  match serde_json::to_string(&did_key) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify did:key"); },
   }
+}
 
-  // check public key is valid
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
+#[allow(dead_code)]
+pub fn rust_bls_resolve_did_key(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_resolve_did_key);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<ResolveDidKeyRequest>(context_json)?;
 
-  let (blinding_context, blinding_factor) = Prover::new_blind_signature_context(&public_key, &messages, &nonce).unwrap();
+  let (did, multikey, dpk) = resolve_did_key(&request.did)?;
 
-  let blind_commitment_context = json!({
-    "commitment": base64::encode(blinding_context.commitment.to_bytes_compressed_form().as_slice()),
-    "challenge_hash": base64::encode(blinding_context.challenge_hash.to_bytes_compressed_form().as_slice()),
-    "blinding_factor": base64::encode(blinding_factor.to_bytes_compressed_form().as_slice()),
-    "proof_of_hidden_messages": base64::encode(blinding_context.proof_of_hidden_messages.to_bytes_compressed_form().as_slice()),
+  let resolved = json!({
+    "did": did,
+    "key_id": format!("{}#{}", did, multikey),
+    "type": "Multikey",
+    "public_key_multibase": multikey,
+    "public_key": base64::encode(dpk.to_bytes_compressed_form().as_slice()),
   });
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&blind_commitment_context) {
+  match serde_json::to_string(&resolved) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify Blind Commitment Context"); },
+    Err(_) => { handle_err!("Failed to stringify resolved did:key"); },
   }
 }
 
 #[allow(dead_code)]
-pub fn rust_bls_blind_signature_commitment(
+pub fn rust_bbs_schema_hash(
   mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get `known_message_count` value as integer
-  let known_message_count = match context_json["known_message_count"].as_i64() {
-    Some(known_message_count) => known_message_count,
-    None => { handle_err!("Property not set: 'known_message_count'"); }
-  };
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_schema_hash);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<SchemaHashRequest>(context_json)?;
 
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
+  let schema = match request.schema {
+    Value::Null => { handle_err!("Property not set: 'schema'"); },
+    ref schema => Schema::from_json(schema)?,
   };
 
-  let message_count = messages_array.len() as i64 + known_message_count;
-  
-  // convert 'public_key' base64 string to `DeterministicPublicKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
-  }
+  let schema_hash = json!({
+    "schema_hash": schema.hash_message(),
+    "message_count": schema.message_count(),
+  });
 
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
+  // Serialize return object to JSON string
+  match serde_json::to_string(&schema_hash) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify schema hash"); },
   }
-
-  let pk_bytes = pk.to_bytes_compressed_form();
-  
-  context_json["public_key"] = Value::String(base64::encode(pk_bytes.as_slice()));
-  
-  rust_bbs_blind_signature_commitment(context_json)
 }
 
 #[allow(dead_code)]
-pub fn rust_bbs_verify_blind_signature_proof(
-  context_json: Value
+pub fn rust_bbs_sign(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert 'commitment' base64 string to `Commitment` instance
-  let commitment;
-  match context_json["commitment"].as_str() {
-    Some(commitment_b64) => {
-      let commitment_b64 = base64::decode(commitment_b64).unwrap().to_vec();
-      commitment = Commitment::from(*array_ref![
-        commitment_b64,
-        0,
-        G1_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'commitment'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_sign);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsSignRequest>(context_json)?;
 
-  // convert 'challenge_hash' base64 string to `ProofChallenge` instance
-  let challenge_hash;
-  match context_json["challenge_hash"].as_str() {
-    Some(challenge_hash_b64) => {
-      let challenge_hash_b64 = base64::decode(challenge_hash_b64).unwrap().to_vec();
-      challenge_hash = ProofChallenge::from(*array_ref![
-        challenge_hash_b64,
-        0,
-        FR_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'challenge_hash'"); }
-  }
-
-  // convert public key base64 string to `PublicKey` instance
-  let public_key = match context_json["public_key"].as_str() {
-    Some(public_key) => PublicKey::from_bytes_compressed_form(base64::decode(public_key).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'public_key'"); }
-  };
-
-  // convert public key base64 string to `PublicKey` instance
-  let proof_of_hidden_messages = match context_json["proof_of_hidden_messages"].as_str() {
-    Some(proof_of_hidden_messages) => ProofG1::from_bytes_compressed_form(base64::decode(proof_of_hidden_messages).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'proof_of_hidden_messages'"); }
-  };
-  
-  // map `blinded` serde array values to Vec
-  let blinded: Vec<i64> = match context_json["blinded"].as_array() {
-    Some(blinded) => blinded.into_iter().map(|b| match b.as_i64() {
-      Some(index) => index,
-      None => -1
-    }).collect(),
-    None => { handle_err!("Blinded message indexes array not set"); }
-  };
+  // map named 'claims' or a JSON 'document' to positional 'messages'
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
 
-  // convert nonce base64 string to `ProofNonce` instance
-  let nonce = match context_json["nonce"].as_str() {
-    Some(nonce) => ProofNonce::hash(base64::decode(nonce).unwrap().as_slice()),
-    None => ProofNonce::hash(b"bbs+rustffiwrapper".to_vec())
-  };
+  // get `SecretKey` by 'key_handle', or from 'secret_key' base64 string
+  let secret_key = signing_key(request.key_handle, request.secret_key)?;
 
-  let commitment_context = BlindSignatureContext {
-    commitment,
-    proof_of_hidden_messages,
-    challenge_hash,
-  };
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = decode_bbs_public_key(&request.public_key)?;
 
-  // check public key is valid
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
-  }
+  bbs_sign(&secret_key, &public_key, &required(messages.messages, "messages")?, &request.deterministic_seed)
+}
 
-  let mut messages: BTreeSet<usize> = (0..public_key.message_count()).collect();
-  let message_count = public_key.message_count() as i64;
+/// Sign base64 messages with a BBS key
+fn bbs_sign(
+  secret_key: &SigningKey,
+  public_key: &BbsPublicKey,
+  messages: &[String],
+  deterministic_seed: &Option<DeterministicSeed>,
+) -> Result<String, BBSError> {
+  // convert messages base64 string to array of message scalars
+  let messages = signature_messages(messages)?;
 
-  for i in 0..blinded.len() {
-      let index = blinded[i];
-      if index < 0 {
-        handle_err!(format!(
-          "Invalid index for 'blinded'. Must be integer between {} and {}",
-          0,
-          message_count
-        ));
-      }
-      if index > message_count {
-        handle_err!(format!(
-          "Index is out of bounds. Must be between {} and {}: found {}",
-          0,
-          public_key.message_count(),
-          index
-        ));
-      }
-      messages.remove(&(index as usize));
-  }
-  
-  let verified = match commitment_context.verify(&messages, &public_key, &nonce) {
-    Ok(b) => b,
-    Err(_) => false,
+  // Serialize `Signature` to a JSON string
+  let mut rng = context_rng(deterministic_seed, "bbs_sign")?;
+  let signature = match secret_key.with(|secret_key| bbs_plus_backend::sign(secret_key, public_key, messages.as_slice(), &mut rng))? {
+    Ok(signature) => signature,
+    Err(_) => { handle_err!("Failed to sign messages"); }
   };
 
-  let verify_outcome = json!({
-    "verified": verified,
+  let bbs_signature = json!({
+    "signature": base64::encode(signature.to_bytes())
   });
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&verify_outcome) {
+  match serde_json::to_string(&bbs_signature) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify Blind Commitment Verification"); },
+    Err(_) => { handle_err!("Failed to stringify BBS Signature"); },
   }
 }
 
 #[allow(dead_code)]
-pub fn rust_bls_verify_blind_signature_proof(
+pub fn rust_bls_sign(
   mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get `known_message_count` value as integer
-  let known_message_count = match context_json["known_message_count"].as_i64() {
-    Some(known_message_count) => known_message_count,
-    None => { handle_err!("Property not set: 'known_message_count'"); }
-  };
-
-  // get `blinded` values as array
-  let blinded_array = match context_json["blinded"].as_array() {
-    Some(blinded) => blinded,
-    None => { handle_err!("Property not set: 'blinded'"); }
-  };
-
-  let message_count = blinded_array.len() as i64 + known_message_count;
-  
-  // convert 'public_key' base64 string to `DeterministicPublicKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
-  }
-
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
-  }
-
-  let pk_bytes = pk.to_bytes_compressed_form();
-  
-  context_json["public_key"] = Value::String(base64::encode(pk_bytes.as_slice()));
-  
-  rust_bbs_verify_blind_signature_proof(context_json)
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_sign);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsSignRequest>(context_json)?;
+
+  // map named 'claims' or a JSON 'document' to positional 'messages'
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
+  let messages = required(messages.messages, "messages")?;
+
+  // get `SecretKey` by 'key_handle', or from 'secret_key', and its `DeterministicPublicKey`
+  let (secret_key, dpk) = signer_key_pair(request.key_handle, request.secret_key, request.public_key.as_ref())?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, messages.len())?;
+
+  bbs_sign(&secret_key, &pk, &messages, &request.deterministic_seed)
 }
 
 #[allow(dead_code)]
-pub fn rust_bbs_blind_sign(
-  context_json: Value
+pub fn rust_bbs_ietf_sign(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert 'secret_key' base64 string to `SecretKey` instance
-  let secret_key;
-  match context_json["secret_key"].as_str() {
-    Some(secret_key_b64) => {
-      let secret_key_b64 = base64::decode(secret_key_b64).unwrap().to_vec();
-      secret_key = SecretKey::from(*array_ref![
-        secret_key_b64,
-        0,
-        FR_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'secret_key'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_ietf_sign);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<IetfSignRequest>(context_json)?;
 
-  // convert public key base64 string to `PublicKey` instance
-  let public_key = match context_json["public_key"].as_str() {
-    Some(public_key) => PublicKey::from_bytes_compressed_form(base64::decode(public_key).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'public_key'"); }
-  };
+  let (mut secret_key, public_key) = ietf_key_pair(request.key_handle, request.secret_key, request.public_key.as_ref())?;
+  let header = optional_base64(&request.header, "header")?;
+  let messages = base64_array(&request.messages, "messages")?;
 
-  // check public key is valid
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
-  }
-  
-  // map `known` serde array values to Vec
-  let known: Vec<i64> = match context_json["known"].as_array() {
-    Some(known) => known.into_iter().map(|b| match b.as_i64() {
-      Some(index) => index,
-      None => -1
-    }).collect(),
-    None => { handle_err!("Known message indexes array not set"); }
-  };
+  let signature = ietf_bbs::sign::<DefaultBackend>(&secret_key, &public_key, &header, &messages);
+  secret_key.zeroize();
 
-  // get `messages` values as array
-  let messages_visible = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Messages data array not set"); }
-  };
+  let bbs_signature = json!({
+    "signature": base64::encode(signature?)
+  });
 
-  if known.len() != messages_visible.len() {
-    handle_err!(format!(
-      "known length is not the same as messages length: {} != {}",
-      known.len(),
-      messages_visible.len()
-    ));
+  // Serialize return object to JSON string
+  match serde_json::to_string(&bbs_signature) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify BBS Signature"); },
   }
+}
 
-  // convert messages base64 string to array of `SignatureMessage` instances
-  let mut messages = BTreeMap::new();
-  let message_count = public_key.message_count() as i64;
-
-  for i in 0..known.len() {
-      let index = known[i];
-      if index < 0 {
-        handle_err!(format!(
-          "Invalid index for 'known'. Must be integer between {} and {}",
-          0,
-          message_count
-        ));
-      }
-      if index > message_count {
-        handle_err!(format!(
-          "Index is out of bounds. Must be between {} and {}: found {}",
-          0,
-          public_key.message_count(),
-          index
-        ));
-      }
-
-      // add message to tree map
-      messages.insert(index as usize, SignatureMessage::hash(base64::decode(messages_visible[i].as_str().unwrap()).unwrap().as_slice()));
-  }
+#[allow(dead_code)]
+pub fn rust_bbs_ietf_verify(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_ietf_verify);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<IetfVerifyRequest>(context_json)?;
+
+  // take 'signature', 'header' and 'messages' from an IETF BBS COSE 'credential'
+  let credential = ContextMessages {
+    credential: request.credential,
+    signature: request.signature,
+    header: request.header,
+    messages: request.messages,
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsIetf)?;
+
+  let public_key = public_key_bytes(&request.public_key)?;
+  let signature = decode_base64(&required(credential.signature, "signature")?, "signature")?;
+  let header = optional_base64(&credential.header, "header")?;
+  let messages = base64_array(&required(credential.messages, "messages")?, "messages")?;
+
+  let verified = json!({
+    "verified": ietf_bbs::verify::<DefaultBackend>(&public_key, &signature, &header, &messages)?
+  });
 
-  // convert 'commitment' base64 string to `Commitment` instance
-  let commitment;
-  match context_json["commitment"].as_str() {
-    Some(commitment_b64) => {
-      let commitment_b64 = base64::decode(commitment_b64).unwrap().to_vec();
-      commitment = Commitment::from(*array_ref![
-        commitment_b64,
-        0,
-        G1_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'commitment'"); }
+  // Serialize return object to JSON string
+  match serde_json::to_string(&verified) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify verification result"); },
   }
+}
 
-  let blind_signature = match BlindSignature::new(
-    &commitment,
-    &messages,
-    &secret_key,
-    &public_key
-  ) {
-    Ok(blind_signature) => blind_signature,
-    Err(_) => { handle_err!("Failed to generate Blind Signature"); },
-  };
+#[allow(dead_code)]
+pub fn rust_bbs_ietf_create_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_ietf_create_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<IetfCreateProofRequest>(context_json)?;
+
+  // take 'signature', 'header' and 'messages' from an IETF BBS COSE 'credential'
+  let credential = ContextMessages {
+    credential: request.credential,
+    signature: request.signature,
+    header: request.header,
+    messages: request.messages,
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsIetf)?;
+
+  let public_key = public_key_bytes(&request.public_key)?;
+  let signature = decode_base64(&required(credential.signature, "signature")?, "signature")?;
+  let header = optional_base64(&credential.header, "header")?;
+  let presentation_header = optional_base64(&request.presentation_header, "presentation_header")?;
+  let messages = base64_array(&required(credential.messages, "messages")?, "messages")?;
+  let revealed = sorted_indexes(&request.revealed);
+  let mut rng = context_rng(&request.deterministic_seed, "bbs_ietf_create_proof")?;
 
-  let signature_outcome = json!({
-    "blind_signature": base64::encode(blind_signature.to_bytes_compressed_form().as_slice()),
+  let proof = json!({
+    "proof": base64::encode(ietf_bbs::proof_gen::<DefaultBackend, _>(&public_key, &signature, &header, &presentation_header, &messages, &revealed, &mut rng)?)
   });
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&signature_outcome) {
+  match serde_json::to_string(&proof) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify Blind Signature"); },
+    Err(_) => { handle_err!("Failed to stringify BBS Proof"); },
   }
 }
 
+/// Verify an IETF BBS proof, given only the revealed 'messages' along with their 'revealed' indices
 #[allow(dead_code)]
-pub fn rust_bls_blind_sign(
+pub fn rust_bbs_ietf_verify_proof(
   mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get `blinded_message_count` value as integer
-  let blinded_message_count = match context_json["blinded_message_count"].as_i64() {
-    Some(blinded_message_count) => blinded_message_count,
-    None => { handle_err!("Property not set: 'blinded_message_count'"); }
-  };
-
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
-  };
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_ietf_verify_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<IetfVerifyProofRequest>(context_json)?;
+
+  let public_key = public_key_bytes(&request.public_key)?;
+  let proof = decode_base64(&request.proof, "proof")?;
+  let header = optional_base64(&request.header, "header")?;
+  let presentation_header = optional_base64(&request.presentation_header, "presentation_header")?;
+  let messages = base64_array(&request.messages, "messages")?;
+  let revealed = sorted_indexes(&request.revealed);
+
+  let verified = json!({
+    "verified": ietf_bbs::proof_verify::<DefaultBackend>(&public_key, &proof, &header, &presentation_header, &messages, &revealed)?
+  });
 
-  let message_count = messages_array.len() as i64 + blinded_message_count;
-  
-  // convert 'public_key' base64 string to `DeterministicPublicKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
+  // Serialize return object to JSON string
+  match serde_json::to_string(&verified) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify verification result"); },
   }
+}
 
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
+#[allow(dead_code)]
+pub fn rust_rdf_canonize(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_rdf_canonize);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<RdfCanonizeRequest>(context_json)?;
+
+  let canonical = json!({
+    "document": bbs_2023::canonical_statements(&request.document)?.concat()
+  });
 
-  let pk_bytes = pk.to_bytes_compressed_form();
-  
-  context_json["public_key"] = Value::String(base64::encode(pk_bytes.as_slice()));
-  
-  rust_bbs_blind_sign(context_json)
+  // Serialize return object to JSON string
+  match serde_json::to_string(&canonical) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify canonical document"); },
+  }
 }
 
+/// Create a `bbs-2023` base proof over an N-Quads 'document', with 'mandatory_indexes' addressing
+/// statements of its canonical form
 #[allow(dead_code)]
-pub fn rust_bbs_unblind_signature(
-  context_json: Value
+pub fn rust_bbs_2023_create_base_proof(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert 'blind_signature' base64 string to `BlindSignature` instance
-  let blind_signature;
-  match context_json["blind_signature"].as_str() {
-    Some(blind_signature_b64) => {
-      let blind_signature_b64 = base64::decode(blind_signature_b64).unwrap().to_vec();
-      blind_signature = BlindSignature::from(*array_ref![
-        blind_signature_b64,
-        0,
-        SIGNATURE_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'blind_signature'"); }
-  };
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_2023_create_base_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<Bbs2023CreateBaseProofRequest>(context_json)?;
 
-  // convert 'blinding_factor' base64 string to `SignatureBlinding` instance
-  let blinding_factor;
-  match context_json["blinding_factor"].as_str() {
-    Some(blinding_factor_b64) => {
-      let blinding_factor_b64 = base64::decode(blinding_factor_b64).unwrap().to_vec();
-      blinding_factor = SignatureBlinding::from(*array_ref![
-        blinding_factor_b64,
-        0,
-        FR_COMPRESSED_SIZE
-      ]);
+  let (mut secret_key, public_key) = ietf_key_pair(request.key_handle, request.secret_key, request.public_key.as_ref())?;
+  let mandatory_indexes = match &request.mandatory_indexes {
+    Some(mandatory_indexes) => sorted_indexes(mandatory_indexes),
+    None => Vec::new(),
+  };
+  let hmac_key = match &request.hmac_key {
+    Some(hmac_key) => match secret_bytes(hmac_key, "hmac_key")? {
+      hmac_key if hmac_key.len() == bbs_2023::HMAC_KEY_LENGTH => hmac_key,
+      _ => { handle_err!(format!("Invalid length for: 'hmac_key', expected {} bytes", bbs_2023::HMAC_KEY_LENGTH)); },
+    },
+    None => {
+      let mut hmac_key = Zeroizing::new(vec![0u8; bbs_2023::HMAC_KEY_LENGTH]);
+      context_rng(&request.deterministic_seed, "bbs_2023_create_base_proof")?.fill_bytes(&mut hmac_key);
+      hmac_key
     },
-    None => { handle_err!("Property not set: 'blinding_factor'"); }
   };
 
-  let unblinded_signature = blind_signature.to_unblinded(&blinding_factor);
-
-  let signature_outcome = json!({
-    "signature": base64::encode(unblinded_signature.to_bytes_compressed_form().as_slice()),
+  let proof_value = bbs_2023::create_base_proof(
+    &secret_key,
+    &public_key,
+    &request.document,
+    &request.proof_config,
+    &mandatory_indexes,
+    &hmac_key,
+  );
+  secret_key.zeroize();
+
+  let base_proof = json!({
+    "proof_value": proof_value?
   });
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&signature_outcome) {
+  match serde_json::to_string(&base_proof) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify Unblinded Signature"); },
+    Err(_) => { handle_err!("Failed to stringify base proof"); },
   }
 }
 
+/// Derive a `bbs-2023` proof disclosing the mandatory and 'selective_indexes' statements of the
+/// canonical form of the signed N-Quads 'document'
 #[allow(dead_code)]
-pub fn rust_bbs_verify(
-  context_json: Value
+pub fn rust_bbs_2023_create_derived_proof(
+  mut context_json: Value
 ) -> Result<String, BBSError> {
-  // convert public key base64 string to `PublicKey` instance
-  let public_key = match context_json["public_key"].as_str() {
-    Some(public_key) => PublicKey::from_bytes_compressed_form(base64::decode(public_key).unwrap().as_slice()).unwrap(),
-    None => { handle_err!("Property not set: 'public_key'"); }
-  };
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_2023_create_derived_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<Bbs2023CreateDerivedProofRequest>(context_json)?;
+
+  let selective_indexes = sorted_indexes(&request.selective_indexes);
+  let presentation_header = optional_base64(&request.presentation_header, "presentation_header")?;
+  let mut rng = context_rng(&request.deterministic_seed, "bbs_2023_create_derived_proof")?;
+
+  let (document, proof_value) = bbs_2023::create_derived_proof(
+    &request.document,
+    &request.proof_value,
+    &selective_indexes,
+    &presentation_header,
+    &mut rng,
+  )?;
+
+  let derived_proof = json!({
+    "document": document,
+    "proof_value": proof_value,
+  });
 
-  // check public key is valid
-  if public_key.validate().is_err() {
-    handle_err!("Invalid public key");
+  // Serialize return object to JSON string
+  match serde_json::to_string(&derived_proof) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify derived proof"); },
   }
+}
 
-  // convert 'blind_signature' base64 string to `BlindSignature` instance
-  let signature;
-  match context_json["signature"].as_str() {
-    Some(signature_b64) => {
-      let signature_b64 = base64::decode(signature_b64).unwrap().to_vec();
-      signature = Signature::from(*array_ref![
-        signature_b64,
-        0,
-        SIGNATURE_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'signature'"); }
-  };
-
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
-  };
-
-  // convert messages base64 string to array of `SignatureMessage` instances
-  let mut messages = Vec::new();
-
-  for i in 0..messages_array.len() {
-      // add message to Vec
-      messages.push(SignatureMessage::hash(base64::decode(messages_array[i].as_str().unwrap()).unwrap().as_slice()));
+#[allow(dead_code)]
+pub fn rust_bbs_2023_verify_derived_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_2023_verify_derived_proof);
   }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<Bbs2023VerifyDerivedProofRequest>(context_json)?;
 
-  let verified = match signature.verify(messages.as_slice(), &public_key) {
-    Ok(verified) => verified,
-    Err(_) => { handle_err!("Failed to verify Signature"); },
-  };
+  let public_key = public_key_bytes(&request.public_key)?;
+  let verified = bbs_2023::verify_derived_proof(
+    &public_key,
+    &request.document,
+    &request.proof_value,
+    &request.proof_config,
+  )?;
 
-  let verify_outcome = json!({
-    "verified": verified,
+  let verified = json!({
+    "verified": verified
   });
 
   // Serialize return object to JSON string
-  match serde_json::to_string(&verify_outcome) {
+  match serde_json::to_string(&verified) {
     Ok(json_string) => Ok(json_string),
-    Err(_) => { handle_err!("Failed to stringify Signature verification"); },
+    Err(_) => { handle_err!("Failed to stringify verification result"); },
   }
 }
 
+/// Issue a JSON Web Proof signing base64 'payloads' under the issuer protected 'header'
 #[allow(dead_code)]
-pub fn rust_bls_verify(
+pub fn rust_jwp_issue(
   mut context_json: Value
 ) -> Result<String, BBSError> {
-  // get `messages` values as array
-  let messages_array = match context_json["messages"].as_array() {
-    Some(messages) => messages,
-    None => { handle_err!("Property not set: 'messages'"); }
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, JWP_BYTE_PROPERTIES, rust_jwp_issue);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<JwpIssueRequest>(context_json)?;
+
+  let (mut secret_key, public_key) = ietf_key_pair(request.key_handle, request.secret_key, request.public_key.as_ref())?;
+  let issuer_header = protected_header(request.header.unwrap_or_default(), "header")?;
+  let payloads = base64_array(&request.payloads, "payloads")?;
+  let serialization = request.serialization.unwrap_or(Serialization::Compact);
+
+  let signature = ietf_bbs::sign::<DefaultBackend>(&secret_key, &public_key, &issuer_header, &payloads);
+  secret_key.zeroize();
+
+  let jwp = Jwp {
+    presentation_header: None,
+    issuer_header,
+    payloads: payloads.into_iter().map(Some).collect(),
+    proof: signature?,
   };
+  let issued = json!({
+    "jwp": jwp.serialize(serialization)?
+  });
 
-  let message_count = messages_array.len() as i64;
-  
-  // convert 'public_key' base64 string to `DeterministicPublicKey` instance
-  let dpk;
-  match context_json["public_key"].as_str() {
-    Some(public_key_b64) => {
-      let public_key_bytes = base64::decode(public_key_b64).unwrap().to_vec();
-      dpk = DeterministicPublicKey::from(*array_ref![
-        public_key_bytes,
-        0,
-        DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE
-      ]);
-    },
-    None => { handle_err!("Property not set: 'public_key'"); }
-  }
-
-  // convert deterministic public key to compressed BBS public key
-  let pk;
-  match dpk.to_public_key(message_count as usize) {
-    Ok(p) => pk = p,
-    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
-  }
-  if pk.validate().is_err() {
-    handle_err!("Failed to validate public key");
+  // Serialize return object to JSON string
+  match serde_json::to_string(&issued) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify JWP"); },
   }
-
-  let pk_bytes = pk.to_bytes_compressed_form();
-  
-  context_json["public_key"] = Value::String(base64::encode(pk_bytes.as_slice()));
-  
-  rust_bbs_verify(context_json)
 }
 
-/// Expects `revealed` to be sorted
-fn revealed_to_bitvector(total: usize, revealed: &BTreeSet<usize>) -> Vec<u8> {
-    let mut bytes = vec![0u8; (total / 8) + 1];
-
-    for r in revealed {
-        let idx = *r / 8;
-        let bit = (*r % 8) as u8;
-        bytes[idx] |= 1u8 << bit;
+/// Present an issued JSON Web Proof, disclosing the 'revealed' payloads under a presentation
+/// protected header holding the verifier's 'nonce' and 'audience'
+#[allow(dead_code)]
+pub fn rust_jwp_present(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, JWP_BYTE_PROPERTIES, rust_jwp_present);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<JwpPresentRequest>(context_json)?;
+
+  let public_key = public_key_bytes(&request.public_key)?;
+  let issued = Jwp::from_value(&request.jwp)?;
+  if issued.presentation_header.is_some() {
+    handle_err!("Invalid 'jwp', expected the issued form");
+  }
+  parse_protected_header(&issued.issuer_header, "issuer header")?;
+  let revealed = sorted_indexes(&request.revealed);
+  let serialization = request.serialization.unwrap_or(Serialization::Compact);
+
+  let mut header = request.presentation_header.unwrap_or_default();
+  for (value, member) in [(request.nonce, "nonce"), (request.audience, "aud")] {
+    if let Some(value) = value {
+      header.insert(member.to_string(), Value::from(value));
     }
+  }
+  let presentation_header = protected_header(header, "presentation_header")?;
 
-    // Convert to big endian
-    bytes.reverse();
-    bytes
+  let payloads: Vec<Vec<u8>> = issued.payloads.into_iter().flatten().collect();
+  let mut rng = context_rng(&request.deterministic_seed, "jwp_present")?;
+  let proof = ietf_bbs::proof_gen::<DefaultBackend, _>(&public_key, &issued.proof, &issued.issuer_header, &presentation_header, &payloads, &revealed, &mut rng)?;
+
+  let jwp = Jwp {
+    presentation_header: Some(presentation_header),
+    issuer_header: issued.issuer_header,
+    payloads: payloads.into_iter().enumerate()
+      .map(|(index, payload)| if revealed.binary_search(&index).is_ok() { Some(payload) } else { None })
+      .collect(),
+    proof,
+  };
+  let presented = json!({
+    "jwp": jwp.serialize(serialization)?
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&presented) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify JWP"); },
+  }
+}
+
+/// Verify an issued or presented JSON Web Proof, and for a presentation that its header holds
+/// the expected 'nonce' and 'audience' where set
+#[allow(dead_code)]
+pub fn rust_jwp_verify(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, JWP_BYTE_PROPERTIES, rust_jwp_verify);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<JwpVerifyRequest>(context_json)?;
+
+  let public_key = public_key_bytes(&request.public_key)?;
+  let jwp = Jwp::from_value(&request.jwp)?;
+  let issuer_header = parse_protected_header(&jwp.issuer_header, "issuer header")?;
+
+  let (verified, presentation_header) = match &jwp.presentation_header {
+    None => {
+      let payloads: Vec<Vec<u8>> = jwp.payloads.iter().flatten().cloned().collect();
+      (ietf_bbs::verify::<DefaultBackend>(&public_key, &jwp.proof, &jwp.issuer_header, &payloads)?, Value::Null)
+    },
+    Some(header_bytes) => {
+      let header = parse_protected_header(header_bytes, "presentation header")?;
+      let (payloads, indexes) = jwp.disclosed_payloads();
+      let expected = [(&request.nonce, "nonce"), (&request.audience, "aud")].iter()
+        .all(|(value, member)| value.as_ref().is_none_or(|value| header[*member] == *value));
+      let verified = expected && ietf_bbs::proof_verify::<DefaultBackend>(&public_key, &jwp.proof, &jwp.issuer_header, header_bytes, &payloads, &indexes)?;
+      (verified, header)
+    },
+  };
+
+  let mut verification = json!({
+    "verified": verified,
+    "header": issuer_header,
+    "payloads": jwp.payloads.iter().map(|payload| payload.as_ref().map(base64::encode)).collect::<Vec<Option<String>>>(),
+  });
+  if !presentation_header.is_null() {
+    verification["presentation_header"] = presentation_header;
+  }
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&verification) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify verification result"); },
+  }
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_create_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_create_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsCreateProofRequest>(context_json)?;
+
+  // map a COSE 'credential', named 'claims' or a JSON 'document' to positional 'messages' and indices
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    credential: request.credential,
+    signature: request.signature,
+    reveal: request.reveal,
+    revealed: request.revealed.as_deref().map(sorted_indexes),
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
+
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = decode_bbs_public_key(&request.public_key)?;
+
+  let proof = bbs_create_proof(&public_key, &messages, &request.nonce, &request.deterministic_seed)?;
+
+  proof_to_json(&proof)
+}
+
+/// Create a base64 proof of knowledge of a BBS signature over the context's messages
+fn bbs_create_proof(
+  public_key: &BbsPublicKey,
+  messages: &ContextMessages,
+  nonce: &Option<String>,
+  deterministic_seed: &Option<DeterministicSeed>,
+) -> Result<String, BBSError> {
+  // convert 'signature' base64 string to `Signature` instance
+  let signature: BbsSignature = decode_fixed(required(messages.signature.as_ref(), "signature")?, "signature", SIGNATURE_COMPRESSED_SIZE)?;
+
+  // get `messages` values as array
+  let messages_array = required(messages.messages.as_ref(), "messages")?;
+
+  let message_count = messages_array.len() as i64;
+
+  let mut revealed = BTreeSet::new();
+  for index in required(messages.revealed.as_ref(), "revealed")? {
+    if *index as i64 >= message_count {
+      handle_err!(format!(
+        "Index for 'revealed' is out of bounds. Must be between {} and {}: found {}",
+        0,
+        message_count - 1,
+        index
+      ));
+    }
+    revealed.insert(*index);
+  }
+
+  // convert messages base64 string to array of message scalars
+  let proof_messages = signature_messages(messages_array)?;
+
+  // convert nonce base64 string to a nonce scalar, which is zero without a nonce
+  let nonce = proof_nonce(nonce)?;
+
+  let mut rng = context_rng(deterministic_seed, "bbs_create_proof")?;
+  let proof = match bbs_plus_backend::create_proof(
+    &signature,
+    public_key,
+    proof_messages.as_slice(),
+    &revealed,
+    &nonce,
+    &mut rng,
+  ) {
+    Ok(proof) => proof,
+    Err(error) => {
+      handle_err!(format!("Failed generating proof of knowledge: {}", error));
+    }
+  };
+  let pok = encode_proof(
+    Ciphersuite::Bls12381G2Blake2b,
+    proof_messages.len(),
+    &revealed,
+    proof.to_bytes().as_slice(),
+  )?;
+
+  Ok(base64::encode(pok))
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_create_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_create_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsCreateProofRequest>(context_json)?;
+
+  // map a COSE 'credential', named 'claims' or a JSON 'document' to positional 'messages' and indices
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    credential: request.credential,
+    signature: request.signature,
+    reveal: request.reveal,
+    revealed: request.revealed.as_deref().map(sorted_indexes),
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
+  let message_count = required(messages.messages.as_ref(), "messages")?.len();
+
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(&request.public_key)?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, message_count)?;
+
+  let proof = bbs_create_proof(&pk, &messages, &request.nonce, &request.deterministic_seed)?;
+
+  proof_to_json(&proof)
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_verify_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_verify_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsVerifyProofRequest>(context_json)?;
+
+  // map the revealed 'claims' of a 'schema' to positional 'messages'
+  let messages = verify_proof_messages(request.messages, &request.schema, &request.claims)?;
+
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = proof_public_key(&request.public_key)?;
+
+  proof_verification_to_json(&bbs_verify_proof(public_key, &request.proof, &messages, request.nonce)?)
+}
+
+/// Verify a base64 proof of knowledge of a BBS signature over the revealed base64 messages
+fn bbs_verify_proof(
+  public_key: BbsPublicKey,
+  proof: &str,
+  messages: &[String],
+  nonce: Option<String>,
+) -> Result<ProofVerification, BBSError> {
+  // convert nonce base64 string to a nonce scalar, which is zero without a nonce
+  let proof_nonce = proof_nonce(&nonce)?;
+
+  // convert messages base64 string to array of message scalars
+  let messages = signature_messages(messages)?;
+
+  // a proof that can't be decoded is reported in the verification result
+  let proof = match base64::decode(proof) {
+    Ok(proof) => proof,
+    Err(error) => return Ok(malformed_proof(nonce, format!("Failed decoding base64 for: 'proof': {}", error))),
+  };
+
+  let mut verification = ProofVerification::new(nonce);
+  verify_signature_proof(&proof, messages, public_key, &proof_nonce, &mut verification);
+
+  Ok(verification)
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_verify_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_verify_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsVerifyProofRequest>(context_json)?;
+
+  // map the revealed 'claims' of a 'schema' to positional 'messages'
+  let messages = verify_proof_messages(request.messages, &request.schema, &request.claims)?;
+
+  proof_verification_to_json(&bls_verify_proof(&request.public_key, &request.proof, &messages, request.nonce)?)
+}
+
+/// Verify a base64 proof of knowledge of a signature by a BLS key over the revealed base64 messages
+fn bls_verify_proof(
+  public_key: &PublicKeyInput,
+  proof: &str,
+  messages: &[String],
+  nonce: Option<String>,
+) -> Result<ProofVerification, BBSError> {
+  // read the message count the BBS public key is derived for from the proof
+  let message_count = match base64::decode(proof).map(|proof| decode_proof(&proof).map(|envelope| envelope.message_count)) {
+    Ok(Ok(message_count)) => message_count,
+    // a proof over too many messages is refused rather than reported as malformed
+    Ok(Err(error)) if error_code(&error).is_some() => return Err(error),
+    Ok(Err(error)) => return Ok(malformed_proof(nonce, error_detail(&error))),
+    Err(error) => return Ok(malformed_proof(nonce, format!("Failed decoding base64 for: 'proof': {}", error))),
+  };
+
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(public_key)?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, message_count)?;
+
+  bbs_verify_proof(pk, proof, messages, nonce)
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_create_presentation(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_create_presentation);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsCreatePresentationRequest>(context_json)?;
+
+  // map a COSE 'credential', named 'claims' or a JSON 'document' to positional 'messages' and indices
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    credential: request.credential,
+    signature: request.signature,
+    key_id: request.key_id,
+    reveal: request.reveal,
+    revealed: request.revealed.as_deref().map(sorted_indexes),
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
+
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = decode_bbs_public_key(&request.public_key)?;
+
+  let proof = bbs_create_proof(&public_key, &messages, &request.nonce, &request.deterministic_seed)?;
+  let key_id = messages.key_id.clone();
+
+  presentation_to_json(messages_presentation(messages, request.nonce, proof, key_id)?)
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_create_presentation(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_create_presentation);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsCreatePresentationRequest>(context_json)?;
+
+  // map a COSE 'credential', named 'claims' or a JSON 'document' to positional 'messages' and indices
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    credential: request.credential,
+    signature: request.signature,
+    key_id: request.key_id,
+    reveal: request.reveal,
+    revealed: request.revealed.as_deref().map(sorted_indexes),
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
+  let message_count = required(messages.messages.as_ref(), "messages")?.len();
+
+  // identify the signer's key by its did:key key ID, where given as a did:key
+  let key_id = match (&messages.key_id, &request.public_key) {
+    (Some(key_id), _) => Some(key_id.clone()),
+    (None, PublicKeyInput::Encoded(public_key)) if is_did_key(public_key) => {
+      let (did, multikey, _) = resolve_did_key(public_key)?;
+      Some(format!("{}#{}", did, multikey))
+    },
+    _ => None,
+  };
+
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(&request.public_key)?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, message_count)?;
+
+  let proof = bbs_create_proof(&pk, &messages, &request.nonce, &request.deterministic_seed)?;
+
+  presentation_to_json(messages_presentation(messages, request.nonce, proof, key_id)?)
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_verify_presentation(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_verify_presentation);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsVerifyPresentationRequest>(context_json)?;
+
+  let public_key = &request.public_key;
+  verify_presentation(&request.presentation, &request.nonce, &request.schema, |proof, messages, nonce| {
+    bbs_verify_proof(proof_public_key(public_key)?, proof, messages, nonce)
+  })
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_verify_presentation(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_verify_presentation);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsVerifyPresentationRequest>(context_json)?;
+
+  let public_key = &request.public_key;
+  verify_presentation(&request.presentation, &request.nonce, &request.schema, |proof, messages, nonce| {
+    bls_verify_proof(public_key, proof, messages, nonce)
+  })
+}
+
+/// Envelope a 'signature' over base64 'messages' as a base64 COSE_Sign1 'credential'
+#[allow(dead_code)]
+pub fn rust_bbs_cose_credential(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_cose_credential);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<CoseCredentialRequest>(context_json)?;
+
+  let credential = Credential {
+    algorithm: request.algorithm.unwrap_or(Algorithm::BbsPlus),
+    signature: decode_base64(&request.signature, "signature")?,
+    header: request.header.as_ref().map(|header| decode_base64(header, "header")).transpose()?,
+    messages: base64_array(&request.messages, "messages")?,
+    key_id: request.key_id,
+  };
+
+  let envelope = json!({
+    "credential": base64::encode(encode_credential(&credential)?)
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&envelope) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify COSE credential"); },
+  }
+}
+
+/// Envelope a 'presentation' as a base64 COSE_Sign1, which the verify presentation operations accept
+#[allow(dead_code)]
+pub fn rust_bbs_cose_presentation(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_cose_presentation);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<CosePresentationRequest>(context_json)?;
+
+  let presentation = parse_presentation(&request.presentation)?;
+
+  let envelope = json!({
+    "presentation": base64::encode(encode_presentation(&presentation)?)
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&envelope) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify COSE presentation"); },
+  }
+}
+
+/// Operation taking a JSON context and returning a JSON result
+pub type Operation = fn(Value) -> Result<String, BBSError>;
+
+/// Look up an operation by its wrapper name, e.g. `bls_sign`
+pub fn context_operation(name: &str) -> Option<Operation> {
+  let operation: Operation = match name {
+    "bls_generate_blinded_g1_key" => rust_bls_generate_blinded_g1_key,
+    "bls_generate_blinded_g2_key" => rust_bls_generate_blinded_g2_key,
+    "bls_generate_g1_key" => rust_bls_generate_g1_key,
+    "bls_generate_g2_key" => rust_bls_generate_g2_key,
+    "bls_secret_key_to_bbs_key" => rust_bls_secret_key_to_bbs_key,
+    "bls_public_key_to_bbs_key" => rust_bls_public_key_to_bbs_key,
+    "bls_public_key_cache_warm" => rust_bls_public_key_cache_warm,
+    "bls_keystore_import" => rust_bls_keystore_import,
+    "bls_keystore_generate" => rust_bls_keystore_generate,
+    "bls_keystore_public_key" => rust_bls_keystore_public_key,
+    "bls_keystore_destroy" => rust_bls_keystore_destroy,
+    "bls_export_encrypted_key" => rust_bls_export_encrypted_key,
+    "bls_import_encrypted_key" => rust_bls_import_encrypted_key,
+    "bls_key_to_jwk" => rust_bls_key_to_jwk,
+    "bls_jwk_to_key" => rust_bls_jwk_to_key,
+    "bls_public_key_to_multikey" => rust_bls_public_key_to_multikey,
+    "bls_multikey_to_public_key" => rust_bls_multikey_to_public_key,
+    "bls_public_key_to_did_key" => rust_bls_public_key_to_did_key,
+    "bls_resolve_did_key" => rust_bls_resolve_did_key,
+    "bbs_schema_hash" => rust_bbs_schema_hash,
+    "bbs_sign" => rust_bbs_sign,
+    "bls_sign" => rust_bls_sign,
+    "bbs_ietf_sign" => rust_bbs_ietf_sign,
+    "bbs_ietf_verify" => rust_bbs_ietf_verify,
+    "bbs_ietf_create_proof" => rust_bbs_ietf_create_proof,
+    "bbs_ietf_verify_proof" => rust_bbs_ietf_verify_proof,
+    "rdf_canonize" => rust_rdf_canonize,
+    "bbs_2023_create_base_proof" => rust_bbs_2023_create_base_proof,
+    "bbs_2023_create_derived_proof" => rust_bbs_2023_create_derived_proof,
+    "bbs_2023_verify_derived_proof" => rust_bbs_2023_verify_derived_proof,
+    "jwp_issue" => rust_jwp_issue,
+    "jwp_present" => rust_jwp_present,
+    "jwp_verify" => rust_jwp_verify,
+    "bbs_create_proof" => rust_bbs_create_proof,
+    "bls_create_proof" => rust_bls_create_proof,
+    "bbs_verify_proof" => rust_bbs_verify_proof,
+    "bls_verify_proof" => rust_bls_verify_proof,
+    "bbs_create_presentation" => rust_bbs_create_presentation,
+    "bls_create_presentation" => rust_bls_create_presentation,
+    "bbs_verify_presentation" => rust_bbs_verify_presentation,
+    "bls_verify_presentation" => rust_bls_verify_presentation,
+    "bbs_blind_signature_commitment" => rust_bbs_blind_signature_commitment,
+    "bls_blind_signature_commitment" => rust_bls_blind_signature_commitment,
+    "bbs_verify_blind_signature_proof" => rust_bbs_verify_blind_signature_proof,
+    "bls_verify_blind_signature_proof" => rust_bls_verify_blind_signature_proof,
+    "bbs_blind_sign" => rust_bbs_blind_sign,
+    "bls_blind_sign" => rust_bls_blind_sign,
+    "bbs_unblind_signature" => rust_bbs_unblind_signature,
+    "bbs_verify" => rust_bbs_verify,
+    "bls_verify" => rust_bls_verify,
+    "bbs_cose_credential" => rust_bbs_cose_credential,
+    "bbs_cose_presentation" => rust_bbs_cose_presentation,
+    "bbs_set_limits" => rust_bbs_set_limits,
+    _ => return None,
+  };
+  Some(operation)
+}
+
+/// JSON Schemas of an operation's context and result, by its wrapper name
+fn operation_schemas(name: &str) -> Option<(Value, Value)> {
+  let schemas = match name {
+    "bls_generate_blinded_g1_key" => (GenerateKeyRequest::json_schema(), BlindedKeyPairResponse::json_schema()),
+    "bls_generate_blinded_g2_key" => (GenerateKeyRequest::json_schema(), BlindedKeyPairResponse::json_schema()),
+    "bls_generate_g1_key" => (GenerateKeyRequest::json_schema(), KeyPairResponse::json_schema()),
+    "bls_generate_g2_key" => (GenerateKeyRequest::json_schema(), KeyPairResponse::json_schema()),
+    "bls_secret_key_to_bbs_key" => (SecretKeyToBbsKeyRequest::json_schema(), PublicKeyResponse::json_schema()),
+    "bls_public_key_to_bbs_key" => (PublicKeyToBbsKeyRequest::json_schema(), PublicKeyResponse::json_schema()),
+    "bls_public_key_cache_warm" => (PublicKeyCacheWarmRequest::json_schema(), PublicKeyCacheResponse::json_schema()),
+    "bls_keystore_import" => (KeystoreImportRequest::json_schema(), StoredKeyResponse::json_schema()),
+    "bls_keystore_generate" => (GenerateKeyRequest::json_schema(), StoredKeyResponse::json_schema()),
+    "bls_keystore_public_key" => (KeystoreKeyRequest::json_schema(), PublicKeyResponse::json_schema()),
+    "bls_keystore_destroy" => (KeystoreKeyRequest::json_schema(), KeystoreDestroyResponse::json_schema()),
+    "bls_export_encrypted_key" => (ExportEncryptedKeyRequest::json_schema(), EncryptedKeyResponse::json_schema()),
+    "bls_import_encrypted_key" => (ImportEncryptedKeyRequest::json_schema(), ImportedKeyResponse::json_schema()),
+    "bls_key_to_jwk" => (KeyToJwkRequest::json_schema(), JwkResponse::json_schema()),
+    "bls_jwk_to_key" => (JwkToKeyRequest::json_schema(), JwkKeyResponse::json_schema()),
+    "bls_public_key_to_multikey" => (PublicKeyEncodingRequest::json_schema(), MultikeyResponse::json_schema()),
+    "bls_multikey_to_public_key" => (MultikeyToPublicKeyRequest::json_schema(), MultikeyPublicKeyResponse::json_schema()),
+    "bls_public_key_to_did_key" => (PublicKeyEncodingRequest::json_schema(), DidKeyResponse::json_schema()),
+    "bls_resolve_did_key" => (ResolveDidKeyRequest::json_schema(), ResolvedDidKeyResponse::json_schema()),
+    "bbs_schema_hash" => (SchemaHashRequest::json_schema(), SchemaHashResponse::json_schema()),
+    "bbs_sign" => (BbsSignRequest::json_schema(), SignatureResponse::json_schema()),
+    "bls_sign" => (BlsSignRequest::json_schema(), SignatureResponse::json_schema()),
+    "bbs_ietf_sign" => (IetfSignRequest::json_schema(), SignatureResponse::json_schema()),
+    "bbs_ietf_verify" => (IetfVerifyRequest::json_schema(), VerifiedResponse::json_schema()),
+    "bbs_ietf_create_proof" => (IetfCreateProofRequest::json_schema(), ProofResponse::json_schema()),
+    "bbs_ietf_verify_proof" => (IetfVerifyProofRequest::json_schema(), VerifiedResponse::json_schema()),
+    "rdf_canonize" => (RdfCanonizeRequest::json_schema(), CanonicalDocumentResponse::json_schema()),
+    "bbs_2023_create_base_proof" => (Bbs2023CreateBaseProofRequest::json_schema(), BaseProofResponse::json_schema()),
+    "bbs_2023_create_derived_proof" => (Bbs2023CreateDerivedProofRequest::json_schema(), DerivedProofResponse::json_schema()),
+    "bbs_2023_verify_derived_proof" => (Bbs2023VerifyDerivedProofRequest::json_schema(), VerifiedResponse::json_schema()),
+    "jwp_issue" => (JwpIssueRequest::json_schema(), JwpResponse::json_schema()),
+    "jwp_present" => (JwpPresentRequest::json_schema(), JwpResponse::json_schema()),
+    "jwp_verify" => (JwpVerifyRequest::json_schema(), JwpVerifyResponse::json_schema()),
+    "bbs_create_proof" => (BbsCreateProofRequest::json_schema(), ProofResponse::json_schema()),
+    "bls_create_proof" => (BlsCreateProofRequest::json_schema(), ProofResponse::json_schema()),
+    "bbs_verify_proof" => (BbsVerifyProofRequest::json_schema(), ProofVerificationResponse::json_schema()),
+    "bls_verify_proof" => (BlsVerifyProofRequest::json_schema(), ProofVerificationResponse::json_schema()),
+    "bbs_create_presentation" => (BbsCreatePresentationRequest::json_schema(), PresentationResponse::json_schema()),
+    "bls_create_presentation" => (BlsCreatePresentationRequest::json_schema(), PresentationResponse::json_schema()),
+    "bbs_verify_presentation" => (BbsVerifyPresentationRequest::json_schema(), PresentationVerifyResponse::json_schema()),
+    "bls_verify_presentation" => (BlsVerifyPresentationRequest::json_schema(), PresentationVerifyResponse::json_schema()),
+    "bbs_blind_signature_commitment" => (BbsBlindSignatureCommitmentRequest::json_schema(), BlindSignatureCommitmentResponse::json_schema()),
+    "bls_blind_signature_commitment" => (BlsBlindSignatureCommitmentRequest::json_schema(), BlindSignatureCommitmentResponse::json_schema()),
+    "bbs_verify_blind_signature_proof" => (BbsVerifyBlindSignatureProofRequest::json_schema(), VerifiedResponse::json_schema()),
+    "bls_verify_blind_signature_proof" => (BlsVerifyBlindSignatureProofRequest::json_schema(), VerifiedResponse::json_schema()),
+    "bbs_blind_sign" => (BbsBlindSignRequest::json_schema(), BlindSignatureResponse::json_schema()),
+    "bls_blind_sign" => (BlsBlindSignRequest::json_schema(), BlindSignatureResponse::json_schema()),
+    "bbs_unblind_signature" => (UnblindSignatureRequest::json_schema(), SignatureResponse::json_schema()),
+    "bbs_verify" => (BbsVerifyRequest::json_schema(), VerifiedResponse::json_schema()),
+    "bls_verify" => (BlsVerifyRequest::json_schema(), VerifiedResponse::json_schema()),
+    "bbs_cose_credential" => (CoseCredentialRequest::json_schema(), CoseCredentialResponse::json_schema()),
+    "bbs_cose_presentation" => (CosePresentationRequest::json_schema(), CosePresentationResponse::json_schema()),
+    "bbs_set_limits" => (SetLimitsRequest::json_schema(), LimitsResponse::json_schema()),
+    _ => return None,
+  };
+  Some(schemas)
+}
+
+/// Get the JSON Schemas of an operation's context ('request') and result ('response')
+#[allow(dead_code)]
+pub fn rust_bbs_get_schema(operation: &str) -> Result<String, BBSError> {
+  let (mut request, mut response) = match operation_schemas(operation) {
+    Some(schemas) => schemas,
+    None => { handle_err!(format!("Unknown operation: {}", operation)); }
+  };
+  for (schema, kind) in [(&mut request, "request"), (&mut response, "response")] {
+    schema["$schema"] = Value::String(SCHEMA_DIALECT.to_string());
+    schema["title"] = Value::String(format!("{} {}", operation, kind));
+  }
+
+  let schemas = json!({
+    "operation": operation,
+    "request": request,
+    "response": response,
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&schemas) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify schemas"); },
+  }
+}
+
+/// Describe the library: its version, enabled features, what it can create and read, and its limits
+#[allow(dead_code)]
+pub fn rust_bbs_library_info() -> Result<String, BBSError> {
+  let features: Vec<&str> = [
+    ("c", cfg!(feature = "c")),
+    ("java", cfg!(feature = "java")),
+    ("node", cfg!(feature = "node")),
+    ("blst", cfg!(feature = "blst")),
+  ]
+    .iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(feature, _)| *feature)
+    .collect();
+  let mut ciphersuites: Vec<&str> = Ciphersuite::ALL.iter().map(|ciphersuite| ciphersuite.name()).collect();
+  // IETF BBS signatures and proofs, which bbs-2023 and JSON Web Proofs are built on
+  ciphersuites.push(std::str::from_utf8(ietf_bbs::CIPHERSUITE_ID).unwrap_or_default());
+  let limits = limits();
+
+  let info = json!({
+    "name": env!("CARGO_PKG_NAME"),
+    "version": env!("CARGO_PKG_VERSION"),
+    "features": features,
+    "ciphersuites": ciphersuites,
+    "cryptosuites": ["bbs-2023"],
+    // BLS12-381 backend of key generation and the IETF BBS based operations
+    "curve_backend": DefaultBackend::NAME,
+    "jwp_algorithms": [jwp::ALGORITHM],
+    "encodings": ["base64", "base64url", "hex", "cbor"],
+    "formats": {
+      "proof": { "read": PROOF_VERSIONS, "write": PROOF_VERSION },
+      "presentation": { "read": [PRESENTATION_VERSION], "write": PRESENTATION_VERSION },
+      "encrypted_key": { "read": [ENCRYPTED_KEY_VERSION], "write": ENCRYPTED_KEY_VERSION },
+    },
+    "limits": {
+      "max_message_count": limits.max_message_count,
+      "max_message_size": limits.max_message_size,
+      "max_context_size": limits.max_context_size,
+      "max_batch_size": limits.max_batch_size,
+      "public_key_cache_capacity": PUBLIC_KEY_CACHE_CAPACITY,
+      "rdfc_max_n_degree_calls": rdfc::MAX_N_DEGREE_CALLS,
+      "argon2_max_m_cost": ARGON2_MAX_M_COST,
+      "argon2_max_t_cost": ARGON2_MAX_T_COST,
+      "argon2_max_p_cost": ARGON2_MAX_P_COST,
+    },
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&info) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify library info"); },
+  }
+}
+
+/// An error, or the message of one, that may have a code callers can handle, e.g. by retrying
+/// with less input
+pub trait ErrorCode {
+  /// Message the code is read from, which has to start with the text of its code's errors
+  fn code_message(&self) -> String;
+}
+
+impl ErrorCode for BBSError {
+  fn code_message(&self) -> String {
+    // only the message itself, as `Display` and `Debug` frame it
+    error_detail(self)
+  }
+}
+
+impl ErrorCode for str {
+  fn code_message(&self) -> String {
+    self.to_string()
+  }
+}
+
+impl ErrorCode for String {
+  fn code_message(&self) -> String {
+    self.clone()
+  }
+}
+
+/// Code of an error that callers can handle. Messages repeat caller input, e.g. the names of
+/// unknown properties, so only a message starting with a code's text has that code
+pub fn error_code<E: ErrorCode + ?Sized>(error: &E) -> Option<&'static str> {
+  if error.code_message().starts_with(LIMIT_EXCEEDED) {
+    Some(LIMIT_EXCEEDED_CODE)
+  } else {
+    None
+  }
+}
+
+/// Read a wrapper's context bytes: a JSON string, or a CBOR map setting 'encoding' to 'cbor'
+pub fn parse_context(context: &[u8]) -> Result<Value, String> {
+  if context.is_empty() {
+    return Err("Context not set".to_string());
+  }
+  // checked ahead of parsing, which is the first work done for a call
+  if let Err(error) = check_context_size(context.len()) {
+    return Err(error_detail(&error));
+  }
+
+  match std::str::from_utf8(context) {
+    Ok(context_string) => match serde_json::from_str(context_string) {
+      Ok(context_json) => Ok(context_json),
+      Err(_) => Err("Failed parsing JSON for context".to_string()),
+    },
+    // a CBOR map isn't UTF-8, and is only read as a context where it sets 'encoding' to 'cbor'
+    Err(_) => context_from_cbor(context).map_err(|error| error.to_string()),
+  }
+}
+
+/// Call an operation by name with a CBOR (or JSON) context, returning its result, or error,
+/// as CBOR with byte properties as byte strings
+pub fn rust_call_cbor(operation: &str, context: &[u8]) -> Vec<u8> {
+  let operation = match context_operation(operation) {
+    Some(operation) => operation,
+    None => return error_to_cbor(&format!("Unknown operation: {}", operation), None),
+  };
+
+  // the code is read from the error before its message is framed
+  let result = parse_context(context)
+    .map_err(|error| (error_code(&error), error))
+    .and_then(|context_json| operation(context_json).map_err(|error| (error_code(&error), error.to_string())))
+    .map(Zeroizing::new)
+    .and_then(|result| result_to_cbor(&result).map_err(|error| (error_code(&error), error.to_string())));
+  match result {
+    Ok(result) => result,
+    Err((code, error)) => error_to_cbor(&error, code),
+  }
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_blind_signature_commitment(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_blind_signature_commitment);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsBlindSignatureCommitmentRequest>(context_json)?;
+
+  // map claim names of a 'schema' to the blinded messages and their indices
+  let schema = optional_schema(&request.schema)?;
+  let (messages, blinded) = blinded_messages(request.messages, schema.as_ref(), request.claims.as_ref(), &request.blinded)?;
+
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = decode_bbs_public_key(&request.public_key)?;
+
+  bbs_blind_signature_commitment(&public_key, &messages, &blinded, &request.nonce, &request.deterministic_seed)
+}
+
+/// Commit to the base64 messages blinded at the `blinded` indices, for a BBS key
+fn bbs_blind_signature_commitment(
+  public_key: &BbsPublicKey,
+  messages_to_blind: &[String],
+  blinded: &[usize],
+  nonce: &Option<String>,
+  deterministic_seed: &Option<DeterministicSeed>,
+) -> Result<String, BBSError> {
+  if blinded.len() != messages_to_blind.len() {
+    handle_err!(format!(
+      "hidden length is not the same as messages length: {} != {}",
+      blinded.len(),
+      messages_to_blind.len()
+    ));
+  }
+
+  // convert nonce base64 string to a nonce scalar
+  let nonce = blind_signature_nonce(nonce)?;
+
+  // convert messages base64 string to array of message scalars
+  let mut messages = BTreeMap::new();
+  let message_count = public_key.message_count();
+
+  for (index, message) in blinded.iter().zip(signature_messages(messages_to_blind)?) {
+      if *index >= message_count {
+        handle_err!(format!(
+          "Index is out of bounds. Must be between {} and {}: found {}",
+          0,
+          message_count as i64 - 1,
+          index
+        ));
+      }
+
+      // add message to tree map
+      messages.insert(*index, message);
+  }
+
+  let mut rng = context_rng(deterministic_seed, "bbs_blind_signature_commitment")?;
+  let (blinding_context, mut blinding_factor) = bbs_plus_backend::blind_signature_context(public_key, &messages, &nonce, &mut rng)?;
+
+  #[derive(Serialize)]
+  struct BlindCommitmentContext<'a> {
+    blinding_factor: &'a str,
+    challenge_hash: &'a str,
+    commitment: &'a str,
+    proof_of_hidden_messages: &'a str,
+  }
+
+  let mut blinding_factor_bytes = blinding_factor.to_bytes();
+  let blinding_factor_b64 = encode_secret(&blinding_factor_bytes);
+  blinding_factor_bytes.zeroize();
+  blinding_factor.zeroize();
+
+  let commitment = base64::encode(blinding_context.commitment.to_compressed().as_slice());
+  let challenge_hash = base64::encode(blinding_context.challenge_hash.to_bytes());
+  let proof_of_hidden_messages = base64::encode(blinding_context.proof_of_hidden_messages.to_bytes().as_slice());
+
+  let blind_commitment_context = BlindCommitmentContext {
+    blinding_factor: &blinding_factor_b64,
+    challenge_hash: &challenge_hash,
+    commitment: &commitment,
+    proof_of_hidden_messages: &proof_of_hidden_messages,
+  };
+  let values = [blinding_factor_b64.as_str(), &challenge_hash, &commitment, &proof_of_hidden_messages];
+
+  // Serialize return object to JSON string
+  match secret_to_json(&blind_commitment_context, &values) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify Blind Commitment Context"); },
+  }
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_blind_signature_commitment(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_blind_signature_commitment);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsBlindSignatureCommitmentRequest>(context_json)?;
+
+  // map claim names of a 'schema' to the blinded messages and their indices
+  let schema = optional_schema(&request.schema)?;
+  let (messages, blinded) = blinded_messages(request.messages, schema.as_ref(), request.claims.as_ref(), &request.blinded)?;
+
+  // get `known_message_count`, by default the schema's claims that aren't blinded
+  let known_message_count = unselected_count(request.known_message_count, schema.as_ref(), blinded.len(), "known_message_count")?;
+
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(&request.public_key)?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, messages.len() + known_message_count)?;
+
+  bbs_blind_signature_commitment(&pk, &messages, &blinded, &request.nonce, &request.deterministic_seed)
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_verify_blind_signature_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_verify_blind_signature_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsVerifyBlindSignatureProofRequest>(context_json)?;
+
+  // map claim names of a 'schema' to the blinded message indices
+  let schema = optional_schema(&request.schema)?;
+  let (_, blinded) = blinded_messages(Some(Vec::new()), schema.as_ref(), None, &request.blinded)?;
+
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = decode_bbs_public_key(&request.public_key)?;
+
+  bbs_verify_blind_signature_proof(
+    &public_key,
+    &request.commitment,
+    &request.challenge_hash,
+    &request.proof_of_hidden_messages,
+    &blinded,
+    &request.nonce,
+  )
+}
+
+/// Verify a commitment to the messages blinded at the `blinded` indices, for a BBS key
+fn bbs_verify_blind_signature_proof(
+  public_key: &BbsPublicKey,
+  commitment: &str,
+  challenge_hash: &str,
+  proof_of_hidden_messages: &str,
+  blinded: &[usize],
+  nonce: &Option<String>,
+) -> Result<String, BBSError> {
+  // convert 'commitment' base64 string to a G1 point
+  let commitment = decode_point(commitment, "commitment")?;
+
+  // convert 'challenge_hash' base64 string to a scalar
+  let challenge_hash = decode_scalar(challenge_hash, "challenge_hash")?;
+
+  // convert 'proof_of_hidden_messages' base64 string to `ProofG1` instance
+  let proof_of_hidden_messages = match bbs_plus_backend::ProofG1::from_bytes(decode_base64(proof_of_hidden_messages, "proof_of_hidden_messages")?.as_slice()) {
+    Ok(proof_of_hidden_messages) => proof_of_hidden_messages,
+    Err(_) => { handle_err!("Failed to deserialize proof of hidden messages"); }
+  };
+
+  // convert nonce base64 string to a nonce scalar
+  let nonce = blind_signature_nonce(nonce)?;
+
+  let commitment_context = bbs_plus_backend::BlindSignatureContext {
+    commitment,
+    proof_of_hidden_messages,
+    challenge_hash,
+  };
+
+  let mut messages = BTreeSet::new();
+  let message_count = public_key.message_count();
+
+  for index in blinded {
+      if *index >= message_count {
+        handle_err!(format!(
+          "Index is out of bounds. Must be between {} and {}: found {}",
+          0,
+          message_count as i64 - 1,
+          index
+        ));
+      }
+      messages.insert(*index);
+  }
+
+  let verified = commitment_context.verify(public_key, &messages, &nonce);
+
+  let verify_outcome = json!({
+    "verified": verified,
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&verify_outcome) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify Blind Commitment Verification"); },
+  }
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_verify_blind_signature_proof(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_verify_blind_signature_proof);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsVerifyBlindSignatureProofRequest>(context_json)?;
+
+  // map claim names of a 'schema' to the blinded message indices
+  let schema = optional_schema(&request.schema)?;
+  let (_, blinded) = blinded_messages(Some(Vec::new()), schema.as_ref(), None, &request.blinded)?;
+
+  // get `known_message_count`, by default the schema's claims that aren't blinded
+  let known_message_count = unselected_count(request.known_message_count, schema.as_ref(), blinded.len(), "known_message_count")?;
+
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(&request.public_key)?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, blinded.len() + known_message_count)?;
+
+  bbs_verify_blind_signature_proof(
+    &pk,
+    &request.commitment,
+    &request.challenge_hash,
+    &request.proof_of_hidden_messages,
+    &blinded,
+    &request.nonce,
+  )
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_blind_sign(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_blind_sign);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsBlindSignRequest>(context_json)?;
+
+  // map claim names of a 'schema' to the known messages and their indices
+  let schema = optional_schema(&request.schema)?;
+  let (messages, known) = known_messages(request.messages, schema.as_ref(), request.claims.as_ref(), &request.known)?;
+
+  // get `SecretKey` by 'key_handle', or from 'secret_key' base64 string
+  let secret_key = signing_key(request.key_handle, request.secret_key)?;
+
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = decode_bbs_public_key(&request.public_key)?;
+
+  bbs_blind_sign(&secret_key, &public_key, &request.commitment, &messages, &known, &request.deterministic_seed)
+}
+
+/// Sign the base64 messages known at the `known` indices alongside a commitment to the
+/// blinded ones, with a BBS key
+fn bbs_blind_sign(
+  secret_key: &SigningKey,
+  public_key: &BbsPublicKey,
+  commitment: &str,
+  messages_visible: &[String],
+  known: &[usize],
+  deterministic_seed: &Option<DeterministicSeed>,
+) -> Result<String, BBSError> {
+  if known.len() != messages_visible.len() {
+    handle_err!(format!(
+      "known length is not the same as messages length: {} != {}",
+      known.len(),
+      messages_visible.len()
+    ));
+  }
+
+  // convert messages base64 string to array of `SignatureMessage` instances
+  let mut messages = BTreeMap::new();
+  let message_count = public_key.message_count();
+
+  for (index, message) in known.iter().zip(signature_messages(messages_visible)?) {
+      if *index >= message_count {
+        handle_err!(format!(
+          "Index is out of bounds. Must be between {} and {}: found {}",
+          0,
+          message_count as i64 - 1,
+          index
+        ));
+      }
+
+      // add message to tree map
+      messages.insert(*index, message);
+  }
+
+  // convert 'commitment' base64 string to a G1 point
+  let commitment = decode_point(commitment, "commitment")?;
+
+  let mut rng = context_rng(deterministic_seed, "bbs_blind_sign")?;
+  let blind_signature = match secret_key.with(|secret_key| bbs_plus_backend::blind_sign(
+    secret_key,
+    public_key,
+    &commitment,
+    &messages,
+    &mut rng,
+  ))? {
+    Ok(blind_signature) => blind_signature,
+    Err(_) => { handle_err!("Failed to generate Blind Signature"); },
+  };
+
+  let signature_outcome = json!({
+    "blind_signature": base64::encode(blind_signature.to_bytes().as_slice()),
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&signature_outcome) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify Blind Signature"); },
+  }
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_blind_sign(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_blind_sign);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsBlindSignRequest>(context_json)?;
+
+  // map claim names of a 'schema' to the known messages and their indices
+  let schema = optional_schema(&request.schema)?;
+  let (messages, known) = known_messages(request.messages, schema.as_ref(), request.claims.as_ref(), &request.known)?;
+
+  // get `blinded_message_count`, by default the schema's claims that aren't known
+  let blinded_message_count = unselected_count(request.blinded_message_count, schema.as_ref(), known.len(), "blinded_message_count")?;
+
+  // get `SecretKey` by 'key_handle', or from 'secret_key', and its `DeterministicPublicKey`
+  let (secret_key, dpk) = signer_key_pair(request.key_handle, request.secret_key, request.public_key.as_ref())?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, messages.len() + blinded_message_count)?;
+
+  bbs_blind_sign(&secret_key, &pk, &request.commitment, &messages, &known, &request.deterministic_seed)
+}
+
+#[allow(dead_code)]
+pub fn rust_bbs_unblind_signature(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_unblind_signature);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<UnblindSignatureRequest>(context_json)?;
+
+  // convert 'blind_signature' base64 string to `Signature` instance
+  let blind_signature: BbsSignature = decode_fixed(&request.blind_signature, "blind_signature", SIGNATURE_COMPRESSED_SIZE)?;
+
+  // convert 'blinding_factor' base64 string to a scalar
+  let mut blinding_factor = scalar_value(&secret_bytes(&request.blinding_factor, "blinding_factor")?, "blinding_factor")?;
+
+  let unblinded_signature = blind_signature.unblind(&blinding_factor);
+  blinding_factor.zeroize();
+
+  let signature_outcome = json!({
+    "signature": base64::encode(unblinded_signature.to_bytes().as_slice()),
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&signature_outcome) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify Unblinded Signature"); },
+  }
 }
 
-/// Convert big-endian vector to u32
-fn bitvector_to_revealed(data: &[u8]) -> BTreeSet<usize> {
-    let mut revealed_messages = BTreeSet::new();
-    let mut scalar = 0;
+#[allow(dead_code)]
+pub fn rust_bbs_verify(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bbs_verify);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BbsVerifyRequest>(context_json)?;
+
+  // map a COSE 'credential', named 'claims' or a JSON 'document' to positional 'messages'
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    credential: request.credential,
+    signature: request.signature,
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
+
+  // convert 'public_key' base64 string to `PublicKey` instance
+  let public_key = decode_bbs_public_key(&request.public_key)?;
 
-    for b in data.iter().rev() {
-        let mut v = *b;
-        let mut remaining = 8;
-        while v > 0 {
-            let revealed = v & 1u8;
-            if revealed == 1 {
-                revealed_messages.insert(scalar);
-            }
-            v >>= 1;
-            scalar += 1;
-            remaining -= 1;
+  bbs_verify(&public_key, &messages)
+}
+
+/// Verify a BBS signature over the context's messages
+fn bbs_verify(
+  public_key: &BbsPublicKey,
+  messages: &ContextMessages,
+) -> Result<String, BBSError> {
+  // convert 'signature' base64 string to `Signature` instance
+  let signature: BbsSignature = decode_fixed(required(messages.signature.as_ref(), "signature")?, "signature", SIGNATURE_COMPRESSED_SIZE)?;
+
+  // convert messages base64 string to array of message scalars
+  let messages = signature_messages(required(messages.messages.as_ref(), "messages")?)?;
+
+  let verified = match signature.verify(public_key, messages.as_slice()) {
+    Ok(verified) => verified,
+    Err(_) => { handle_err!("Failed to verify Signature"); },
+  };
+
+  let verify_outcome = json!({
+    "verified": verified,
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&verify_outcome) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify Signature verification"); },
+  }
+}
+
+#[allow(dead_code)]
+pub fn rust_bls_verify(
+  mut context_json: Value
+) -> Result<String, BBSError> {
+  // run on base64 bytes where the context sets another 'encoding'
+  if let Some(encoding) = take_encoding(&mut context_json)? {
+    return encoding.call(context_json, BYTE_PROPERTIES, rust_bls_verify);
+  }
+  // read the request, rejecting unknown, mistyped and oversized properties
+  let request = check_request::<BlsVerifyRequest>(context_json)?;
+
+  // map a COSE 'credential', named 'claims' or a JSON 'document' to positional 'messages'
+  let messages = ContextMessages {
+    messages: request.messages,
+    schema: request.schema,
+    claims: request.claims,
+    document: request.document,
+    credential: request.credential,
+    signature: request.signature,
+    ..ContextMessages::default()
+  }.normalize(Algorithm::BbsPlus)?;
+  let message_count = required(messages.messages.as_ref(), "messages")?.len();
+
+  // convert base64, JWK or did:key 'public_key' to `DeterministicPublicKey` instance
+  let dpk = bls_public_key(&request.public_key)?;
+
+  // convert deterministic public key to BBS public key
+  let pk = bbs_public_key(&dpk, message_count)?;
+
+  bbs_verify(&pk, &messages)
+}
+
+/// Verify a decoded proof over the revealed `messages`, recording the outcome in `verification`
+fn verify_signature_proof(
+  proof: &[u8],
+  messages: Vec<DefaultScalar>,
+  public_key: BbsPublicKey,
+  nonce: &DefaultScalar,
+  verification: &mut ProofVerification,
+) {
+  let envelope = match decode_proof(proof) {
+    Ok(envelope) => envelope,
+    Err(error) => return verification.fail(ProofFailure::MalformedProof, error_detail(&error)),
+  };
+  verification.version = Some(envelope.version);
+  verification.message_count = Some(envelope.message_count);
+  verification.revealed = Some(envelope.revealed.iter().copied().collect());
+
+  if envelope.message_count != public_key.message_count() {
+    return verification.fail(
+      ProofFailure::KeyMessageCountMismatch,
+      format!("Proof message count ({}) is different from public key message count ({})", envelope.message_count, public_key.message_count()),
+    );
+  }
+  if messages.len() != envelope.revealed.len() {
+    return verification.fail(
+      ProofFailure::MessageCountMismatch,
+      format!("Given messages count ({}) is different from revealed messages count ({}) for this proof", messages.len(), envelope.revealed.len()),
+    );
+  }
+
+  let proof = match (envelope.version, envelope.ciphersuite) {
+    (PROOF_VERSION_LEGACY | PROOF_VERSION_2, Ciphersuite::Bls12381G2Blake2b) => {
+      match envelope.signature_proof::<DefaultBackend>() {
+        Ok(proof) => proof,
+        Err(error) => return verification.fail(ProofFailure::MalformedProof, error_detail(&error)),
+      }
+    },
+    (version, ciphersuite) => {
+      return verification.fail(
+        ProofFailure::MalformedProof,
+        format!("Unsupported proof version {} for ciphersuite: {}", version, ciphersuite.name()),
+      );
+    }
+  };
+
+  let revealed_messages = envelope.revealed.iter().copied().zip(messages).collect();
+
+  match proof.verify(&public_key, &revealed_messages, nonce) {
+    Ok(PoKOfSignatureProofStatus::Success) => verification.verified = true,
+    Ok(PoKOfSignatureProofStatus::BadSignature) => {
+      verification.fail(ProofFailure::InvalidSignature, "Proof of knowledge of the signature failed")
+    },
+    Ok(_) => {
+      verification.fail(ProofFailure::ChallengeMismatch, "Proof challenge is different from the recomputed challenge")
+    },
+    Err(error) => verification.fail(ProofFailure::MalformedProof, error_detail(&error)),
+  }
+}
+
+/// Verification result for a proof that couldn't be decoded
+fn malformed_proof(nonce: Option<String>, detail: String) -> ProofVerification {
+  let mut verification = ProofVerification::new(nonce);
+  verification.fail(ProofFailure::MalformedProof, detail);
+  verification
+}
+
+fn proof_verification_to_json(verification: &ProofVerification) -> Result<String, BBSError> {
+  // Serialize return object to JSON string
+  match serde_json::to_string(verification) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify BBS Proof Verification"); },
+  }
+}
+
+fn proof_to_json(proof: &str) -> Result<String, BBSError> {
+  let proof = json!({
+    "proof": proof
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&proof) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify BBS Proof"); },
+  }
+}
+
+/// Presentation of the context's revealed messages, with a proof over them
+fn messages_presentation(
+  messages: ContextMessages,
+  nonce: Option<String>,
+  proof: String,
+  key_id: Option<String>,
+) -> Result<Presentation, BBSError> {
+  create_presentation(
+    required(messages.messages.as_ref(), "messages")?,
+    required(messages.revealed.as_ref(), "revealed")?,
+    nonce,
+    proof,
+    key_id,
+    messages.message_format,
+  )
+}
+
+fn presentation_to_json(presentation: Presentation) -> Result<String, BBSError> {
+  let presentation = json!({
+    "presentation": presentation,
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&presentation) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify BBS Presentation"); },
+  }
+}
+
+/// Verify a presentation's proof over its own revealed messages, against the trusted public key
+/// `verify_proof` is given
+fn verify_presentation(
+  presentation: &Value,
+  nonce: &Option<String>,
+  schema: &Option<Value>,
+  verify_proof: impl FnOnce(&str, &[String], Option<String>) -> Result<ProofVerification, BBSError>,
+) -> Result<String, BBSError> {
+  let presentation = parse_presentation(presentation)?;
+  let schema = optional_schema(schema)?;
+
+  // a verifier checking freshness passes the nonce it issued, which the proof must be bound to
+  if let Some(nonce) = nonce {
+    if presentation.nonce.as_ref() != Some(nonce) {
+      return failed_presentation_to_json(ProofFailure::NonceMismatch, "Presentation nonce is different from the expected nonce".to_string());
+    }
+  }
+
+  // a proof that can't be decoded fails verification, as in the verify proof operations
+  let proof = match base64::decode(&presentation.proof) {
+    Ok(proof) => proof,
+    Err(error) => return failed_presentation_to_json(ProofFailure::MalformedProof, format!("Failed decoding base64 for presentation: 'proof': {}", error)),
+  };
+  let envelope = match decode_proof(&proof) {
+    Ok(envelope) => envelope,
+    // a proof over too many messages is refused rather than reported as malformed
+    Err(error) if error_code(&error).is_some() => return Err(error),
+    Err(error) => return failed_presentation_to_json(ProofFailure::MalformedProof, error_detail(&error)),
+  };
+  if !envelope.revealed.iter().eq(presentation.revealed_messages.keys()) {
+    return failed_presentation_to_json(
+      ProofFailure::RevealedMismatch,
+      "Presentation revealed message indices are different from those revealed by its proof".to_string(),
+    );
+  }
+
+  let messages: Vec<String> = presentation.revealed_messages.values().cloned().collect();
+  let proof_verification = verify_proof(&presentation.proof, &messages, presentation.nonce.clone())?;
+
+  // disclosed messages are only returned once the proof over them has verified
+  let verify_outcome = if proof_verification.verified {
+    let mut verify_outcome = json!({
+      "verified": true,
+      "revealed_messages": presentation.revealed_messages,
+      "nonce": presentation.nonce,
+      "key_id": presentation.key_id,
+    });
+    // name the revealed claims, once the signed schema hash is checked to be the expected schema's
+    if let Some(schema) = schema {
+      if !presentation.revealed_messages.contains_key(&SCHEMA_HASH_INDEX) {
+        handle_err!("Presentation doesn't reveal the schema hash");
+      }
+      let revealed_messages: Vec<(usize, &String)> = presentation.revealed_messages.iter()
+        .map(|(index, message)| (*index, message))
+        .collect();
+      verify_outcome["claims"] = Value::Object(claims_by_name(&schema, &revealed_messages)?);
+    }
+    if presentation.message_format == Some(MessageFormat::JsonPointer) {
+      verify_outcome["document"] = disclosed_document(presentation.revealed_messages.values())?;
+    }
+    verify_outcome
+  } else {
+    json!({
+      "verified": false,
+      "reason": proof_verification.reason,
+      "detail": proof_verification.detail,
+    })
+  };
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&verify_outcome) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify BBS Presentation Verification"); },
+  }
+}
+
+/// Verification result for a presentation whose proof couldn't be decoded
+fn failed_presentation_to_json(failure: ProofFailure, detail: String) -> Result<String, BBSError> {
+  let verify_outcome = json!({
+    "verified": false,
+    "reason": failure.code(),
+    "detail": detail,
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&verify_outcome) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify BBS Presentation Verification"); },
+  }
+}
+
+/// Messages of a context, given as positional 'messages', named 'claims' of a 'schema', a JSON
+/// 'document' or a COSE 'credential', along with the indices it reveals
+#[derive(Default)]
+struct ContextMessages {
+  messages: Option<Vec<String>>,
+  schema: Option<Value>,
+  claims: Option<Claims>,
+  document: Option<Value>,
+  credential: Option<String>,
+  signature: Option<String>,
+  header: Option<String>,
+  key_id: Option<String>,
+  /// Claim names or JSON Pointers to reveal
+  reveal: Option<Vec<String>>,
+  revealed: Option<Vec<usize>>,
+  /// Set where the messages are flattened from a document
+  message_format: Option<MessageFormat>,
+}
+
+impl ContextMessages {
+  /// Map a COSE 'credential', named 'claims' (with a 'schema') or a JSON 'document' to the
+  /// positional messages and indices operations expect
+  fn normalize(mut self, algorithm: Algorithm) -> Result<ContextMessages, BBSError> {
+    check_claim_selectors(self.messages.is_some(), self.schema.is_some(), self.claims.is_some())?;
+    if self.claims.is_some() && self.credential.is_some() {
+      handle_err!("Property 'credential' can't be used with 'claims'");
+    }
+    if self.document.is_some() && self.schema.is_some() {
+      handle_err!("Property 'document' can't be used with 'schema'");
+    }
+    if self.reveal.is_some() {
+      if self.revealed.is_some() {
+        handle_err!("Only one of 'reveal' and 'revealed' can be set");
+      }
+      if self.schema.is_none() && self.document.is_none() {
+        handle_err!("Property 'reveal' requires 'schema' or 'document'");
+      }
+    }
+
+    if let Some(credential) = self.credential.take() {
+      let credential = decode_context_credential(&credential, algorithm)?;
+      if self.signature.is_some() || self.messages.is_some() {
+        handle_err!("Property 'credential' can't be used with 'signature' or 'messages'");
+      }
+      if let Some(header) = credential.header {
+        if self.header.is_some() {
+          handle_err!("Property 'credential' can't be used with 'header'");
         }
-        scalar += remaining;
+        self.header = Some(base64::encode(header));
+      }
+      self.signature = Some(base64::encode(&credential.signature));
+      self.messages = Some(credential.messages.iter().map(base64::encode).collect());
+      if self.key_id.is_none() {
+        self.key_id = credential.key_id;
+      }
+    }
+
+    if let Some(schema) = optional_schema(&self.schema)? {
+      if let Some(claims) = &self.claims {
+        self.messages = Some(signed_claim_messages(&schema, claims)?);
+      }
+      if let Some(reveal) = self.reveal.take() {
+        self.revealed = Some(reveal_claim_indices(&schema, &reveal)?);
+      }
+    }
+
+    if let Some(document) = self.document.take() {
+      if self.messages.is_some() {
+        handle_err!("Only one of 'document' and 'messages' can be set");
+      }
+      let (messages, revealed) = document_messages(&document, self.reveal.take().as_deref())?;
+      self.messages = Some(messages);
+      if revealed.is_some() {
+        self.revealed = revealed;
+      }
+      self.message_format = Some(MessageFormat::JsonPointer);
+    }
+
+    Ok(self)
+  }
+}
+
+/// Messages a proof is verified against: the revealed 'claims' of a 'schema', led by the
+/// schema hash, or the positional 'messages'
+fn verify_proof_messages(messages: Option<Vec<String>>, schema: &Option<Value>, claims: &Option<Claims>) -> Result<Vec<String>, BBSError> {
+  check_claim_selectors(messages.is_some(), schema.is_some(), claims.is_some())?;
+  match (optional_schema(schema)?, claims) {
+    (Some(schema), Some(claims)) => revealed_claim_messages(&schema, claims),
+    _ => required(messages, "messages"),
+  }
+}
+
+/// Reject named 'claims' without the 'schema' naming them, or alongside positional 'messages',
+/// given whether each property is set
+fn check_claim_selectors(messages: bool, schema: bool, claims: bool) -> Result<(), BBSError> {
+  if claims {
+    if !schema {
+      handle_err!("Property 'claims' requires 'schema'");
+    }
+    if messages {
+      handle_err!("Only one of 'claims' and 'messages' can be set");
+    }
+  }
+  Ok(())
+}
+
+/// Blinded messages and their indices, given by index or, with a 'schema', by claim name
+fn blinded_messages(
+  messages: Option<Vec<String>>,
+  schema: Option<&Schema>,
+  claims: Option<&Claims>,
+  blinded: &[MessageSelector],
+) -> Result<(Vec<String>, Vec<usize>), BBSError> {
+  check_claim_selectors(messages.is_some(), schema.is_some(), claims.is_some())?;
+  let (claim_messages, blinded) = match schema {
+    Some(schema) => blinded_claims(schema, claims, blinded)?,
+    None => (None, selector_indexes(blinded, "blinded")?),
+  };
+  Ok((required(claim_messages.or(messages), "messages")?, blinded))
+}
+
+/// Known messages and their indices, given by index or, with a 'schema', by claim name
+fn known_messages(
+  messages: Option<Vec<String>>,
+  schema: Option<&Schema>,
+  claims: Option<&Claims>,
+  known: &[MessageSelector],
+) -> Result<(Vec<String>, Vec<usize>), BBSError> {
+  check_claim_selectors(messages.is_some(), schema.is_some(), claims.is_some())?;
+  match (schema, messages) {
+    (Some(schema), _) => known_claims(schema, claims, known),
+    (None, Some(messages)) => Ok((messages, selector_indexes(known, "known")?)),
+    (None, None) => { handle_err!("Messages data array not set"); }
+  }
+}
+
+/// Message count of a blind signature's other party, by default the schema's messages that
+/// weren't selected
+fn unselected_count(count: Option<u32>, schema: Option<&Schema>, selected: usize, property: &str) -> Result<usize, BBSError> {
+  match (count, schema) {
+    (Some(count), _) => Ok(count as usize),
+    (None, Some(schema)) => Ok(schema.message_count().saturating_sub(selected)),
+    (None, None) => { handle_err!(format!("Property not set: '{}'", property)); }
+  }
+}
+
+/// Message indices of 'blinded' or 'known' selectors, which name claims only where there's a schema
+fn selector_indexes(selectors: &[MessageSelector], property: &str) -> Result<Vec<usize>, BBSError> {
+  selectors.iter()
+    .map(|selector| match selector {
+      MessageSelector::Index(index) => Ok(*index as usize),
+      MessageSelector::Claim(_) => { handle_err!(format!("Invalid '{}', claim names need a 'schema'", property)); }
+    })
+    .collect()
+}
+
+/// Indices sorted and deduplicated, such as 'revealed'
+fn sorted_indexes(indexes: &[u32]) -> Vec<usize> {
+  let indexes: BTreeSet<usize> = indexes.iter().map(|index| *index as usize).collect();
+  indexes.into_iter().collect()
+}
+
+fn optional_schema(schema: &Option<Value>) -> Result<Option<Schema>, BBSError> {
+  schema.as_ref().map(Schema::from_json).transpose()
+}
+
+/// Value of a property that is only optional in some forms of a context
+fn required<T>(value: Option<T>, property: &str) -> Result<T, BBSError> {
+  match value {
+    Some(value) => Ok(value),
+    None => { handle_err!(format!("Property not set: '{}'", property)); }
+  }
+}
+
+/// Convert base64 messages to the scalars signed for them
+fn signature_messages(messages: &[String]) -> Result<Vec<DefaultScalar>, BBSError> {
+  messages.iter()
+    .map(|message| Ok(bbs_plus_backend::hash_to_scalar(decode_base64(message, "messages")?.as_slice())))
+    .collect()
+}
+
+/// Nonce scalar of a proof, which is zero without a base64 'nonce'
+fn proof_nonce(nonce: &Option<String>) -> Result<DefaultScalar, BBSError> {
+  match nonce {
+    Some(nonce) => Ok(bbs_plus_backend::hash_to_scalar(decode_base64(nonce, "nonce")?.as_slice())),
+    None => scalar_value(&[0u8; FR_COMPRESSED_SIZE], "nonce"),
+  }
+}
+
+/// Nonce scalar of a blind signature commitment, which has a fixed default
+fn blind_signature_nonce(nonce: &Option<String>) -> Result<DefaultScalar, BBSError> {
+  match nonce {
+    Some(nonce) => Ok(bbs_plus_backend::hash_to_scalar(decode_base64(nonce, "nonce")?.as_slice())),
+    None => Ok(bbs_plus_backend::hash_to_scalar(b"bbs+rustffiwrapper")),
+  }
+}
+
+/// Decode a base64, JWK or did:key public key
+fn public_key_bytes(public_key: &PublicKeyInput) -> Result<Vec<u8>, BBSError> {
+  match public_key {
+    PublicKeyInput::Encoded(did) if is_did_key(did) => {
+      let (_, _, dpk) = resolve_did_key(did)?;
+      Ok(dpk.to_bytes_compressed_form().to_vec())
+    },
+    PublicKeyInput::Encoded(public_key) => decode_base64(public_key, "public_key"),
+    PublicKeyInput::Jwk(jwk) => jwk_public_key(jwk),
+  }
+}
+
+/// Decode a base64, JWK or did:key BLS public key
+fn bls_public_key(public_key: &PublicKeyInput) -> Result<DeterministicPublicKey, BBSError> {
+  fixed_value(&public_key_bytes(public_key)?, "public_key", DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE)
+}
+
+/// Public key bytes given as 'public_key', or in a JWK 'secret_key'
+fn given_public_key(public_key: Option<&PublicKeyInput>, secret_key: Option<&SecretKeyInput>) -> Result<Option<Vec<u8>>, BBSError> {
+  match (public_key, secret_key) {
+    (Some(public_key), _) => public_key_bytes(public_key).map(Some),
+    (None, Some(SecretKeyInput::Jwk(jwk))) if !jwk.0["x"].is_null() => jwk_public_key(&jwk.0).map(Some),
+    _ => Ok(None),
+  }
+}
+
+/// Get the signing key by 'key_handle' or from 'secret_key', and its BLS public key. A
+/// 'public_key', or a JWK 'secret_key' with 'x', has to be the signing key's own
+fn signer_key_pair(
+  key_handle: Option<u64>,
+  secret_key: Option<SecretKeyInput>,
+  public_key: Option<&PublicKeyInput>,
+) -> Result<(SigningKey, DeterministicPublicKey), BBSError> {
+  let given_public_key = match given_public_key(public_key, secret_key.as_ref())? {
+    Some(public_key) => Some(fixed_value::<DeterministicPublicKey>(&public_key, "public_key", DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE)?),
+    None => None,
+  };
+  let signing_key = signing_key(key_handle, secret_key)?;
+  let dpk = signing_key.public_key()?;
+
+  // signatures under a mismatched key would verify under neither
+  if let Some(given_public_key) = given_public_key {
+    if given_public_key.to_bytes_compressed_form()[..] != dpk.to_bytes_compressed_form()[..] {
+      handle_err!(PUBLIC_KEY_MISMATCH);
+    }
+  }
+  Ok((signing_key, dpk))
+}
+
+/// Convert a BLS public key to a BBS public key for `message_count` messages
+fn bbs_public_key(dpk: &DeterministicPublicKey, message_count: usize) -> Result<BbsPublicKey, BBSError> {
+  let pk = match cached_public_key(dpk, message_count) {
+    Ok(pk) => pk,
+    Err(_) => { handle_err!("Failed to convert to BBS public key"); },
+  };
+  if pk.validate().is_err() {
+    handle_err!("Failed to validate public key");
+  }
+  Ok(pk)
+}
+
+/// Decode a base64 BBS public key, checking it's valid
+fn decode_bbs_public_key(public_key: &str) -> Result<BbsPublicKey, BBSError> {
+  let public_key = match BbsPublicKey::from_bytes(decode_base64(public_key, "public_key")?.as_slice()) {
+    Ok(public_key) => public_key,
+    Err(_) => { handle_err!("Failed to deserialize public key"); }
+  };
+  if public_key.validate().is_err() {
+    handle_err!("Invalid public key");
+  }
+  Ok(public_key)
+}
+
+/// Decode a base64 BBS public key a proof is verified against, any that can't be used being invalid
+fn proof_public_key(public_key: &str) -> Result<BbsPublicKey, BBSError> {
+  match base64::decode(public_key).map(|public_key| BbsPublicKey::from_bytes(public_key.as_slice())) {
+    Ok(Ok(public_key)) if public_key.validate().is_ok() => Ok(public_key),
+    _ => { handle_err!("Invalid public key"); }
+  }
+}
+
+/// Decode a base64 value of the context's `property`
+fn decode_base64(value: &str, property: &str) -> Result<Vec<u8>, BBSError> {
+  match base64::decode(value) {
+    Ok(bytes) => Ok(bytes),
+    Err(_) => { handle_err!(format!("Failed decoding base64 for: '{}'", property)); }
+  }
+}
+
+/// Decode a base64 value of the context's `property` to a point or scalar of `length` bytes,
+/// rejecting the bytes `From<[u8; N]>` would panic on
+fn decode_fixed<T: for<'a> TryFrom<&'a [u8]>>(value: &str, property: &str, length: usize) -> Result<T, BBSError> {
+  fixed_value(&decode_base64(value, property)?, property, length)
+}
+
+/// Secret material of the context's `property` as a scalar of `length` bytes, like `decode_fixed`
+fn fixed_value<T: for<'a> TryFrom<&'a [u8]>>(bytes: &[u8], property: &str, length: usize) -> Result<T, BBSError> {
+  if bytes.len() != length {
+    handle_err!(format!("Invalid length for: '{}', expected {} bytes", property, length));
+  }
+  let value = match T::try_from(bytes) {
+    Ok(value) => value,
+    Err(_) => { handle_err!(format!("Invalid value for: '{}'", property)); }
+  };
+  Ok(value)
+}
+
+/// Decode a base64 value of the context's `property` to a G1 point, like `decode_fixed`
+fn decode_point(value: &str, property: &str) -> Result<DefaultG1, BBSError> {
+  let bytes = decode_base64(value, property)?;
+  if bytes.len() != DefaultG1::COMPRESSED_LENGTH {
+    handle_err!(format!("Invalid length for: '{}', expected {} bytes", property, DefaultG1::COMPRESSED_LENGTH));
+  }
+  match DefaultG1::from_compressed(&bytes) {
+    Some(point) => Ok(point),
+    None => { handle_err!(format!("Invalid value for: '{}'", property)); }
+  }
+}
+
+/// Decode a base64 value of the context's `property` to a scalar, like `decode_fixed`
+fn decode_scalar(value: &str, property: &str) -> Result<DefaultScalar, BBSError> {
+  scalar_value(&decode_base64(value, property)?, property)
+}
+
+/// Bytes of the context's `property` as a scalar, like `fixed_value`
+fn scalar_value(bytes: &[u8], property: &str) -> Result<DefaultScalar, BBSError> {
+  let bytes = match <&[u8; FR_COMPRESSED_SIZE]>::try_from(bytes) {
+    Ok(bytes) => bytes,
+    Err(_) => { handle_err!(format!("Invalid length for: '{}', expected {} bytes", property, FR_COMPRESSED_SIZE)); }
+  };
+  match DefaultScalar::from_bytes(bytes) {
+    Some(scalar) => Ok(scalar),
+    None => { handle_err!(format!("Invalid value for: '{}'", property)); }
+  }
+}
+
+/// Decode a base64 secret into a buffer that is wiped when dropped
+fn secret_bytes(secret: &Secret, property: &str) -> Result<SecretBytes, BBSError> {
+  secret_bytes_of(&secret.0, property)
+}
+
+fn secret_bytes_of(secret_b64: &str, property: &str) -> Result<SecretBytes, BBSError> {
+  match decode_secret(secret_b64) {
+    Ok(secret) => Ok(secret),
+    Err(_) => { handle_err!(format!("Failed decoding base64 for: '{}'", property)); }
+  }
+}
+
+/// Take a secret key out of a JWK or base64 string, into a buffer that is wiped when dropped
+fn secret_key_bytes(secret_key: SecretKeyInput, property: &str) -> Result<SecretBytes, BBSError> {
+  match secret_key {
+    SecretKeyInput::Encoded(secret_key) => secret_bytes(&secret_key, property),
+    SecretKeyInput::Jwk(mut jwk) => take_jwk_secret_key(&mut jwk.0),
+  }
+}
+
+/// Check a scalar secret (secret key or blinding factor) has the length of one
+fn scalar_secret(secret: SecretBytes, property: &str) -> Result<SecretBytes, BBSError> {
+  if secret.len() != FR_COMPRESSED_SIZE {
+    handle_err!(format!("Invalid length for: '{}', expected {} bytes", property, FR_COMPRESSED_SIZE));
+  }
+  Ok(secret)
+}
+
+/// Decode an optional base64 property, empty where unset
+fn optional_base64(value: &Option<String>, property: &str) -> Result<Vec<u8>, BBSError> {
+  match value {
+    Some(value) => decode_base64(value, property),
+    None => Ok(Vec::new()),
+  }
+}
+
+/// Decode an array of base64 strings, such as 'messages'
+fn base64_array(values: &[String], property: &str) -> Result<Vec<Vec<u8>>, BBSError> {
+  values.iter()
+    .map(|value| decode_base64(value, property))
+    .collect()
+}
+
+/// Get the IETF BBS secret scalar by 'key_handle' or from 'secret_key', and its BLS12-381 G2
+/// public key. A 'public_key', or a JWK 'secret_key' with 'x', has to be the secret key's own
+fn ietf_key_pair(
+  key_handle: Option<u64>,
+  secret_key: Option<SecretKeyInput>,
+  public_key: Option<&PublicKeyInput>,
+) -> Result<(DefaultScalar, Vec<u8>), BBSError> {
+  let given_public_key = given_public_key(public_key, secret_key.as_ref())?;
+  let secret_key = signing_key(key_handle, secret_key)?;
+  let secret_key = secret_key.with(|secret_key| Zeroizing::new(secret_key.to_bytes_compressed_form()))?;
+  let secret_key = ietf_bbs::deserialize_scalar(secret_key.as_ref())?;
+
+  // signatures under a mismatched key would verify under neither
+  let public_key = ietf_bbs::secret_key_to_public_key::<DefaultBackend>(&secret_key);
+  if given_public_key.is_some_and(|given_public_key| given_public_key != public_key) {
+    handle_err!(PUBLIC_KEY_MISMATCH);
+  }
+  Ok((secret_key, public_key))
+}
+
+/// Check the 'password' isn't empty
+fn password(password: &Secret) -> Result<&str, BBSError> {
+  if password.0.is_empty() {
+    handle_err!("Property must not be empty: 'password'");
+  }
+  Ok(&password.0)
+}
+
+/// Serialize the handle and BLS public key of a newly stored key
+fn stored_key_to_json(key_handle: u64) -> Result<String, BBSError> {
+  let public_key = stored_public_key(key_handle)?;
+
+  let key = json!({
+    "key_handle": key_handle,
+    "public_key": base64::encode(public_key.to_bytes_compressed_form().as_slice()),
+  });
+
+  // Serialize return object to JSON string
+  match serde_json::to_string(&key) {
+    Ok(json_string) => Ok(json_string),
+    Err(_) => { handle_err!("Failed to stringify stored key"); },
+  }
+}
+
+/// Look up the BLS public key of the keystore entry for a 'key_handle'
+fn stored_public_key(key_handle: u64) -> Result<DeterministicPublicKey, BBSError> {
+  match get_public_key(key_handle) {
+    Some(public_key) => Ok(public_key),
+    None => { handle_err!(format!("Unknown key handle: {}", key_handle)); }
+  }
+}
+
+/// Signing key of an operation, stored in the keystore under a 'key_handle' or taken from 'secret_key'
+enum SigningKey {
+  Stored(u64),
+  Secret(SecretKey),
+}
+
+impl SigningKey {
+  /// Run `f` with the secret key, which a stored key only lends rather than copying out of the keystore
+  fn with<T>(&self, f: impl FnOnce(&SecretKey) -> T) -> Result<T, BBSError> {
+    match self {
+      SigningKey::Secret(secret_key) => Ok(f(secret_key)),
+      SigningKey::Stored(key_handle) => match with_key(*key_handle, |stored_key| f(&stored_key.secret_key)) {
+        Some(result) => Ok(result),
+        None => { handle_err!(format!("Unknown key handle: {}", key_handle)); }
+      },
     }
-    revealed_messages
+  }
+
+  /// BLS public key of the signing key
+  fn public_key(&self) -> Result<DeterministicPublicKey, BBSError> {
+    match self {
+      SigningKey::Secret(secret_key) => bbs_plus_backend::deterministic_public_key::<DefaultBackend>(secret_key),
+      SigningKey::Stored(key_handle) => stored_public_key(*key_handle),
+    }
+  }
+}
+
+/// Get the signing key by 'key_handle' where set, otherwise from the base64 or JWK 'secret_key'
+fn signing_key(key_handle: Option<u64>, secret_key: Option<SecretKeyInput>) -> Result<SigningKey, BBSError> {
+  match (key_handle, secret_key) {
+    (Some(_), Some(_)) => { handle_err!("Only one of 'key_handle' or 'secret_key' may be set"); },
+    // checked to exist up front, so an unknown handle fails before any other work
+    (Some(key_handle), None) => stored_public_key(key_handle).map(|_| SigningKey::Stored(key_handle)),
+    (None, Some(secret_key)) => {
+      let secret_key = secret_key_bytes(secret_key, "secret_key")?;
+      fixed_value(&secret_key, "secret_key", FR_COMPRESSED_SIZE).map(SigningKey::Secret)
+    },
+    (None, None) => { handle_err!("Property not set: 'secret_key'"); }
+  }
 }
@@ -0,0 +1,345 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::TryFrom;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use ciborium::value::Value as Cbor;
+use hmac::{Hmac, Mac};
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+use super::curve::{DefaultBackend, DefaultScalar};
+use super::ietf_bbs;
+use super::limits::check_messages;
+use super::nquads::{parse_nquads, Quad};
+use super::rdfc::canonicalize;
+
+// W3C Data Integrity `bbs-2023` cryptosuite (https://www.w3.org/TR/vc-di-bbs/) over N-Quads documents.
+// Statements are selected by their index in the document's canonical (RDFC-1.0) form, standing in
+// for the JSON Pointers a JSON-LD document would use
+
+/// Base proofs hold mandatory statement indexes where the spec's (`0xd9 0x5d 0x02`) hold mandatory
+/// JSON Pointers, so they take a header of their own rather than pass for spec base proofs
+const BASE_PROOF_HEADER: [u8; 3] = [0xd9, 0x5d, 0x80];
+/// Derived proofs hold mandatory and selective indexes, as the spec's do
+const DERIVED_PROOF_HEADER: [u8; 3] = [0xd9, 0x5d, 0x03];
+pub const HMAC_KEY_LENGTH: usize = 32;
+
+/// Decoded base proof value, as created by the issuer
+pub struct BaseProof {
+  pub signature: Vec<u8>,
+  pub header: Vec<u8>,
+  pub public_key: Vec<u8>,
+  pub hmac_key: Vec<u8>,
+  pub mandatory_indexes: Vec<usize>,
+}
+
+/// Decoded derived proof value, as presented by the holder
+pub struct DerivedProof {
+  pub proof: Vec<u8>,
+  /// Canonical blank node label index of the revealed document to HMAC label index
+  pub label_map: BTreeMap<usize, usize>,
+  pub mandatory_indexes: Vec<usize>,
+  pub selective_indexes: Vec<usize>,
+  pub presentation_header: Vec<u8>,
+}
+
+/// Statements of an N-Quads document, limited like a context's messages before they're
+/// canonicalized
+fn parse_document(document: &str) -> Result<Vec<Quad>, BBSError> {
+  let quads = parse_nquads(document)?;
+  check_messages("'document' statement", quads.iter().map(|quad| quad.to_string().len()))?;
+  Ok(quads)
+}
+
+/// Canonical N-Quads of a document, one statement per entry
+pub fn canonical_statements(document: &str) -> Result<Vec<String>, BBSError> {
+  Ok(canonicalize(&parse_document(document)?)?.quads)
+}
+
+/// Create the base proof value over a document's canonical statements, signing all but the
+/// mandatory ones as BBS messages and the mandatory ones through the BBS header
+pub fn create_base_proof(
+  secret_key: &DefaultScalar,
+  public_key: &[u8],
+  document: &str,
+  proof_config: &str,
+  mandatory_indexes: &[usize],
+  hmac_key: &[u8],
+) -> Result<String, BBSError> {
+  let mandatory_indexes = sorted_indexes(mandatory_indexes);
+  let dataset = canonicalize(&parse_document(document)?)?;
+  let hmac_labels = hmac_label_map(dataset.labels.values(), hmac_key)?;
+  let mandatory = select_statements(&dataset.quads, &mandatory_indexes, &hmac_labels, "mandatory")?;
+  let non_mandatory = without(relabeled_statements(&dataset.quads, &hmac_labels)?, &mandatory);
+
+  let header = bbs_header(proof_config, &mandatory)?;
+  let signature = ietf_bbs::sign::<DefaultBackend>(secret_key, public_key, &header, &to_messages(&non_mandatory))?;
+
+  let proof_value = Cbor::Array(vec![
+    Cbor::Bytes(signature),
+    Cbor::Bytes(header),
+    Cbor::Bytes(public_key.to_vec()),
+    Cbor::Bytes(hmac_key.to_vec()),
+    cbor_indexes(&mandatory_indexes),
+  ]);
+  encode_proof_value(&BASE_PROOF_HEADER, &proof_value)
+}
+
+/// Disclose the mandatory and `selective_indexes` statements of a document signed by a base proof.
+/// Returns the canonical N-Quads of the revealed document and its derived proof value
+pub fn create_derived_proof<R: RngCore + CryptoRng>(
+  document: &str,
+  proof_value: &str,
+  selective_indexes: &[usize],
+  presentation_header: &[u8],
+  rng: &mut R,
+) -> Result<(String, String), BBSError> {
+  let base_proof = decode_base_proof(proof_value)?;
+  let selective_indexes = sorted_indexes(selective_indexes);
+
+  let dataset = canonicalize(&parse_document(document)?)?;
+  let hmac_labels = hmac_label_map(dataset.labels.values(), &base_proof.hmac_key)?;
+  let mandatory = select_statements(&dataset.quads, &base_proof.mandatory_indexes, &hmac_labels, "mandatory")?;
+  let selective = select_statements(&dataset.quads, &selective_indexes, &hmac_labels, "selective")?;
+  let non_mandatory = without(relabeled_statements(&dataset.quads, &hmac_labels)?, &mandatory);
+
+  // selected statements that are mandatory are disclosed through the header instead
+  let disclosed_indexes: Vec<usize> = selective.iter()
+    .filter_map(|statement| non_mandatory.binary_search(statement).ok())
+    .collect();
+  let proof = ietf_bbs::proof_gen::<DefaultBackend, _>(
+    &base_proof.public_key,
+    &base_proof.signature,
+    &base_proof.header,
+    presentation_header,
+    &to_messages(&non_mandatory),
+    &disclosed_indexes,
+    rng,
+  )?;
+
+  // the revealed document holds the mandatory and selected statements, in their canonical form
+  let revealed_indexes: Vec<usize> = sorted_indexes(&[&base_proof.mandatory_indexes[..], &selective_indexes[..]].concat());
+  let revealed_quads = revealed_indexes.iter()
+    .map(|index| parse_statement(&dataset.quads[*index]))
+    .collect::<Result<Vec<Quad>, BBSError>>()?;
+  let revealed = canonicalize(&revealed_quads)?;
+
+  // the verifier gets the HMAC label of each revealed blank node by its canonical label there;
+  // the revealed quads carry their canonical labels in the full document as input labels
+  let mut label_map = BTreeMap::new();
+  for (full_label, revealed_label) in &revealed.labels {
+    label_map.insert(label_index(revealed_label, "c14n")?, label_index(&hmac_labels[full_label], "b")?);
+  }
+
+  let revealed_statements = select_statements(&dataset.quads, &revealed_indexes, &hmac_labels, "revealed")?;
+  let mandatory_indexes: Vec<usize> = revealed_statements.iter().enumerate()
+    .filter(|(_, statement)| mandatory.binary_search(statement).is_ok())
+    .map(|(index, _)| index)
+    .collect();
+
+  let derived_proof_value = Cbor::Array(vec![
+    Cbor::Bytes(proof),
+    Cbor::Map(label_map.into_iter().map(|(key, value)| (Cbor::Integer(key.into()), Cbor::Integer(value.into()))).collect()),
+    cbor_indexes(&mandatory_indexes),
+    cbor_indexes(&disclosed_indexes),
+    Cbor::Bytes(presentation_header.to_vec()),
+  ]);
+
+  Ok((revealed.quads.concat(), encode_proof_value(&DERIVED_PROOF_HEADER, &derived_proof_value)?))
+}
+
+/// Verify a derived proof over the revealed document it was presented with
+pub fn verify_derived_proof(
+  public_key: &[u8],
+  document: &str,
+  proof_value: &str,
+  proof_config: &str,
+) -> Result<bool, BBSError> {
+  let derived_proof = decode_derived_proof(proof_value)?;
+
+  let dataset = canonicalize(&parse_document(document)?)?;
+  let mut labels = HashMap::new();
+  for canonical_label in dataset.labels.values() {
+    match derived_proof.label_map.get(&label_index(canonical_label, "c14n")?) {
+      Some(index) => labels.insert(canonical_label.clone(), format!("b{}", index)),
+      None => { handle_err!("Derived proof label map doesn't cover the document's blank nodes"); }
+    };
+  }
+
+  // mandatory indexes address the relabeled statements, in their sorted order
+  let statements = relabeled_statements(&dataset.quads, &labels)?;
+  if derived_proof.mandatory_indexes.iter().any(|index| *index >= statements.len()) {
+    handle_err!("Invalid mandatory statement index");
+  }
+  let mandatory: Vec<String> = derived_proof.mandatory_indexes.iter().map(|index| statements[*index].clone()).collect();
+  let non_mandatory = without(statements, &mandatory);
+
+  let header = bbs_header(proof_config, &mandatory)?;
+  ietf_bbs::proof_verify::<DefaultBackend>(
+    public_key,
+    &derived_proof.proof,
+    &header,
+    &derived_proof.presentation_header,
+    &to_messages(&non_mandatory),
+    &derived_proof.selective_indexes,
+  )
+}
+
+/// Decode a base proof value
+pub fn decode_base_proof(proof_value: &str) -> Result<BaseProof, BBSError> {
+  let components = decode_proof_value(proof_value, &BASE_PROOF_HEADER, "base")?;
+  Ok(BaseProof {
+    signature: cbor_bytes(&components[0])?,
+    header: cbor_bytes(&components[1])?,
+    public_key: cbor_bytes(&components[2])?,
+    hmac_key: cbor_bytes(&components[3])?,
+    mandatory_indexes: sorted_indexes(&cbor_usizes(&components[4])?),
+  })
+}
+
+/// Decode a derived proof value
+pub fn decode_derived_proof(proof_value: &str) -> Result<DerivedProof, BBSError> {
+  let components = decode_proof_value(proof_value, &DERIVED_PROOF_HEADER, "derived")?;
+  let label_map = match &components[1] {
+    Cbor::Map(entries) => entries.iter()
+      .map(|(key, value)| Ok((cbor_usize(key)?, cbor_usize(value)?)))
+      .collect::<Result<BTreeMap<usize, usize>, BBSError>>()?,
+    _ => { handle_err!("Invalid derived proof value, expected a label map"); }
+  };
+
+  Ok(DerivedProof {
+    proof: cbor_bytes(&components[0])?,
+    label_map,
+    mandatory_indexes: sorted_indexes(&cbor_usizes(&components[2])?),
+    selective_indexes: cbor_usizes(&components[3])?,
+    presentation_header: cbor_bytes(&components[4])?,
+  })
+}
+
+/// Shuffled label map: each canonical label is HMACed, and the digests' sort order gives its
+/// `b<n>` label, hiding the canonical order from the verifier
+fn hmac_label_map<'a, I: Iterator<Item = &'a String>>(canonical_labels: I, hmac_key: &[u8]) -> Result<HashMap<String, String>, BBSError> {
+  let mut digests = HashMap::new();
+  for canonical_label in canonical_labels {
+    let mut mac = match Hmac::<Sha256>::new_varkey(hmac_key) {
+      Ok(mac) => mac,
+      Err(_) => { handle_err!("Invalid HMAC key"); }
+    };
+    mac.input(canonical_label.as_bytes());
+    digests.insert(canonical_label.clone(), base64::encode_config(mac.result().code(), base64::URL_SAFE_NO_PAD));
+  }
+
+  let mut sorted: Vec<&String> = digests.values().collect();
+  sorted.sort_unstable();
+  Ok(digests.iter()
+    .map(|(label, digest)| (label.clone(), format!("b{}", sorted.binary_search(&digest).unwrap())))
+    .collect())
+}
+
+/// Relabel canonical statements by their blank nodes' canonical labels, and sort them
+fn relabeled_statements(canonical: &[String], labels: &HashMap<String, String>) -> Result<Vec<String>, BBSError> {
+  let mut statements = canonical.iter()
+    .map(|statement| relabel_statement(statement, labels))
+    .collect::<Result<Vec<String>, BBSError>>()?;
+  statements.sort_unstable();
+  Ok(statements)
+}
+
+/// Relabeled canonical statements at `indexes`, sorted
+fn select_statements(canonical: &[String], indexes: &[usize], labels: &HashMap<String, String>, kind: &str) -> Result<Vec<String>, BBSError> {
+  if indexes.iter().any(|index| *index >= canonical.len()) {
+    handle_err!(format!("Invalid {} statement index, the document has {} statements", kind, canonical.len()));
+  }
+  let selected: Vec<String> = indexes.iter().map(|index| canonical[*index].clone()).collect();
+  relabeled_statements(&selected, labels)
+}
+
+fn relabel_statement(statement: &str, labels: &HashMap<String, String>) -> Result<String, BBSError> {
+  let quad = parse_statement(statement)?;
+  if let Some(label) = quad.blank_nodes().find(|label| !labels.contains_key(*label)) {
+    handle_err!(format!("No label for blank node _:{}", label));
+  }
+  Ok(quad.relabel(|label| labels[label].clone()).to_string())
+}
+
+fn parse_statement(statement: &str) -> Result<Quad, BBSError> {
+  match parse_nquads(statement)?.pop() {
+    Some(quad) => Ok(quad),
+    None => { handle_err!("Expected an N-Quads statement"); }
+  }
+}
+
+/// Sorted `statements` that aren't in the sorted `excluded`
+fn without(statements: Vec<String>, excluded: &[String]) -> Vec<String> {
+  statements.into_iter().filter(|statement| excluded.binary_search(statement).is_err()).collect()
+}
+
+fn to_messages(statements: &[String]) -> Vec<Vec<u8>> {
+  statements.iter().map(|statement| statement.as_bytes().to_vec()).collect()
+}
+
+/// `proofHash || mandatoryHash`: SHA-256 of the canonical proof configuration and of the mandatory statements
+fn bbs_header(proof_config: &str, mandatory: &[String]) -> Result<Vec<u8>, BBSError> {
+  let mut header = Sha256::digest(canonical_statements(proof_config)?.concat().as_bytes()).to_vec();
+  header.extend_from_slice(&Sha256::digest(mandatory.concat().as_bytes()));
+  Ok(header)
+}
+
+fn sorted_indexes(indexes: &[usize]) -> Vec<usize> {
+  indexes.iter().cloned().collect::<BTreeSet<usize>>().into_iter().collect()
+}
+
+/// Index `n` of a `<prefix><n>` blank node label
+fn label_index(label: &str, prefix: &str) -> Result<usize, BBSError> {
+  match label.strip_prefix(prefix).and_then(|index| index.parse().ok()) {
+    Some(index) => Ok(index),
+    None => { handle_err!(format!("Invalid blank node label: {}", label)); }
+  }
+}
+
+/// Multibase base64url (`u`) of the header bytes followed by the CBOR components
+fn encode_proof_value(header: &[u8], components: &Cbor) -> Result<String, BBSError> {
+  let mut bytes = header.to_vec();
+  if ciborium::ser::into_writer(components, &mut bytes).is_err() {
+    handle_err!("Failed to encode proof value");
+  }
+  Ok(format!("u{}", base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)))
+}
+
+fn decode_proof_value(proof_value: &str, header: &[u8], kind: &str) -> Result<Vec<Cbor>, BBSError> {
+  let bytes = match proof_value.strip_prefix('u').map(|value| base64::decode_config(value, base64::URL_SAFE_NO_PAD)) {
+    Some(Ok(bytes)) => bytes,
+    _ => { handle_err!(format!("Invalid {} proof value, expected multibase base64url", kind)); }
+  };
+  if !bytes.starts_with(header) {
+    handle_err!(format!("Invalid {} proof value header", kind));
+  }
+
+  match ciborium::de::from_reader(&bytes[header.len()..]) {
+    Ok(Cbor::Array(components)) if components.len() == 5 => Ok(components),
+    _ => { handle_err!(format!("Invalid {} proof value, expected 5 CBOR components", kind)); }
+  }
+}
+
+fn cbor_indexes(indexes: &[usize]) -> Cbor {
+  Cbor::Array(indexes.iter().map(|index| Cbor::Integer((*index).into())).collect())
+}
+
+fn cbor_bytes(value: &Cbor) -> Result<Vec<u8>, BBSError> {
+  match value {
+    Cbor::Bytes(bytes) => Ok(bytes.clone()),
+    _ => { handle_err!("Invalid proof value, expected a CBOR byte string"); }
+  }
+}
+
+fn cbor_usize(value: &Cbor) -> Result<usize, BBSError> {
+  match value.as_integer().and_then(|integer| usize::try_from(integer).ok()) {
+    Some(index) => Ok(index),
+    None => { handle_err!("Invalid proof value, expected a CBOR unsigned integer"); }
+  }
+}
+
+fn cbor_usizes(value: &Cbor) -> Result<Vec<usize>, BBSError> {
+  match value {
+    Cbor::Array(values) => values.iter().map(cbor_usize).collect(),
+    _ => { handle_err!("Invalid proof value, expected a CBOR array"); }
+  }
+}
@@ -0,0 +1,602 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::{TryFrom, TryInto};
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use bbs_plus::prelude::{
+  DeterministicPublicKey,
+  PoKOfSignatureProofStatus,
+  SecretKey,
+  SignatureMessage,
+  ToVariableLengthBytes,
+  HashElem,
+  FR_COMPRESSED_SIZE,
+  G1_COMPRESSED_SIZE,
+  G2_COMPRESSED_SIZE,
+  SIGNATURE_COMPRESSED_SIZE,
+};
+use ff_zeroize::Field;
+use pairing_plus::bls12_381::Fr;
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+use super::curve::{CurveBackend, Group, Scalar, SCALAR_LENGTH};
+
+// BBS+ signatures, blind signatures and proofs of knowledge of a signature, as the `bbs` crate
+// builds them, over any `CurveBackend`. Generators, hashes and random scalars are derived the way
+// the crate derives them, so every backend reads what the others build, and seeded operations give
+// the same bytes on each
+
+/// Length of `A`, `A-bar` and `d`, and the proof of knowledge length, leading a proof
+const PROOF_POINTS_LENGTH: usize = 3 * G1_COMPRESSED_SIZE + 4;
+/// Length of the commitment and response count leading a proof of knowledge
+const PROOF_G1_BASE_LENGTH: usize = G1_COMPRESSED_SIZE + 4;
+
+fn scalar_from_fr<S: Scalar>(fr: &Fr) -> S {
+  S::from_bytes(&Scalar::to_bytes(fr)).unwrap()
+}
+
+/// Hash to a scalar, as the `bbs` crate hashes messages, nonces and challenges
+pub fn hash_to_scalar<S: Scalar>(data: &[u8]) -> S {
+  scalar_from_fr(SignatureMessage::hash(data).as_ref())
+}
+
+/// Random scalar drawn as the `bbs` crate draws it, so a seeded RNG gives the same scalar on any backend
+fn random_scalar<S: Scalar, R: RngCore + CryptoRng>(rng: &mut R) -> S {
+  let mut fr = Fr::random(rng);
+  let scalar = scalar_from_fr(&fr);
+  fr.zeroize();
+  scalar
+}
+
+fn zero<S: Scalar>() -> S {
+  S::from_bytes(&[0u8; SCALAR_LENGTH]).unwrap()
+}
+
+fn negate<S: Scalar>(scalar: &S) -> S {
+  zero::<S>().sub(scalar)
+}
+
+/// Secret key as a scalar of the backend
+pub fn secret_scalar<S: Scalar>(secret_key: &SecretKey) -> Result<S, BBSError> {
+  let mut bytes = secret_key.to_bytes_compressed_form();
+  let scalar = S::from_bytes(&bytes);
+  bytes.zeroize();
+  match scalar {
+    Some(scalar) => Ok(scalar),
+    None => { handle_err!("Invalid secret key"); }
+  }
+}
+
+/// BLS public key `w = g2^x` of a secret key
+pub fn deterministic_public_key<B: CurveBackend>(secret_key: &SecretKey) -> Result<DeterministicPublicKey, BBSError> {
+  let mut secret_key: B::Scalar = secret_scalar(secret_key)?;
+  let public_key = B::G2::generator().mul(&secret_key).to_compressed();
+  secret_key.zeroize();
+  match DeterministicPublicKey::try_from(public_key.as_slice()) {
+    Ok(public_key) => Ok(public_key),
+    Err(_) => { handle_err!("Invalid public key"); }
+  }
+}
+
+fn read_point<G: Group>(bytes: &[u8]) -> Option<G> {
+  G::from_compressed(bytes)
+}
+
+fn read_scalar<S: Scalar>(bytes: &[u8]) -> Option<S> {
+  S::from_bytes(bytes.try_into().ok()?)
+}
+
+fn sum_of_products<G: Group>(points: &[G], scalars: &[G::Scalar]) -> G {
+  points.iter().zip(scalars).fold(G::identity(), |sum, (point, scalar)| sum.add(&point.mul(scalar)))
+}
+
+/// BBS+ public key: `w` and the generators `h0` and `h_i` for each message
+pub struct PublicKey<B: CurveBackend> {
+  pub w: B::G2,
+  pub h0: B::G1,
+  pub h: Vec<B::G1>,
+}
+
+impl<B: CurveBackend> Clone for PublicKey<B> {
+  fn clone(&self) -> Self {
+    PublicKey { w: self.w, h0: self.h0, h: self.h.clone() }
+  }
+}
+
+impl<B: CurveBackend> PublicKey<B> {
+  /// Public key for `message_count` messages of a BLS public key, whose generators are hashed
+  /// by the `bbs` crate
+  pub fn from_deterministic(dpk: &DeterministicPublicKey, message_count: usize) -> Result<Self, BBSError> {
+    PublicKey::from_bytes(&dpk.to_public_key(message_count)?.to_bytes_compressed_form())
+  }
+
+  /// Public key from `w || h0 || message count || h_i...`, checking no point is the identity
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, BBSError> {
+    let base_length = G2_COMPRESSED_SIZE + G1_COMPRESSED_SIZE + 4;
+    if bytes.len() < base_length || (bytes.len() - base_length) % G1_COMPRESSED_SIZE != 0 {
+      return Err(BBSErrorKind::MalformedPublicKey.into());
+    }
+    let count_bytes = &bytes[base_length - 4..base_length];
+    let message_count = u32::from_be_bytes(count_bytes.try_into().unwrap()) as usize;
+    if (bytes.len() - base_length) / G1_COMPRESSED_SIZE != message_count {
+      return Err(BBSErrorKind::MalformedPublicKey.into());
+    }
+
+    let w = read_point(&bytes[..G2_COMPRESSED_SIZE]);
+    let h0 = read_point(&bytes[G2_COMPRESSED_SIZE..base_length - 4]);
+    let h: Option<Vec<B::G1>> = bytes[base_length..].chunks(G1_COMPRESSED_SIZE).map(read_point).collect();
+    let public_key = match (w, h0, h) {
+      (Some(w), Some(h0), Some(h)) => PublicKey { w, h0, h },
+      _ => return Err(BBSErrorKind::MalformedPublicKey.into()),
+    };
+    public_key.validate()?;
+    Ok(public_key)
+  }
+
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut bytes = self.w.to_compressed();
+    bytes.append(&mut self.h0.to_compressed());
+    bytes.extend_from_slice(&(self.h.len() as u32).to_be_bytes());
+    for generator in &self.h {
+      bytes.append(&mut generator.to_compressed());
+    }
+    bytes
+  }
+
+  pub fn message_count(&self) -> usize {
+    self.h.len()
+  }
+
+  /// Check no point of the key is the identity
+  pub fn validate(&self) -> Result<(), BBSError> {
+    if self.w.is_identity() || self.h0.is_identity() || self.h.iter().any(Group::is_identity) {
+      return Err(BBSErrorKind::MalformedPublicKey.into());
+    }
+    Ok(())
+  }
+
+  /// `g1 * h0^s * h_i^m_i...` for the messages at their indices
+  fn signature_base<'a>(&self, s: &B::Scalar, messages: impl IntoIterator<Item = (usize, &'a B::Scalar)>) -> Result<B::G1, BBSError>
+  where
+    B::Scalar: 'a,
+  {
+    let mut b = B::G1::generator().add(&self.h0.mul(s));
+    for (index, message) in messages {
+      match self.h.get(index) {
+        Some(generator) => b = b.add(&generator.mul(message)),
+        None => return Err(BBSErrorKind::PublicKeyGeneratorMessageCountMismatch(index, self.message_count()).into()),
+      }
+    }
+    Ok(b)
+  }
+}
+
+/// BBS+ signature `A, e, s`, which is also the form of a blind signature
+pub struct Signature<B: CurveBackend> {
+  a: B::G1,
+  e: B::Scalar,
+  s: B::Scalar,
+}
+
+impl<B: CurveBackend> TryFrom<&[u8]> for Signature<B> {
+  type Error = BBSError;
+
+  fn try_from(bytes: &[u8]) -> Result<Self, BBSError> {
+    if bytes.len() != SIGNATURE_COMPRESSED_SIZE {
+      return Err(BBSErrorKind::InvalidNumberOfBytes(SIGNATURE_COMPRESSED_SIZE, bytes.len()).into());
+    }
+    let a = read_point(&bytes[..G1_COMPRESSED_SIZE]);
+    let e = read_scalar(&bytes[G1_COMPRESSED_SIZE..G1_COMPRESSED_SIZE + FR_COMPRESSED_SIZE]);
+    let s = read_scalar(&bytes[G1_COMPRESSED_SIZE + FR_COMPRESSED_SIZE..]);
+    match (a, e, s) {
+      (Some(a), Some(e), Some(s)) => Ok(Signature { a, e, s }),
+      _ => { handle_err!("Invalid signature"); }
+    }
+  }
+}
+
+impl<B: CurveBackend> Signature<B> {
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut bytes = self.a.to_compressed();
+    bytes.extend_from_slice(&self.e.to_bytes());
+    bytes.extend_from_slice(&self.s.to_bytes());
+    bytes
+  }
+
+  /// Verify the signature over all the messages of the public key
+  pub fn verify(&self, public_key: &PublicKey<B>, messages: &[B::Scalar]) -> Result<bool, BBSError> {
+    if messages.len() != public_key.message_count() {
+      return Err(BBSErrorKind::PublicKeyGeneratorMessageCountMismatch(public_key.message_count(), messages.len()).into());
+    }
+    let b = public_key.signature_base(&self.s, messages.iter().enumerate())?;
+
+    // e(A, w * g2^e) * e(b^-1, g2) == 1
+    let w_e = B::G2::generator().mul(&self.e).add(&public_key.w);
+    Ok(B::pairings_are_identity(&self.a, &w_e, &b.neg(), &B::G2::generator()))
+  }
+
+  /// Signature of a blind signature, given the blinding factor of its commitment
+  pub fn unblind(&self, blinding_factor: &B::Scalar) -> Signature<B> {
+    Signature { a: self.a, e: self.e, s: self.s.add(blinding_factor) }
+  }
+}
+
+/// `A` for a signature with `e` over `b`, which is `b * 1/(x + e)`
+fn signature_point<B: CurveBackend>(b: &B::G1, secret_key: &SecretKey, e: &B::Scalar) -> Result<B::G1, BBSError> {
+  let mut secret_key: B::Scalar = secret_scalar(secret_key)?;
+  let mut exponent = secret_key.add(e);
+  let inverse = exponent.inverse();
+  secret_key.zeroize();
+  exponent.zeroize();
+  match inverse {
+    Some(inverse) => Ok(b.mul(&inverse)),
+    None => { handle_err!("Invalid secret key"); }
+  }
+}
+
+/// Sign messages known to the signer, drawing `e` and `s` from `rng`
+pub fn sign<B: CurveBackend, R: RngCore + CryptoRng>(
+  secret_key: &SecretKey,
+  public_key: &PublicKey<B>,
+  messages: &[B::Scalar],
+  rng: &mut R,
+) -> Result<Signature<B>, BBSError> {
+  if messages.len() > public_key.message_count() {
+    return Err(BBSErrorKind::PublicKeyGeneratorMessageCountMismatch(public_key.message_count(), messages.len()).into());
+  }
+  let e = random_scalar(rng);
+  let s = random_scalar(rng);
+
+  let b = public_key.signature_base(&s, messages.iter().enumerate())?;
+  Ok(Signature { a: signature_point::<B>(&b, secret_key, &e)?, e, s })
+}
+
+/// Sign a commitment to blinded messages and the known messages at their indices
+pub fn blind_sign<B: CurveBackend, R: RngCore + CryptoRng>(
+  secret_key: &SecretKey,
+  public_key: &PublicKey<B>,
+  commitment: &B::G1,
+  messages: &BTreeMap<usize, B::Scalar>,
+  rng: &mut R,
+) -> Result<Signature<B>, BBSError> {
+  if messages.len() > public_key.message_count() {
+    return Err(BBSErrorKind::PublicKeyGeneratorMessageCountMismatch(public_key.message_count(), messages.len()).into());
+  }
+  let e = random_scalar(rng);
+  let s = random_scalar(rng);
+
+  // commitment * g1 * h0^s * h_i^m_i... for the known messages
+  let b = public_key.signature_base(&s, messages.iter().map(|(index, message)| (*index, message)))?.add(commitment);
+  Ok(Signature { a: signature_point::<B>(&b, secret_key, &e)?, e, s })
+}
+
+/// Commitment to bases with random blinding factors, the first move of a proof of knowledge of
+/// the exponents of the bases
+struct ProverCommitted<B: CurveBackend> {
+  bases: Vec<B::G1>,
+  blinding_factors: Vec<B::Scalar>,
+  commitment: B::G1,
+}
+
+impl<B: CurveBackend> ProverCommitted<B> {
+  fn new(bases: Vec<B::G1>, blinding_factors: Vec<B::Scalar>) -> Self {
+    let commitment = sum_of_products(&bases, &blinding_factors);
+    ProverCommitted { bases, blinding_factors, commitment }
+  }
+
+  /// Bases and commitment, as hashed for a challenge
+  fn to_bytes(&self) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for base in &self.bases {
+      bytes.append(&mut base.to_uncompressed());
+    }
+    bytes.append(&mut self.commitment.to_uncompressed());
+    bytes
+  }
+
+  /// Respond to the challenge for the exponents `secrets` of the bases
+  fn gen_proof(mut self, challenge: &B::Scalar, secrets: &[B::Scalar]) -> ProofG1<B> {
+    let responses = self.blinding_factors.iter()
+      .zip(secrets)
+      .map(|(blinding_factor, secret)| blinding_factor.sub(&challenge.mul(secret)))
+      .collect();
+    self.blinding_factors.iter_mut().for_each(Zeroize::zeroize);
+    ProofG1 { commitment: self.commitment, responses }
+  }
+}
+
+/// Proof of knowledge of the exponents of bases: the prover's commitment and its responses
+pub struct ProofG1<B: CurveBackend> {
+  commitment: B::G1,
+  responses: Vec<B::Scalar>,
+}
+
+impl<B: CurveBackend> ProofG1<B> {
+  /// Proof from `commitment || response count || responses...`
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, BBSError> {
+    if bytes.len() < PROOF_G1_BASE_LENGTH {
+      handle_err!("Invalid proof: truncated proof of knowledge");
+    }
+    let response_count = u32::from_be_bytes(bytes[G1_COMPRESSED_SIZE..PROOF_G1_BASE_LENGTH].try_into().unwrap()) as usize;
+    let responses = &bytes[PROOF_G1_BASE_LENGTH..];
+    if responses.len() % FR_COMPRESSED_SIZE != 0 || responses.len() / FR_COMPRESSED_SIZE != response_count {
+      handle_err!("Invalid proof: proof of knowledge length is different from its response count");
+    }
+
+    let commitment = match read_point(&bytes[..G1_COMPRESSED_SIZE]) {
+      Some(commitment) => commitment,
+      None => { handle_err!("Invalid proof: invalid point"); }
+    };
+    let responses = match responses.chunks(FR_COMPRESSED_SIZE).map(read_scalar).collect() {
+      Some(responses) => responses,
+      None => { handle_err!("Invalid proof: invalid scalar"); }
+    };
+    Ok(ProofG1 { commitment, responses })
+  }
+
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut bytes = self.commitment.to_compressed();
+    bytes.extend_from_slice(&(self.responses.len() as u32).to_be_bytes());
+    for response in &self.responses {
+      bytes.extend_from_slice(&response.to_bytes());
+    }
+    bytes
+  }
+
+  /// `bases^responses * commitment^challenge`, which is the prover's commitment for a valid proof,
+  /// or `None` where the proof doesn't have a response for each base
+  fn challenge_contribution(&self, bases: &[B::G1], commitment: &B::G1, challenge: &B::Scalar) -> Option<B::G1> {
+    if bases.len() != self.responses.len() {
+      return None;
+    }
+    Some(sum_of_products(bases, &self.responses).add(&commitment.mul(challenge)))
+  }
+
+  fn verify(&self, bases: &[B::G1], commitment: &B::G1, challenge: &B::Scalar) -> Option<bool> {
+    let contribution = self.challenge_contribution(bases, commitment, challenge)?;
+    Some(contribution.add(&self.commitment.neg()).is_identity())
+  }
+}
+
+/// Commitment to blinded messages, with a proof of knowledge of them bound to a nonce
+pub struct BlindSignatureContext<B: CurveBackend> {
+  pub commitment: B::G1,
+  pub challenge_hash: B::Scalar,
+  pub proof_of_hidden_messages: ProofG1<B>,
+}
+
+/// Bases and commitments of a proof of hidden messages, and the nonce, as hashed for its challenge
+fn blind_signature_challenge<B: CurveBackend>(bases: &[B::G1], contribution: &B::G1, commitment: &B::G1, nonce: &B::Scalar) -> B::Scalar {
+  let mut bytes = Vec::new();
+  for base in bases.iter().chain(Some(contribution)).chain(Some(commitment)) {
+    bytes.append(&mut base.to_uncompressed());
+  }
+  bytes.extend_from_slice(&nonce.to_bytes());
+  hash_to_scalar(&bytes)
+}
+
+/// Commit to the blinded messages at their indices, returning the commitment context and its
+/// blinding factor
+pub fn blind_signature_context<B: CurveBackend, R: RngCore + CryptoRng>(
+  public_key: &PublicKey<B>,
+  messages: &BTreeMap<usize, B::Scalar>,
+  nonce: &B::Scalar,
+  rng: &mut R,
+) -> Result<(BlindSignatureContext<B>, B::Scalar), BBSError> {
+  let blinding_factor = random_scalar(rng);
+
+  // h0^blinding_factor * h_i^m_i...
+  let mut bases = vec![public_key.h0];
+  let mut blinding_factors = vec![random_scalar(rng)];
+  let mut secrets = vec![blinding_factor];
+  for (index, message) in messages {
+    match public_key.h.get(*index) {
+      Some(generator) => bases.push(*generator),
+      None => return Err(BBSErrorKind::PublicKeyGeneratorMessageCountMismatch(*index, public_key.message_count()).into()),
+    }
+    blinding_factors.push(random_scalar(rng));
+    secrets.push(*message);
+  }
+  let commitment = sum_of_products(&bases, &secrets);
+  let committed = ProverCommitted::<B>::new(bases, blinding_factors);
+
+  let mut challenge_bytes = committed.to_bytes();
+  challenge_bytes.append(&mut commitment.to_uncompressed());
+  challenge_bytes.extend_from_slice(&nonce.to_bytes());
+  let challenge_hash = hash_to_scalar(&challenge_bytes);
+  let proof_of_hidden_messages = committed.gen_proof(&challenge_hash, &secrets);
+  secrets.iter_mut().for_each(Zeroize::zeroize);
+
+  Ok((BlindSignatureContext { commitment, challenge_hash, proof_of_hidden_messages }, blinding_factor))
+}
+
+impl<B: CurveBackend> BlindSignatureContext<B> {
+  /// Verify the proof of knowledge of the messages committed to at the `blinded` indices
+  pub fn verify(&self, public_key: &PublicKey<B>, blinded: &BTreeSet<usize>, nonce: &B::Scalar) -> bool {
+    let mut bases = vec![public_key.h0];
+    for index in blinded {
+      match public_key.h.get(*index) {
+        Some(generator) => bases.push(*generator),
+        None => return false,
+      }
+    }
+
+    let proof = &self.proof_of_hidden_messages;
+    let contribution = match proof.challenge_contribution(&bases, &self.commitment, &self.challenge_hash) {
+      Some(contribution) => contribution,
+      None => return false,
+    };
+    let challenge = blind_signature_challenge::<B>(&bases, &contribution, &self.commitment, nonce);
+    challenge == self.challenge_hash && contribution.add(&proof.commitment.neg()).is_identity()
+  }
+}
+
+/// Proof of knowledge of a signature, revealing some of its messages
+pub struct SignatureProof<B: CurveBackend> {
+  a_prime: B::G1,
+  a_bar: B::G1,
+  d: B::G1,
+  /// For proving `a_bar / d == a_prime^-e * h0^r2`
+  proof_1: ProofG1<B>,
+  /// For proving `g1 * h_i^m_i... == d^r3 * h0^-s' * h_j^-m_j...` for revealed `i` and hidden `j`
+  proof_2: ProofG1<B>,
+}
+
+/// Bytes hashed for the challenge of a proof of knowledge of a signature, before the nonce
+fn signature_proof_challenge_bytes<B: CurveBackend>(a_bar: &B::G1, committed_1: &[u8], committed_2: &[u8]) -> Vec<u8> {
+  let mut bytes = a_bar.to_uncompressed();
+  bytes.extend_from_slice(committed_1);
+  bytes.extend_from_slice(committed_2);
+  bytes
+}
+
+fn signature_proof_challenge<S: Scalar>(mut bytes: Vec<u8>, nonce: &S) -> S {
+  bytes.extend_from_slice(&nonce.to_bytes());
+  hash_to_scalar(&bytes)
+}
+
+/// Prove knowledge of a signature over `messages`, revealing those at the `revealed` indices
+pub fn create_proof<B: CurveBackend, R: RngCore + CryptoRng>(
+  signature: &Signature<B>,
+  public_key: &PublicKey<B>,
+  messages: &[B::Scalar],
+  revealed: &BTreeSet<usize>,
+  nonce: &B::Scalar,
+  rng: &mut R,
+) -> Result<SignatureProof<B>, BBSError> {
+  if !signature.verify(public_key, messages)? {
+    handle_err!("The messages and signature do not match");
+  }
+
+  let r1: B::Scalar = random_scalar(rng);
+  let r2: B::Scalar = random_scalar(rng);
+  let b = public_key.signature_base(&signature.s, messages.iter().enumerate())?;
+
+  // a' = a^r1, a_bar = b^r1 / a'^e, d = b^r1 * h0^-r2
+  let a_prime = signature.a.mul(&r1);
+  let b_r1 = b.mul(&r1);
+  let a_bar = b_r1.add(&a_prime.mul(&signature.e).neg());
+  let d = b_r1.add(&public_key.h0.mul(&negate(&r2)));
+
+  // r3 = 1 / r1, s' = s - r2 * r3
+  let r3 = match r1.inverse() {
+    Some(r3) => r3,
+    None => { handle_err!("Failed generating proof blinding"); }
+  };
+  let s_prime = signature.s.sub(&r2.mul(&r3));
+
+  let committed_1 = ProverCommitted::<B>::new(vec![a_prime, public_key.h0], vec![random_scalar(rng), random_scalar(rng)]);
+  let secrets_1 = [negate(&signature.e), r2];
+
+  let mut bases_2 = vec![d, public_key.h0];
+  let mut blinding_factors_2 = vec![random_scalar(rng), random_scalar(rng)];
+  let mut secrets_2 = vec![negate(&r3), s_prime];
+  for (index, (generator, message)) in public_key.h.iter().zip(messages).enumerate() {
+    if !revealed.contains(&index) {
+      bases_2.push(*generator);
+      blinding_factors_2.push(random_scalar(rng));
+      secrets_2.push(*message);
+    }
+  }
+  let committed_2 = ProverCommitted::<B>::new(bases_2, blinding_factors_2);
+
+  let challenge_bytes = signature_proof_challenge_bytes::<B>(&a_bar, &committed_1.to_bytes(), &committed_2.to_bytes());
+  let challenge = signature_proof_challenge(challenge_bytes, nonce);
+  let proof = SignatureProof {
+    a_prime,
+    a_bar,
+    d,
+    proof_1: committed_1.gen_proof(&challenge, &secrets_1),
+    proof_2: committed_2.gen_proof(&challenge, &secrets_2),
+  };
+  secrets_2.iter_mut().for_each(Zeroize::zeroize);
+  Ok(proof)
+}
+
+impl<B: CurveBackend> SignatureProof<B> {
+  /// Proof from `A' || A-bar || d || proof 1 length || proof 1 || proof 2`
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, BBSError> {
+    if bytes.len() < PROOF_POINTS_LENGTH {
+      handle_err!("Invalid proof: truncated proof of knowledge");
+    }
+    let proof_1_length = u32::from_be_bytes(bytes[3 * G1_COMPRESSED_SIZE..PROOF_POINTS_LENGTH].try_into().unwrap()) as usize;
+    if bytes.len() - PROOF_POINTS_LENGTH < proof_1_length {
+      handle_err!("Invalid proof: truncated proof of knowledge");
+    }
+
+    let points: Option<Vec<B::G1>> = bytes[..3 * G1_COMPRESSED_SIZE].chunks(G1_COMPRESSED_SIZE).map(read_point).collect();
+    let (a_prime, a_bar, d) = match points.as_deref() {
+      Some([a_prime, a_bar, d]) => (*a_prime, *a_bar, *d),
+      _ => { handle_err!("Invalid proof: invalid point"); }
+    };
+    let proof_2_offset = PROOF_POINTS_LENGTH + proof_1_length;
+    Ok(SignatureProof {
+      a_prime,
+      a_bar,
+      d,
+      proof_1: ProofG1::from_bytes(&bytes[PROOF_POINTS_LENGTH..proof_2_offset])?,
+      proof_2: ProofG1::from_bytes(&bytes[proof_2_offset..])?,
+    })
+  }
+
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut proof_1 = self.proof_1.to_bytes();
+    let mut bytes = self.a_prime.to_compressed();
+    bytes.append(&mut self.a_bar.to_compressed());
+    bytes.append(&mut self.d.to_compressed());
+    bytes.extend_from_slice(&(proof_1.len() as u32).to_be_bytes());
+    bytes.append(&mut proof_1);
+    bytes.append(&mut self.proof_2.to_bytes());
+    bytes
+  }
+
+  /// Verify the proof for the revealed messages at their indices
+  pub fn verify(
+    &self,
+    public_key: &PublicKey<B>,
+    revealed: &BTreeMap<usize, B::Scalar>,
+    nonce: &B::Scalar,
+  ) -> Result<PoKOfSignatureProofStatus, BBSError> {
+    public_key.validate()?;
+    if let Some(index) = revealed.keys().find(|index| **index >= public_key.message_count()) {
+      handle_err!(format!("Index {} should be less than {}", index, public_key.message_count()));
+    }
+
+    let hidden: Vec<B::G1> = public_key.h.iter()
+      .enumerate()
+      .filter(|(index, _)| !revealed.contains_key(index))
+      .map(|(_, generator)| *generator)
+      .collect();
+    let mut committed_1 = Vec::new();
+    for point in [&self.a_prime, &public_key.h0, &self.proof_1.commitment] {
+      committed_1.append(&mut point.to_uncompressed());
+    }
+    let mut committed_2 = Vec::new();
+    for point in [&self.d, &public_key.h0].iter().copied().chain(&hidden).chain(Some(&self.proof_2.commitment)) {
+      committed_2.append(&mut point.to_uncompressed());
+    }
+    let challenge_bytes = signature_proof_challenge_bytes::<B>(&self.a_bar, &committed_1, &committed_2);
+    let challenge = signature_proof_challenge(challenge_bytes, nonce);
+
+    // e(a', w) * e(a_bar^-1, g2) == 1
+    if self.a_prime.is_identity()
+      || !B::pairings_are_identity(&self.a_prime, &public_key.w, &self.a_bar.neg(), &B::G2::generator())
+    {
+      return Ok(PoKOfSignatureProofStatus::BadSignature);
+    }
+
+    let a_bar_d = self.a_bar.add(&self.d.neg());
+    match self.proof_1.verify(&[self.a_prime, public_key.h0], &a_bar_d, &challenge) {
+      Some(true) => (),
+      Some(false) => return Ok(PoKOfSignatureProofStatus::BadHiddenMessage),
+      None => { handle_err!("Invalid proof: proof of knowledge of the signature doesn't have 2 responses"); }
+    }
+
+    // (g1 * h_i^m_i...)^-1 for the revealed messages
+    let revealed_base = public_key.signature_base(&zero(), revealed.iter().map(|(index, message)| (*index, message)))?.neg();
+    let mut bases_2 = vec![self.d, public_key.h0];
+    bases_2.extend(hidden);
+    match self.proof_2.verify(&bases_2, &revealed_base, &challenge) {
+      Some(true) => Ok(PoKOfSignatureProofStatus::Success),
+      _ => Ok(PoKOfSignatureProofStatus::BadRevealedMessage),
+    }
+  }
+}
@@ -0,0 +1,159 @@
+use std::convert::TryFrom;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use ciborium::value::{Integer, Value as Cbor};
+use serde_json::{Map, Number, Value};
+
+/// Result properties holding base64 bytes, written as CBOR byte strings. They're converted
+/// in the result itself and in a nested presentation, but not in documents, headers or JWPs
+const BYTE_PROPERTIES: &[&str] = &[
+  "blind_signature",
+  "blinding_factor",
+  "challenge_hash",
+  "commitment",
+  "credential",
+  "messages",
+  "nonce",
+  "payloads",
+  "presentation",
+  "proof",
+  "proof_of_hidden_messages",
+  "public_key",
+  "schema_hash",
+  "secret_key",
+  "signature",
+];
+
+/// `encoding` a CBOR context sets, as its bytes are only read as CBOR when it says so
+pub const CBOR_ENCODING: &str = "cbor";
+
+/// Read a CBOR map context, which sets `encoding` to "cbor", as the JSON context operations
+/// expect, with byte strings as base64
+pub fn context_from_cbor(bytes: &[u8]) -> Result<Value, BBSError> {
+  let mut context_json = match ciborium::de::from_reader(bytes) {
+    Ok(context @ Cbor::Map(_)) => cbor_to_json(context)?,
+    _ => { handle_err!("Failed parsing CBOR for context"); }
+  };
+
+  match context_json.as_object_mut().and_then(|context| context.remove("encoding")) {
+    Some(Value::String(encoding)) if encoding == CBOR_ENCODING => Ok(context_json),
+    _ => { handle_err!("Invalid CBOR context, expected 'encoding' to be 'cbor'"); }
+  }
+}
+
+fn cbor_to_json(value: Cbor) -> Result<Value, BBSError> {
+  Ok(match value {
+    Cbor::Null => Value::Null,
+    Cbor::Bool(value) => Value::Bool(value),
+    Cbor::Text(text) => Value::String(text),
+    Cbor::Bytes(bytes) => Value::String(base64::encode(bytes)),
+    Cbor::Integer(integer) => match (u64::try_from(integer), i64::try_from(integer)) {
+      (Ok(integer), _) => Value::from(integer),
+      (_, Ok(integer)) => Value::from(integer),
+      _ => { handle_err!("CBOR integer out of range"); }
+    },
+    Cbor::Float(float) => match Number::from_f64(float) {
+      Some(number) => Value::Number(number),
+      None => { handle_err!("CBOR float isn't a finite number"); }
+    },
+    Cbor::Array(items) => Value::Array(items.into_iter().map(cbor_to_json).collect::<Result<Vec<Value>, BBSError>>()?),
+    Cbor::Map(entries) => {
+      let mut object = Map::new();
+      for (key, value) in entries {
+        let key = match key {
+          Cbor::Text(key) => key,
+          Cbor::Integer(key) => i128::from(key).to_string(),
+          _ => { handle_err!("CBOR map keys must be text or integers"); }
+        };
+        object.insert(key, cbor_to_json(value)?);
+      }
+      Value::Object(object)
+    },
+    Cbor::Tag(_, value) => cbor_to_json(*value)?,
+    _ => { handle_err!("Unsupported CBOR value in context"); }
+  })
+}
+
+/// Write an operation's JSON result as CBOR, with its base64 properties as byte strings
+pub fn result_to_cbor(result: &str) -> Result<Vec<u8>, BBSError> {
+  let result: Value = match serde_json::from_str(result) {
+    Ok(result) => result,
+    Err(_) => { handle_err!("Failed parsing JSON result"); }
+  };
+
+  let mut bytes = Vec::new();
+  match ciborium::ser::into_writer(&json_to_cbor(&result, true), &mut bytes) {
+    Ok(_) => Ok(bytes),
+    Err(_) => { handle_err!("Failed to encode CBOR result"); }
+  }
+}
+
+fn json_to_cbor(value: &Value, convert_bytes: bool) -> Cbor {
+  match value {
+    Value::Null => Cbor::Null,
+    Value::Bool(value) => Cbor::Bool(*value),
+    Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+      (Some(integer), _) => Cbor::Integer(integer.into()),
+      (_, Some(integer)) => Cbor::Integer(integer.into()),
+      _ => Cbor::Float(number.as_f64().unwrap_or_default()),
+    },
+    Value::String(text) => Cbor::Text(text.clone()),
+    Value::Array(items) => Cbor::Array(items.iter().map(|item| json_to_cbor(item, false)).collect()),
+    Value::Object(object) => Cbor::Map(object.iter()
+      .map(|(key, value)| {
+        let value = match (convert_bytes, key.as_str()) {
+          (true, "revealed_messages") => revealed_messages_to_cbor(value),
+          (true, key) if BYTE_PROPERTIES.contains(&key) => bytes_to_cbor(value),
+          _ => json_to_cbor(value, false),
+        };
+        (Cbor::Text(key.clone()), value)
+      })
+      .collect()),
+  }
+}
+
+/// Base64 strings (or arrays of them) as byte strings, and a nested presentation's properties
+fn bytes_to_cbor(value: &Value) -> Cbor {
+  match value {
+    Value::String(text) => match base64::decode(text) {
+      Ok(bytes) => Cbor::Bytes(bytes),
+      Err(_) => Cbor::Text(text.clone()),
+    },
+    Value::Array(items) => Cbor::Array(items.iter().map(bytes_to_cbor).collect()),
+    Value::Object(_) => json_to_cbor(value, true),
+    value => json_to_cbor(value, false),
+  }
+}
+
+/// Revealed messages keyed by their integer index
+fn revealed_messages_to_cbor(value: &Value) -> Cbor {
+  match value {
+    Value::Object(messages) => Cbor::Map(messages.iter()
+      .map(|(index, message)| {
+        let key = match index.parse::<u64>() {
+          Ok(index) => Cbor::Integer(Integer::from(index)),
+          Err(_) => Cbor::Text(index.clone()),
+        };
+        (key, bytes_to_cbor(message))
+      })
+      .collect()),
+    value => json_to_cbor(value, false),
+  }
+}
+
+/// CBOR error result, shaped like the JSON errors the wrappers return
+pub fn error_to_cbor(message: &str, code: Option<&str>) -> Vec<u8> {
+  let mut members = vec![
+    (Cbor::Text("name".to_string()), Cbor::Text("RustError".to_string())),
+    (Cbor::Text("message".to_string()), Cbor::Text(message.to_string())),
+  ];
+  // e.g. a limit was exceeded
+  if let Some(code) = code {
+    members.push((Cbor::Text("code".to_string()), Cbor::Text(code.to_string())));
+  }
+  let error = Cbor::Map(vec![(Cbor::Text("error".to_string()), Cbor::Map(members))]);
+
+  let mut bytes = Vec::new();
+  // writing a map of text to a Vec can't fail
+  ciborium::ser::into_writer(&error, &mut bytes).unwrap();
+  bytes
+}
@@ -0,0 +1,306 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use ciborium::value::{Integer, Value as Cbor};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use super::document::MessageFormat;
+use super::ietf_bbs;
+use super::json_schema::JsonSchema;
+use super::limits::check_messages;
+use super::presentation::{Presentation, PRESENTATION_VERSION};
+use super::proof_envelope::Ciphersuite;
+
+// COSE_Sign1 (RFC 9052) style envelopes: the BBS signature or proof is the envelope's signature,
+// over the messages it signs or reveals as the payload. BBS has no registered COSE algorithm,
+// so `alg` holds the ciphersuite name.
+//
+// Unlike a COSE signature, the BBS signature doesn't cover the envelope's headers, so `kid` is
+// carried in the unprotected header: it only hints at the key to verify with, and isn't
+// authenticated. The protected header holds what verification does check: `alg`, which picks
+// how the signature is verified, an IETF BBS signature's header, which it signs, and a
+// presentation's nonce, which its proof is bound to
+
+const COSE_SIGN1_TAG: u64 = 18;
+const HEADER_ALG: i64 = 1;
+const HEADER_KID: i64 = 4;
+const HEADER_BBS_HEADER: &str = "bbs_header";
+const HEADER_NONCE: &str = "nonce";
+const HEADER_MESSAGE_FORMAT: &str = "message_format";
+
+/// COSE header map entries, in their encoded order
+type Header = Vec<(Cbor, Cbor)>;
+
+/// BBS variant of a credential's signature
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Algorithm {
+  /// BBS+ signatures of the `bbs` crate
+  BbsPlus,
+  /// IETF BBS signatures, of the BLS12-381 SHA-256 ciphersuite
+  BbsIetf,
+}
+
+impl Algorithm {
+  /// Name the envelope's `alg` holds
+  pub fn name(self) -> &'static str {
+    match self {
+      Algorithm::BbsPlus => Ciphersuite::Bls12381G2Blake2b.name(),
+      // the ciphersuite ID is ASCII
+      Algorithm::BbsIetf => std::str::from_utf8(ietf_bbs::CIPHERSUITE_ID).unwrap(),
+    }
+  }
+
+  fn from_name(name: &str) -> Option<Algorithm> {
+    [Algorithm::BbsPlus, Algorithm::BbsIetf].iter().copied().find(|algorithm| algorithm.name() == name)
+  }
+}
+
+impl JsonSchema for Algorithm {
+  fn json_schema() -> Value {
+    json!({ "enum": ["bbs_plus", "bbs_ietf"] })
+  }
+}
+
+/// Signed messages with their BBS signature
+pub struct Credential {
+  pub algorithm: Algorithm,
+  pub signature: Vec<u8>,
+  /// IETF BBS signature header, which the signature covers
+  pub header: Option<Vec<u8>>,
+  pub messages: Vec<Vec<u8>>,
+  pub key_id: Option<String>,
+}
+
+/// Whether bytes are a tagged COSE_Sign1 envelope
+pub fn is_cose_sign1(bytes: &[u8]) -> bool {
+  // major type 6 (tag) with the tag number inline
+  bytes.first() == Some(&(0xc0 | COSE_SIGN1_TAG as u8))
+}
+
+/// COSE_Sign1 envelope of a credential, its payload the CBOR array of messages
+pub fn encode_credential(credential: &Credential) -> Result<Vec<u8>, BBSError> {
+  let mut protected = vec![(Cbor::Integer(HEADER_ALG.into()), Cbor::Text(credential.algorithm.name().to_string()))];
+  match (&credential.header, credential.algorithm) {
+    (Some(header), Algorithm::BbsIetf) => protected.push((Cbor::Text(HEADER_BBS_HEADER.to_string()), Cbor::Bytes(header.clone()))),
+    (Some(_), Algorithm::BbsPlus) => { handle_err!("Property 'header' is only for 'bbs_ietf' credentials"); },
+    (None, _) => (),
+  }
+
+  let payload = Cbor::Array(credential.messages.iter().cloned().map(Cbor::Bytes).collect());
+  encode_sign1(protected, key_id_header(&credential.key_id), &payload, &credential.signature)
+}
+
+pub fn decode_credential(bytes: &[u8]) -> Result<Credential, BBSError> {
+  let (protected, unprotected, payload, signature) = decode_sign1(bytes, "credential")?;
+  let algorithm = header_algorithm(&protected, "credential")?;
+
+  let messages = match payload {
+    Cbor::Array(messages) => messages.into_iter()
+      .map(|message| match message {
+        Cbor::Bytes(message) => Ok(message),
+        _ => { handle_err!("Invalid COSE credential, expected message byte strings"); }
+      })
+      .collect::<Result<Vec<Vec<u8>>, BBSError>>()?,
+    _ => { handle_err!("Invalid COSE credential, expected an array of messages"); }
+  };
+
+  let header = match header_value(&protected, HEADER_BBS_HEADER) {
+    Some(Cbor::Bytes(header)) if algorithm == Algorithm::BbsIetf => Some(header.clone()),
+    Some(_) => { handle_err!("Invalid COSE credential header, expected a byte string of an IETF BBS credential"); },
+    None => None,
+  };
+
+  Ok(Credential { algorithm, signature, header, messages, key_id: header_key_id(&unprotected)? })
+}
+
+/// COSE_Sign1 envelope of a presentation, its payload the map of revealed messages by index
+pub fn encode_presentation(presentation: &Presentation) -> Result<Vec<u8>, BBSError> {
+  // presentations are of BBS+ proofs
+  let mut protected = vec![(Cbor::Integer(HEADER_ALG.into()), Cbor::Text(Algorithm::BbsPlus.name().to_string()))];
+  if let Some(nonce) = &presentation.nonce {
+    protected.push((Cbor::Text(HEADER_NONCE.to_string()), Cbor::Bytes(decode_base64(nonce, "nonce")?)));
+  }
+  if let Some(MessageFormat::JsonPointer) = presentation.message_format {
+    protected.push((Cbor::Text(HEADER_MESSAGE_FORMAT.to_string()), Cbor::Text("json_pointer".to_string())));
+  }
+
+  let mut revealed_messages = Vec::with_capacity(presentation.revealed_messages.len());
+  for (index, message) in &presentation.revealed_messages {
+    revealed_messages.push((Cbor::Integer(Integer::from(*index as u64)), Cbor::Bytes(decode_base64(message, "revealed message")?)));
+  }
+
+  encode_sign1(protected, key_id_header(&presentation.key_id), &Cbor::Map(revealed_messages), &decode_base64(&presentation.proof, "proof")?)
+}
+
+pub fn decode_presentation(bytes: &[u8]) -> Result<Presentation, BBSError> {
+  let (protected, unprotected, payload, proof) = decode_sign1(bytes, "presentation")?;
+  if header_algorithm(&protected, "presentation")? != Algorithm::BbsPlus {
+    handle_err!("Unsupported COSE presentation algorithm");
+  }
+
+  let mut revealed_messages = BTreeMap::new();
+  match payload {
+    Cbor::Map(entries) => for (index, message) in entries {
+      let index = index.as_integer().and_then(|index| usize::try_from(index).ok());
+      match (index, message) {
+        (Some(index), Cbor::Bytes(message)) => { revealed_messages.insert(index, base64::encode(message)); },
+        _ => { handle_err!("Invalid COSE presentation, expected message byte strings by index"); }
+      }
+    },
+    _ => { handle_err!("Invalid COSE presentation, expected a map of revealed messages"); }
+  }
+
+  let nonce = match header_value(&protected, HEADER_NONCE) {
+    Some(Cbor::Bytes(nonce)) => Some(base64::encode(nonce)),
+    Some(_) => { handle_err!("Invalid COSE presentation nonce, expected a byte string"); },
+    None => None,
+  };
+  let message_format = match header_value(&protected, HEADER_MESSAGE_FORMAT) {
+    Some(Cbor::Text(format)) if format == "json_pointer" => Some(MessageFormat::JsonPointer),
+    Some(_) => { handle_err!("Unsupported COSE presentation message format"); },
+    None => None,
+  };
+
+  Ok(Presentation {
+    version: PRESENTATION_VERSION,
+    proof: base64::encode(proof),
+    revealed_messages,
+    nonce,
+    key_id: header_key_id(&unprotected)?,
+    message_format,
+  })
+}
+
+fn encode_sign1(protected: Header, unprotected: Header, payload: &Cbor, signature: &[u8]) -> Result<Vec<u8>, BBSError> {
+  let sign1 = Cbor::Tag(COSE_SIGN1_TAG, Box::new(Cbor::Array(vec![
+    Cbor::Bytes(to_cbor_bytes(&Cbor::Map(protected))?),
+    Cbor::Map(unprotected),
+    Cbor::Bytes(to_cbor_bytes(payload)?),
+    Cbor::Bytes(signature.to_vec()),
+  ])));
+  to_cbor_bytes(&sign1)
+}
+
+/// Protected and unprotected headers, payload and signature of a COSE_Sign1 envelope
+fn decode_sign1(bytes: &[u8], kind: &str) -> Result<(Header, Header, Cbor, Vec<u8>), BBSError> {
+  let parts = match ciborium::de::from_reader(bytes) {
+    Ok(Cbor::Tag(COSE_SIGN1_TAG, sign1)) => match *sign1 {
+      Cbor::Array(parts) if parts.len() == 4 => parts,
+      _ => { handle_err!(format!("Invalid COSE {}, expected 4 COSE_Sign1 parts", kind)); }
+    },
+    _ => { handle_err!(format!("Invalid COSE {}, expected a tagged COSE_Sign1", kind)); }
+  };
+
+  let mut parts = parts.into_iter();
+  let (protected, unprotected, payload, signature) = (parts.next(), parts.next(), parts.next(), parts.next());
+  let protected = match protected.and_then(|protected| protected.into_bytes().ok()).map(|bytes| from_cbor_bytes(&bytes)) {
+    Some(Ok(Cbor::Map(protected))) => protected,
+    _ => { handle_err!(format!("Invalid COSE {} protected header", kind)); }
+  };
+  let unprotected = match unprotected {
+    Some(Cbor::Map(unprotected)) => unprotected,
+    _ => { handle_err!(format!("Invalid COSE {} unprotected header", kind)); }
+  };
+
+  let payload = match payload.and_then(|payload| payload.into_bytes().ok()).map(|bytes| from_cbor_bytes(&bytes)) {
+    Some(Ok(payload)) => payload,
+    _ => { handle_err!(format!("Invalid COSE {} payload", kind)); }
+  };
+  match signature.and_then(|signature| signature.into_bytes().ok()) {
+    Some(signature) => Ok((protected, unprotected, payload, signature)),
+    None => { handle_err!(format!("Invalid COSE {} signature", kind)); }
+  }
+}
+
+fn header_value<K: Into<HeaderLabel>>(header: &[(Cbor, Cbor)], label: K) -> Option<&Cbor> {
+  let label = label.into();
+  header.iter()
+    .find(|(key, _)| match (&label, key) {
+      (HeaderLabel::Int(label), Cbor::Integer(key)) => i128::from(*key) == *label as i128,
+      (HeaderLabel::Text(label), Cbor::Text(key)) => key == label,
+      _ => false,
+    })
+    .map(|(_, value)| value)
+}
+
+enum HeaderLabel {
+  Int(i64),
+  Text(&'static str),
+}
+
+impl From<i64> for HeaderLabel {
+  fn from(label: i64) -> HeaderLabel {
+    HeaderLabel::Int(label)
+  }
+}
+
+impl From<&'static str> for HeaderLabel {
+  fn from(label: &'static str) -> HeaderLabel {
+    HeaderLabel::Text(label)
+  }
+}
+
+fn header_algorithm(protected: &[(Cbor, Cbor)], kind: &str) -> Result<Algorithm, BBSError> {
+  match header_value(protected, HEADER_ALG) {
+    Some(Cbor::Text(alg)) => match Algorithm::from_name(alg) {
+      Some(algorithm) => Ok(algorithm),
+      None => { handle_err!(format!("Unsupported COSE {} algorithm", kind)); }
+    },
+    _ => { handle_err!(format!("Unsupported COSE {} algorithm", kind)); }
+  }
+}
+
+/// Unprotected header of a key ID, which the BBS signature doesn't authenticate
+fn key_id_header(key_id: &Option<String>) -> Header {
+  key_id.iter()
+    .map(|key_id| (Cbor::Integer(HEADER_KID.into()), Cbor::Bytes(key_id.as_bytes().to_vec())))
+    .collect()
+}
+
+fn header_key_id(header: &[(Cbor, Cbor)]) -> Result<Option<String>, BBSError> {
+  match header_value(header, HEADER_KID) {
+    Some(Cbor::Bytes(key_id)) => match String::from_utf8(key_id.clone()) {
+      Ok(key_id) => Ok(Some(key_id)),
+      Err(_) => { handle_err!("Invalid COSE key ID, expected UTF-8"); }
+    },
+    Some(_) => { handle_err!("Invalid COSE key ID, expected a byte string"); },
+    None => Ok(None),
+  }
+}
+
+fn to_cbor_bytes(value: &Cbor) -> Result<Vec<u8>, BBSError> {
+  let mut bytes = Vec::new();
+  match ciborium::ser::into_writer(value, &mut bytes) {
+    Ok(_) => Ok(bytes),
+    Err(_) => { handle_err!("Failed to encode COSE envelope"); }
+  }
+}
+
+fn from_cbor_bytes(bytes: &[u8]) -> Result<Cbor, BBSError> {
+  match ciborium::de::from_reader(bytes) {
+    Ok(value) => Ok(value),
+    Err(_) => { handle_err!("Failed to decode COSE envelope"); }
+  }
+}
+
+fn decode_base64(value: &str, name: &str) -> Result<Vec<u8>, BBSError> {
+  match base64::decode(value) {
+    Ok(bytes) => Ok(bytes),
+    Err(_) => { handle_err!(format!("Failed decoding base64 for presentation {}", name)); }
+  }
+}
+
+/// Decode a base64 COSE 'credential', checking it holds a signature of the `algorithm` an
+/// operation verifies
+pub fn decode_context_credential(credential: &str, algorithm: Algorithm) -> Result<Credential, BBSError> {
+  let credential = match base64::decode(credential) {
+    Ok(credential) => decode_credential(&credential)?,
+    Err(_) => { handle_err!("Failed decoding base64 for: 'credential'"); }
+  };
+  if credential.algorithm != algorithm {
+    handle_err!(format!("Invalid COSE credential algorithm, expected '{}'", algorithm.name()));
+  }
+  check_messages("'credential' message", credential.messages.iter().map(Vec::len))?;
+  Ok(credential)
+}
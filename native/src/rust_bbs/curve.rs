@@ -0,0 +1,193 @@
+use ff_zeroize::{Field, PrimeField, PrimeFieldRepr};
+use pairing_plus::bls12_381::{Bls12, Fq12, Fr, FrRepr, G1, G2};
+use pairing_plus::hash_to_field::BaseFromRO;
+use pairing_plus::serdes::SerDes;
+use pairing_plus::{CurveProjective, Engine};
+use sha2::digest::generic_array::{typenum::U48, GenericArray};
+use zeroize::Zeroize;
+use super::hash_to_curve::hash_to_g1;
+
+// BLS12-381 arithmetic behind key generation, the BBS+ operations and the IETF BBS operations
+// (and so bbs-2023 and JSON Web Proofs), with pairing-plus by default and blst under the `blst`
+// feature. Both encode scalars and points the same way, so keys, signatures and proofs don't
+// depend on the backend. The generators of BBS+ public keys are still hashed by the `bbs` crate,
+// as its hash to curve predates RFC 9380
+
+pub const SCALAR_LENGTH: usize = 32;
+/// Length of the uniform bytes a scalar is reduced from
+pub const OKM_LENGTH: usize = 48;
+
+/// Scalar of the BLS12-381 groups' order `r`
+pub trait Scalar: Copy + PartialEq + Zeroize + Sized {
+  /// Big-endian `okm` reduced modulo `r`, as `OS2IP(okm) mod r`
+  fn from_okm(okm: &[u8; OKM_LENGTH]) -> Self;
+  /// Scalar from its big-endian bytes, `None` where not below `r`
+  fn from_bytes(bytes: &[u8; SCALAR_LENGTH]) -> Option<Self>;
+  fn to_bytes(&self) -> [u8; SCALAR_LENGTH];
+  fn add(&self, other: &Self) -> Self;
+  fn sub(&self, other: &Self) -> Self;
+  fn mul(&self, other: &Self) -> Self;
+  /// Multiplicative inverse, `None` for zero
+  fn inverse(&self) -> Option<Self>;
+}
+
+/// Point of G1 or G2
+pub trait Group: Copy + Sized {
+  type Scalar: Scalar;
+  const COMPRESSED_LENGTH: usize;
+
+  fn generator() -> Self;
+  fn identity() -> Self;
+  fn is_identity(&self) -> bool;
+  fn add(&self, other: &Self) -> Self;
+  fn neg(&self) -> Self;
+  fn mul(&self, scalar: &Self::Scalar) -> Self;
+  fn to_compressed(&self) -> Vec<u8>;
+  fn to_uncompressed(&self) -> Vec<u8>;
+  /// Point of the group from its compressed form, `None` where off the curve or outside the group
+  fn from_compressed(bytes: &[u8]) -> Option<Self>;
+}
+
+pub trait CurveBackend {
+  type Scalar: Scalar;
+  type G1: Group<Scalar = Self::Scalar>;
+  type G2: Group<Scalar = Self::Scalar>;
+  const NAME: &'static str;
+
+  /// `hash_to_curve` of the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite
+  fn hash_to_g1(msg: &[u8], dst: &[u8]) -> Self::G1;
+  /// `e(p1, q1) * e(p2, q2) == 1`
+  fn pairings_are_identity(p1: &Self::G1, q1: &Self::G2, p2: &Self::G1, q2: &Self::G2) -> bool;
+}
+
+#[cfg(feature = "blst")]
+pub use super::curve_blst::{Blst, BlstG1, BlstG2, BlstScalar};
+
+/// Backend of key generation, the BBS+ operations and the IETF BBS operations
+#[cfg(feature = "blst")]
+pub type DefaultBackend = Blst;
+#[cfg(not(feature = "blst"))]
+pub type DefaultBackend = PairingPlus;
+
+pub type DefaultScalar = <DefaultBackend as CurveBackend>::Scalar;
+pub type DefaultG1 = <DefaultBackend as CurveBackend>::G1;
+pub type DefaultG2 = <DefaultBackend as CurveBackend>::G2;
+
+/// The pairing-plus backend
+pub struct PairingPlus;
+
+impl CurveBackend for PairingPlus {
+  type Scalar = Fr;
+  type G1 = G1;
+  type G2 = G2;
+  const NAME: &'static str = "pairing-plus";
+
+  fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1 {
+    hash_to_g1(msg, dst)
+  }
+
+  fn pairings_are_identity(p1: &G1, q1: &G2, p2: &G1, q2: &G2) -> bool {
+    Bls12::pairing_product(p1.into_affine(), q1.into_affine(), p2.into_affine(), q2.into_affine()) == Fq12::one()
+  }
+}
+
+impl Scalar for Fr {
+  fn from_okm(okm: &[u8; OKM_LENGTH]) -> Fr {
+    BaseFromRO::from_okm(GenericArray::<u8, U48>::from_slice(okm))
+  }
+
+  fn from_bytes(bytes: &[u8; SCALAR_LENGTH]) -> Option<Fr> {
+    let mut repr = FrRepr::default();
+    repr.read_be(&bytes[..]).ok()?;
+    Fr::from_repr(repr).ok()
+  }
+
+  fn to_bytes(&self) -> [u8; SCALAR_LENGTH] {
+    let mut bytes = [0u8; SCALAR_LENGTH];
+    self.into_repr().write_be(&mut bytes[..]).unwrap();
+    bytes
+  }
+
+  fn add(&self, other: &Fr) -> Fr {
+    let mut sum = *self;
+    Field::add_assign(&mut sum, other);
+    sum
+  }
+
+  fn sub(&self, other: &Fr) -> Fr {
+    let mut difference = *self;
+    Field::sub_assign(&mut difference, other);
+    difference
+  }
+
+  fn mul(&self, other: &Fr) -> Fr {
+    let mut product = *self;
+    Field::mul_assign(&mut product, other);
+    product
+  }
+
+  fn inverse(&self) -> Option<Fr> {
+    Field::inverse(self)
+  }
+}
+
+macro_rules! pairing_plus_group {
+  ($group:ty, $length:expr) => {
+    impl Group for $group {
+      type Scalar = Fr;
+      const COMPRESSED_LENGTH: usize = $length;
+
+      fn generator() -> Self {
+        CurveProjective::one()
+      }
+
+      fn identity() -> Self {
+        CurveProjective::zero()
+      }
+
+      fn is_identity(&self) -> bool {
+        CurveProjective::is_zero(self)
+      }
+
+      fn add(&self, other: &Self) -> Self {
+        let mut sum = *self;
+        CurveProjective::add_assign(&mut sum, other);
+        sum
+      }
+
+      fn neg(&self) -> Self {
+        let mut negation = *self;
+        CurveProjective::negate(&mut negation);
+        negation
+      }
+
+      fn mul(&self, scalar: &Fr) -> Self {
+        let mut product = *self;
+        CurveProjective::mul_assign(&mut product, *scalar);
+        product
+      }
+
+      fn to_compressed(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity($length);
+        self.serialize(&mut bytes, true).unwrap();
+        bytes
+      }
+
+      fn to_uncompressed(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * $length);
+        self.serialize(&mut bytes, false).unwrap();
+        bytes
+      }
+
+      fn from_compressed(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != $length {
+          return None;
+        }
+        <$group as SerDes>::deserialize(&mut &bytes[..], true).ok()
+      }
+    }
+  };
+}
+
+pairing_plus_group!(G1, 48);
+pairing_plus_group!(G2, 96);
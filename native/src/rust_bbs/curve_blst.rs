@@ -0,0 +1,221 @@
+use blst::*;
+use zeroize::Zeroize;
+use super::curve::{CurveBackend, Group, Scalar, OKM_LENGTH, SCALAR_LENGTH};
+
+// The blst backend of `curve`, enabled by the `blst` feature
+
+/// Bits of a scalar, which are below `r < 2^255`
+const SCALAR_BITS: usize = 255;
+
+pub struct Blst;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlstScalar(blst_fr);
+
+#[derive(Clone, Copy, Debug)]
+pub struct BlstG1(blst_p1);
+
+#[derive(Clone, Copy, Debug)]
+pub struct BlstG2(blst_p2);
+
+impl CurveBackend for Blst {
+  type Scalar = BlstScalar;
+  type G1 = BlstG1;
+  type G2 = BlstG2;
+  const NAME: &'static str = "blst";
+
+  fn hash_to_g1(msg: &[u8], dst: &[u8]) -> BlstG1 {
+    let mut point = blst_p1::default();
+    unsafe { blst_hash_to_g1(&mut point, msg.as_ptr(), msg.len(), dst.as_ptr(), dst.len(), std::ptr::null(), 0) };
+    BlstG1(point)
+  }
+
+  fn pairings_are_identity(p1: &BlstG1, q1: &BlstG2, p2: &BlstG1, q2: &BlstG2) -> bool {
+    let mut product = unsafe { *blst_fp12_one() };
+    let mut result = blst_fp12::default();
+    unsafe {
+      // the Miller loop doesn't handle the identity, whose pairings are one
+      for (p, q) in [(p1, q1), (p2, q2)] {
+        if p.is_identity() || q.is_identity() {
+          continue;
+        }
+        let mut pairing = blst_fp12::default();
+        blst_miller_loop(&mut pairing, &q.affine(), &p.affine());
+        let partial = product;
+        blst_fp12_mul(&mut product, &partial, &pairing);
+      }
+      blst_final_exp(&mut result, &product);
+      blst_fp12_is_one(&result)
+    }
+  }
+}
+
+impl BlstScalar {
+  /// Canonical form of the scalar, whose bytes are little-endian
+  fn scalar(&self) -> blst_scalar {
+    let mut scalar = blst_scalar::default();
+    unsafe { blst_scalar_from_fr(&mut scalar, &self.0) };
+    scalar
+  }
+
+  fn from_scalar(scalar: &blst_scalar) -> BlstScalar {
+    let mut fr = blst_fr::default();
+    unsafe { blst_fr_from_scalar(&mut fr, scalar) };
+    BlstScalar(fr)
+  }
+}
+
+impl Zeroize for BlstScalar {
+  fn zeroize(&mut self) {
+    self.0.l.zeroize();
+  }
+}
+
+impl Scalar for BlstScalar {
+  fn from_okm(okm: &[u8; OKM_LENGTH]) -> BlstScalar {
+    let mut scalar = blst_scalar::default();
+    unsafe { blst_scalar_from_be_bytes(&mut scalar, okm.as_ptr(), okm.len()) };
+    BlstScalar::from_scalar(&scalar)
+  }
+
+  fn from_bytes(bytes: &[u8; SCALAR_LENGTH]) -> Option<BlstScalar> {
+    let mut scalar = blst_scalar::default();
+    unsafe {
+      blst_scalar_from_bendian(&mut scalar, bytes.as_ptr());
+      if !blst_scalar_fr_check(&scalar) {
+        return None;
+      }
+    }
+    Some(BlstScalar::from_scalar(&scalar))
+  }
+
+  fn to_bytes(&self) -> [u8; SCALAR_LENGTH] {
+    let mut bytes = [0u8; SCALAR_LENGTH];
+    unsafe { blst_bendian_from_scalar(bytes.as_mut_ptr(), &self.scalar()) };
+    bytes
+  }
+
+  fn add(&self, other: &BlstScalar) -> BlstScalar {
+    let mut sum = blst_fr::default();
+    unsafe { blst_fr_add(&mut sum, &self.0, &other.0) };
+    BlstScalar(sum)
+  }
+
+  fn sub(&self, other: &BlstScalar) -> BlstScalar {
+    let mut difference = blst_fr::default();
+    unsafe { blst_fr_sub(&mut difference, &self.0, &other.0) };
+    BlstScalar(difference)
+  }
+
+  fn mul(&self, other: &BlstScalar) -> BlstScalar {
+    let mut product = blst_fr::default();
+    unsafe { blst_fr_mul(&mut product, &self.0, &other.0) };
+    BlstScalar(product)
+  }
+
+  fn inverse(&self) -> Option<BlstScalar> {
+    if self.0 == blst_fr::default() {
+      return None;
+    }
+    let mut inverse = blst_fr::default();
+    unsafe { blst_fr_inverse(&mut inverse, &self.0) };
+    Some(BlstScalar(inverse))
+  }
+}
+
+impl BlstG1 {
+  fn affine(&self) -> blst_p1_affine {
+    let mut affine = blst_p1_affine::default();
+    unsafe { blst_p1_to_affine(&mut affine, &self.0) };
+    affine
+  }
+}
+
+impl BlstG2 {
+  fn affine(&self) -> blst_p2_affine {
+    let mut affine = blst_p2_affine::default();
+    unsafe { blst_p2_to_affine(&mut affine, &self.0) };
+    affine
+  }
+}
+
+macro_rules! blst_group {
+  (
+    $group:ident, $point:ty, $affine:ty, $length:expr,
+    $generator:ident, $is_inf:ident, $add:ident, $cneg:ident, $mult:ident,
+    $compress:ident, $serialize:ident, $uncompress:ident, $in_group:ident, $from_affine:ident
+  ) => {
+    impl Group for $group {
+      type Scalar = BlstScalar;
+      const COMPRESSED_LENGTH: usize = $length;
+
+      fn generator() -> $group {
+        $group(unsafe { *$generator() })
+      }
+
+      fn identity() -> $group {
+        $group(<$point>::default())
+      }
+
+      fn is_identity(&self) -> bool {
+        unsafe { $is_inf(&self.0) }
+      }
+
+      fn add(&self, other: &$group) -> $group {
+        let mut sum = <$point>::default();
+        unsafe { $add(&mut sum, &self.0, &other.0) };
+        $group(sum)
+      }
+
+      fn neg(&self) -> $group {
+        let mut negation = self.0;
+        unsafe { $cneg(&mut negation, true) };
+        $group(negation)
+      }
+
+      fn mul(&self, scalar: &BlstScalar) -> $group {
+        let mut product = <$point>::default();
+        unsafe { $mult(&mut product, &self.0, scalar.scalar().b.as_ptr(), SCALAR_BITS) };
+        $group(product)
+      }
+
+      fn to_compressed(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; $length];
+        unsafe { $compress(bytes.as_mut_ptr(), &self.0) };
+        bytes
+      }
+
+      fn to_uncompressed(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; 2 * $length];
+        unsafe { $serialize(bytes.as_mut_ptr(), &self.0) };
+        bytes
+      }
+
+      fn from_compressed(bytes: &[u8]) -> Option<$group> {
+        if bytes.len() != $length {
+          return None;
+        }
+        let mut affine = <$affine>::default();
+        let mut point = <$point>::default();
+        unsafe {
+          if $uncompress(&mut affine, bytes.as_ptr()) != BLST_ERROR::BLST_SUCCESS || !$in_group(&affine) {
+            return None;
+          }
+          $from_affine(&mut point, &affine);
+        }
+        Some($group(point))
+      }
+    }
+  };
+}
+
+blst_group!(
+  BlstG1, blst_p1, blst_p1_affine, 48,
+  blst_p1_generator, blst_p1_is_inf, blst_p1_add_or_double, blst_p1_cneg, blst_p1_mult,
+  blst_p1_compress, blst_p1_serialize, blst_p1_uncompress, blst_p1_affine_in_g1, blst_p1_from_affine
+);
+blst_group!(
+  BlstG2, blst_p2, blst_p2_affine, 96,
+  blst_p2_generator, blst_p2_is_inf, blst_p2_add_or_double, blst_p2_cneg, blst_p2_mult,
+  blst_p2_compress, blst_p2_serialize, blst_p2_uncompress, blst_p2_affine_in_g2, blst_p2_from_affine
+);
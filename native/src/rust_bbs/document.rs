@@ -0,0 +1,202 @@
+use std::collections::BTreeSet;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use super::json_schema::JsonSchema;
+use super::limits::check_messages;
+
+/// How the messages of a presentation were produced, so a verifier can rebuild what they disclose
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageFormat {
+  /// `pointer=value` messages flattened from a JSON document by `flatten_document`
+  JsonPointer,
+}
+
+impl JsonSchema for MessageFormat {
+  fn json_schema() -> Value {
+    json!({ "enum": ["json_pointer"] })
+  }
+}
+
+/// Flatten a JSON document into `(pointer, value)` nodes in canonical order: depth first, parents
+/// ahead of their children, object members sorted by key and array items by index.
+/// Objects and arrays are nodes too, valued `{}` / `[]`, so a rebuilt document keeps its structure
+pub fn flatten_document(document: &Value) -> Vec<(String, String)> {
+  let mut nodes = Vec::new();
+  flatten_node(String::new(), document, &mut nodes);
+  nodes
+}
+
+fn flatten_node(pointer: String, node: &Value, nodes: &mut Vec<(String, String)>) {
+  match node {
+    Value::Object(members) => {
+      nodes.push((pointer.clone(), "{}".to_string()));
+
+      let mut keys: Vec<&String> = members.keys().collect();
+      keys.sort_unstable();
+      for key in keys {
+        flatten_node(format!("{}/{}", pointer, escape_token(key)), &members[key], nodes);
+      }
+    },
+    Value::Array(items) => {
+      nodes.push((pointer.clone(), "[]".to_string()));
+
+      for (index, item) in items.iter().enumerate() {
+        flatten_node(format!("{}/{}", pointer, index), item, nodes);
+      }
+    },
+    value => nodes.push((pointer, value.to_string())),
+  }
+}
+
+/// Message signed for a node
+pub fn node_message(pointer: &str, value: &str) -> String {
+  format!("{}={}", pointer, value)
+}
+
+/// Indices of the nodes disclosed by revealing each pointer: the node, its descendants and
+/// its ancestors' (valueless) `{}` / `[]` nodes
+pub fn reveal_indices(nodes: &[(String, String)], reveal: &[String]) -> Result<Vec<usize>, BBSError> {
+  let mut revealed = BTreeSet::new();
+
+  for pointer in reveal {
+    if !nodes.iter().any(|(node_pointer, _)| node_pointer == pointer) {
+      handle_err!(format!("JSON Pointer not in document: {}", pointer));
+    }
+
+    for (index, (node_pointer, _)) in nodes.iter().enumerate() {
+      if is_within(node_pointer, pointer) || is_within(pointer, node_pointer) {
+        revealed.insert(index);
+      }
+    }
+  }
+
+  Ok(revealed.into_iter().collect())
+}
+
+/// Whether `pointer` is `ancestor` or one of its descendants
+fn is_within(pointer: &str, ancestor: &str) -> bool {
+  match pointer.strip_prefix(ancestor) {
+    Some(rest) => rest.is_empty() || rest.starts_with('/'),
+    None => false,
+  }
+}
+
+/// Rebuild the document disclosed by `pointer=value` messages, in the order they were flattened.
+/// Array items that weren't revealed are left as `null`
+pub fn rebuild_document(messages: &[&str]) -> Result<Value, BBSError> {
+  let mut document = Value::Null;
+
+  for message in messages {
+    let (pointer, value) = parse_node_message(message)?;
+    let tokens = match parse_pointer(pointer) {
+      Some(tokens) => tokens,
+      None => { handle_err!(format!("Invalid JSON Pointer in message: {}", pointer)); }
+    };
+
+    let (last, parents) = match tokens.split_last() {
+      Some(split) => split,
+      None => {
+        document = value;
+        continue;
+      }
+    };
+
+    let mut parent = &mut document;
+    for token in parents {
+      parent = match child_mut(parent, token) {
+        Some(child) => child,
+        None => { handle_err!(format!("Message for {} precedes that of its parent", pointer)); }
+      };
+    }
+
+    match parent {
+      Value::Object(members) => { members.insert(last.to_string(), value); },
+      Value::Array(items) => match last.parse::<usize>() {
+        Ok(index) => {
+          if items.len() <= index {
+            items.resize(index + 1, Value::Null);
+          }
+          items[index] = value;
+        },
+        Err(_) => { handle_err!(format!("Invalid array index in JSON Pointer: {}", pointer)); }
+      },
+      _ => { handle_err!(format!("Message for {} precedes that of its parent", pointer)); }
+    }
+  }
+
+  Ok(document)
+}
+
+fn child_mut<'a>(parent: &'a mut Value, token: &str) -> Option<&'a mut Value> {
+  match parent {
+    Value::Object(members) => members.get_mut(token),
+    Value::Array(items) => token.parse::<usize>().ok().and_then(move |index| items.get_mut(index)),
+    _ => None,
+  }
+}
+
+/// Split a `pointer=value` message. Object keys may hold `=`, so the split is at the first `=`
+/// followed by valid JSON, which the JSON value (holding `=` only within strings) always is
+fn parse_node_message(message: &str) -> Result<(&str, Value), BBSError> {
+  for (position, _) in message.match_indices('=') {
+    if let Ok(value) = serde_json::from_str::<Value>(&message[position + 1..]) {
+      return Ok((&message[..position], value));
+    }
+  }
+  handle_err!("Invalid document message, expected 'pointer=value'");
+}
+
+fn escape_token(token: &str) -> String {
+  token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Unescaped reference tokens of an RFC 6901 JSON Pointer, `None` where it is malformed
+fn parse_pointer(pointer: &str) -> Option<Vec<String>> {
+  if pointer.is_empty() {
+    return Some(Vec::new());
+  }
+
+  pointer.strip_prefix('/')?
+    .split('/')
+    .map(|token| {
+      let unescaped = token.replace("~1", "/").replace("~0", "~");
+      // '~' is only valid as the start of the '~0' / '~1' escapes
+      if token.replace("~0", "").replace("~1", "").contains('~') {
+        None
+      } else {
+        Some(unescaped)
+      }
+    })
+    .collect()
+}
+
+/// Base64 messages of a JSON document's flattened nodes, and the indices of those disclosed by
+/// revealing the `reveal` JSON Pointers where given
+pub fn document_messages(document: &Value, reveal: Option<&[String]>) -> Result<(Vec<String>, Option<Vec<usize>>), BBSError> {
+  let nodes = flatten_document(document);
+  check_messages("'document' node", nodes.iter().map(|(pointer, value)| node_message(pointer, value).len()))?;
+  let revealed = match reveal {
+    Some(reveal) => Some(reveal_indices(&nodes, reveal)?),
+    None => None,
+  };
+
+  let messages = nodes.iter()
+    .map(|(pointer, value)| base64::encode(node_message(pointer, value)))
+    .collect();
+  Ok((messages, revealed))
+}
+
+/// Partially disclosed document from a presentation's base64 revealed messages
+pub fn disclosed_document<'a, I: Iterator<Item = &'a String>>(messages: I) -> Result<Value, BBSError> {
+  let mut decoded = Vec::new();
+  for message in messages {
+    match base64::decode(message).map(String::from_utf8) {
+      Ok(Ok(message)) => decoded.push(message),
+      _ => { handle_err!("Invalid document message, expected base64 'pointer=value'"); }
+    }
+  }
+
+  rebuild_document(&decoded.iter().map(String::as_str).collect::<Vec<&str>>())
+}
@@ -0,0 +1,193 @@
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde_json::{Map, Value};
+use zeroize::{Zeroize, Zeroizing};
+use super::Operation;
+use super::cbor::CBOR_ENCODING;
+use super::secret::wipe_json;
+
+/// Context and result properties holding bytes, as strings or arrays of them. 'claims' and
+/// 'revealed_messages' are objects of them, and a 'presentation' object nests them
+pub const BYTE_PROPERTIES: &[&str] = &[
+  "blind_signature",
+  "blinding_factor",
+  "challenge_hash",
+  "claims",
+  "commitment",
+  "credential",
+  "deterministic_seed",
+  "header",
+  "hmac_key",
+  "messages",
+  "nonce",
+  "payloads",
+  "presentation",
+  "presentation_header",
+  "proof",
+  "proof_of_hidden_messages",
+  "public_key",
+  "revealed_messages",
+  "schema_hash",
+  "secret_key",
+  "seed",
+  "signature",
+];
+
+/// JSON Web Proof contexts read their headers as JSON and 'nonce' as text
+pub const JWP_BYTE_PROPERTIES: &[&str] = &[
+  "deterministic_seed",
+  "payloads",
+  "public_key",
+  "secret_key",
+];
+
+/// Byte properties that hold an object of byte strings, rather than one or an array of them
+const BYTE_OBJECT_PROPERTIES: &[&str] = &["claims", "revealed_messages"];
+
+/// Encoding of the byte properties in a context and its result
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Encoding {
+  /// Standard padded base64, which operations use internally
+  Base64,
+  /// URL-safe base64, read with or without padding and written without
+  Base64Url,
+  /// Hexadecimal, read in either case and written in lower case
+  Hex,
+}
+
+impl Encoding {
+  pub fn from_name(name: &str) -> Option<Encoding> {
+    match name {
+      "base64" => Some(Encoding::Base64),
+      "base64url" => Some(Encoding::Base64Url),
+      "hex" => Some(Encoding::Hex),
+      _ => None,
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      Encoding::Base64 => "base64",
+      Encoding::Base64Url => "base64url",
+      Encoding::Hex => "hex",
+    }
+  }
+
+  fn decode(&self, value: &str) -> Option<Zeroizing<Vec<u8>>> {
+    match self {
+      Encoding::Base64 => base64::decode(value).ok(),
+      Encoding::Base64Url => base64::decode_config(value.trim_end_matches('='), base64::URL_SAFE_NO_PAD).ok(),
+      Encoding::Hex => hex::decode(value).ok(),
+    }.map(Zeroizing::new)
+  }
+
+  fn encode(&self, bytes: &[u8]) -> String {
+    match self {
+      Encoding::Base64 => base64::encode(bytes),
+      Encoding::Base64Url => base64::encode_config(bytes, base64::URL_SAFE_NO_PAD),
+      Encoding::Hex => hex::encode(bytes),
+    }
+  }
+
+  /// Run an operation on the context with its byte `properties` re-encoded as base64, and
+  /// encode the same properties of its result
+  pub fn call(self, mut context_json: Value, properties: &[&str], operation: Operation) -> Result<String, BBSError> {
+    recode_properties(&mut context_json, properties, self, Encoding::Base64, true)?;
+
+    let result = Zeroizing::new(operation(context_json)?);
+    let mut result_json: Value = match serde_json::from_str(&result) {
+      Ok(result_json) => result_json,
+      Err(_) => { handle_err!("Failed parsing JSON result"); }
+    };
+    // results only hold base64 bytes, so anything else in a byte property is kept as is
+    recode_properties(&mut result_json, properties, Encoding::Base64, self, false)?;
+
+    // size the output up front, as growing it would leave copies of secrets in freed memory
+    let mut json_bytes = Zeroizing::new(Vec::with_capacity(result.len() * 2));
+    let serialized = serde_json::to_writer(&mut *json_bytes, &result_json);
+    wipe_json(&mut result_json);
+    match serialized {
+      // serde_json only writes valid UTF-8
+      Ok(_) => Ok(String::from_utf8(std::mem::take(&mut *json_bytes)).unwrap()),
+      Err(_) => { handle_err!("Failed to stringify result"); }
+    }
+  }
+}
+
+/// Take the context's 'encoding', where it sets one other than base64
+pub fn take_encoding(context_json: &mut Value) -> Result<Option<Encoding>, BBSError> {
+  let encoding = match context_json.as_object_mut().and_then(|context| context.remove("encoding")) {
+    None | Some(Value::Null) => return Ok(None),
+    // taken by `parse_context` from the CBOR contexts it's meant for
+    Some(Value::String(name)) if name == CBOR_ENCODING => {
+      handle_err!("Encoding 'cbor' is only for CBOR contexts");
+    },
+    Some(Value::String(name)) => Encoding::from_name(&name),
+    Some(_) => None,
+  };
+
+  match encoding {
+    Some(Encoding::Base64) => Ok(None),
+    Some(encoding) => Ok(Some(encoding)),
+    None => { handle_err!("Unsupported 'encoding', expected 'base64', 'base64url', 'hex' or 'cbor'"); }
+  }
+}
+
+fn recode_properties(
+  value: &mut Value,
+  properties: &[&str],
+  from: Encoding,
+  to: Encoding,
+  strict: bool,
+) -> Result<(), BBSError> {
+  let object = match value.as_object_mut() {
+    Some(object) => object,
+    None => return Ok(()),
+  };
+
+  for (property, value) in object.iter_mut().filter(|(property, _)| properties.contains(&property.as_str())) {
+    match value {
+      // a presentation given as an object, or a JSON string of one
+      Value::Object(_) if property == "presentation" => recode_properties(value, properties, from, to, strict)?,
+      Value::String(presentation) if property == "presentation" && presentation.starts_with('{') => {
+        let mut presentation_json: Value = match serde_json::from_str(presentation) {
+          Ok(presentation_json) => presentation_json,
+          Err(_) => { handle_err!("Failed parsing JSON for: 'presentation'"); }
+        };
+        recode_properties(&mut presentation_json, properties, from, to, strict)?;
+        *presentation = presentation_json.to_string();
+      },
+      Value::Object(values) if BYTE_OBJECT_PROPERTIES.contains(&property.as_str()) => recode_object(values, property, from, to, strict)?,
+      Value::Array(values) => for value in values {
+        recode_value(value, property, from, to, strict)?;
+      },
+      // JWKs are objects, and aren't re-encoded
+      value => recode_value(value, property, from, to, strict)?,
+    }
+  }
+  Ok(())
+}
+
+fn recode_object(values: &mut Map<String, Value>, property: &str, from: Encoding, to: Encoding, strict: bool) -> Result<(), BBSError> {
+  for value in values.values_mut() {
+    recode_value(value, property, from, to, strict)?;
+  }
+  Ok(())
+}
+
+fn recode_value(value: &mut Value, property: &str, from: Encoding, to: Encoding, strict: bool) -> Result<(), BBSError> {
+  let text = match value {
+    // did:key public keys are text
+    Value::String(text) if !text.starts_with("did:") => text,
+    _ => return Ok(()),
+  };
+
+  match from.decode(text) {
+    Some(bytes) => {
+      // the value may be secret material
+      std::mem::replace(text, to.encode(&bytes)).zeroize();
+      Ok(())
+    },
+    None if strict => { handle_err!(format!("Failed decoding {} for: '{}'", from.name(), property)); },
+    None => Ok(()),
+  }
+}
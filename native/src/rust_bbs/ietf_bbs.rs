@@ -0,0 +1,362 @@
+use std::convert::TryInto;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use pairing_plus::hash_to_field::{ExpandMsg, ExpandMsgXmd};
+use rand::prelude::*;
+use sha2::Sha256;
+use zeroize::Zeroize;
+use super::curve::{CurveBackend, Group, Scalar, OKM_LENGTH};
+use super::limits::check_message_count;
+
+// BBS signatures as specified by draft-irtf-cfrg-bbs-signatures, with the
+// BLS12-381-SHA-256 ciphersuite and messages hashed to scalars (the `H2G_HM2S_` interface),
+// over any `CurveBackend`
+
+/// `ciphersuite_id` of the BLS12-381-SHA-256 ciphersuite
+pub const CIPHERSUITE_ID: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_";
+const API_ID_SUFFIX: &[u8] = b"H2G_HM2S_";
+
+pub const SCALAR_LENGTH: usize = 32;
+pub const POINT_LENGTH: usize = 48;
+pub const PUBLIC_KEY_LENGTH: usize = 96;
+pub const SIGNATURE_LENGTH: usize = POINT_LENGTH + SCALAR_LENGTH;
+/// Length of a proof disclosing every message: `Abar, Bbar, D, e^, r1^, r3^, challenge`
+const PROOF_BASE_LENGTH: usize = 3 * POINT_LENGTH + 4 * SCALAR_LENGTH;
+
+fn api_id() -> Vec<u8> {
+  [CIPHERSUITE_ID, API_ID_SUFFIX].concat()
+}
+
+/// Domain separation tag `api_id || suffix`
+pub fn dst(suffix: &[u8]) -> Vec<u8> {
+  [&api_id()[..], suffix].concat()
+}
+
+fn expand_message(msg: &[u8], dst: &[u8]) -> Vec<u8> {
+  ExpandMsgXmd::<Sha256>::expand_message(msg, dst, OKM_LENGTH)
+}
+
+/// `hash_to_scalar`
+pub fn hash_to_scalar<S: Scalar>(msg: &[u8], dst: &[u8]) -> S {
+  let mut uniform_bytes = expand_message(msg, dst);
+  let scalar = S::from_okm(uniform_bytes[..].try_into().unwrap());
+  uniform_bytes.zeroize();
+  scalar
+}
+
+/// `create_generators`, returning `count` generators seeded by `generator_seed`
+pub fn create_generators<B: CurveBackend>(generator_seed: &[u8], count: usize) -> Vec<B::G1> {
+  let seed_dst = dst(b"SIG_GENERATOR_SEED_");
+  let generator_dst = dst(b"SIG_GENERATOR_DST_");
+
+  let mut v = expand_message(&[&api_id()[..], generator_seed].concat(), &seed_dst);
+  (1..=count as u64)
+    .map(|i| {
+      v = expand_message(&[&v[..], &i.to_be_bytes()].concat(), &seed_dst);
+      B::hash_to_g1(&v, &generator_dst)
+    })
+    .collect()
+}
+
+/// Base point `P1`
+pub fn p1<B: CurveBackend>() -> B::G1 {
+  create_generators::<B>(b"BP_MESSAGE_GENERATOR_SEED", 1).remove(0)
+}
+
+/// Generators `Q_1, H_1, ..., H_L` for `message_count` messages
+pub fn message_generators<B: CurveBackend>(message_count: usize) -> (B::G1, Vec<B::G1>) {
+  let mut generators = create_generators::<B>(b"MESSAGE_GENERATOR_SEED", message_count + 1);
+  let q_1 = generators.remove(0);
+  (q_1, generators)
+}
+
+/// `messages_to_scalars`
+pub fn messages_to_scalars<S: Scalar>(messages: &[Vec<u8>]) -> Vec<S> {
+  let map_dst = dst(b"MAP_MSG_TO_SCALAR_AS_HASH_");
+  messages.iter().map(|message| hash_to_scalar(message, &map_dst)).collect()
+}
+
+fn serialize_scalar<S: Scalar>(scalar: &S) -> Vec<u8> {
+  scalar.to_bytes().to_vec()
+}
+
+/// Scalar from its 32 big-endian bytes, rejecting values not below the group order
+pub fn deserialize_scalar<S: Scalar>(bytes: &[u8]) -> Result<S, BBSError> {
+  let bytes = match bytes.try_into() {
+    Ok(bytes) => bytes,
+    Err(_) => { handle_err!("Invalid scalar length"); }
+  };
+  match S::from_bytes(bytes) {
+    Some(scalar) => Ok(scalar),
+    None => { handle_err!("Invalid scalar, not below the group order"); }
+  }
+}
+
+/// Non-identity G1 point from its compressed form
+fn deserialize_g1<G: Group>(bytes: &[u8]) -> Result<G, BBSError> {
+  match G::from_compressed(bytes) {
+    Some(point) if !point.is_identity() => Ok(point),
+    _ => { handle_err!("Invalid G1 point"); }
+  }
+}
+
+/// Non-identity public key `W` from its compressed form
+pub fn deserialize_public_key<G: Group>(bytes: &[u8]) -> Result<G, BBSError> {
+  match G::from_compressed(bytes) {
+    Some(point) if !point.is_identity() => Ok(point),
+    _ => { handle_err!("Invalid public key"); }
+  }
+}
+
+/// `SkToPk`
+pub fn secret_key_to_public_key<B: CurveBackend>(secret_key: &B::Scalar) -> Vec<u8> {
+  B::G2::generator().mul(secret_key).to_compressed()
+}
+
+fn sum_of_products<G: Group>(points: &[G], scalars: &[G::Scalar]) -> G {
+  points.iter().zip(scalars).fold(G::identity(), |sum, (point, scalar)| sum.add(&point.mul(scalar)))
+}
+
+/// `calculate_domain`
+fn calculate_domain<G: Group>(public_key: &[u8], q_1: &G, h_points: &[G], header: &[u8]) -> G::Scalar {
+  let mut dom_input = public_key.to_vec();
+  dom_input.extend_from_slice(&(h_points.len() as u64).to_be_bytes());
+  dom_input.append(&mut q_1.to_compressed());
+  for h in h_points {
+    dom_input.append(&mut h.to_compressed());
+  }
+  dom_input.append(&mut api_id());
+  dom_input.extend_from_slice(&(header.len() as u64).to_be_bytes());
+  dom_input.extend_from_slice(header);
+
+  hash_to_scalar(&dom_input, &dst(b"H2S_"))
+}
+
+/// `B = P1 + Q_1 * domain + H_1 * msg_1 + ... + H_L * msg_L`
+fn signature_base<B: CurveBackend>(q_1: &B::G1, h_points: &[B::G1], domain: &B::Scalar, messages: &[B::Scalar]) -> B::G1 {
+  p1::<B>().add(&q_1.mul(domain)).add(&sum_of_products(h_points, messages))
+}
+
+/// `Sign`, returning the serialized signature `(A, e)`
+pub fn sign<B: CurveBackend>(secret_key: &B::Scalar, public_key: &[u8], header: &[u8], messages: &[Vec<u8>]) -> Result<Vec<u8>, BBSError> {
+  check_message_count(messages.len())?;
+  let scalars: Vec<B::Scalar> = messages_to_scalars(messages);
+  let (q_1, h_points) = message_generators::<B>(messages.len());
+  let domain = calculate_domain(public_key, &q_1, &h_points, header);
+
+  let mut e_input = serialize_scalar(secret_key);
+  for scalar in scalars.iter().chain(Some(&domain)) {
+    e_input.append(&mut serialize_scalar(scalar));
+  }
+  let e: B::Scalar = hash_to_scalar(&e_input, &dst(b"H2S_"));
+  e_input.zeroize();
+
+  let b = signature_base::<B>(&q_1, &h_points, &domain, &scalars);
+  let mut sk_e = secret_key.add(&e);
+  let sk_e_inverse = sk_e.inverse();
+  sk_e.zeroize();
+  let a = match sk_e_inverse {
+    Some(inverse) => b.mul(&inverse),
+    None => { handle_err!("Failed to sign messages"); }
+  };
+
+  let mut signature = a.to_compressed();
+  signature.append(&mut serialize_scalar(&e));
+  Ok(signature)
+}
+
+fn deserialize_signature<B: CurveBackend>(signature: &[u8]) -> Result<(B::G1, B::Scalar), BBSError> {
+  if signature.len() != SIGNATURE_LENGTH {
+    handle_err!("Invalid signature length");
+  }
+  Ok((deserialize_g1(&signature[..POINT_LENGTH])?, deserialize_scalar(&signature[POINT_LENGTH..])?))
+}
+
+/// `Verify`
+pub fn verify<B: CurveBackend>(public_key: &[u8], signature: &[u8], header: &[u8], messages: &[Vec<u8>]) -> Result<bool, BBSError> {
+  check_message_count(messages.len())?;
+  let (a, e) = deserialize_signature::<B>(signature)?;
+  let w: B::G2 = deserialize_public_key(public_key)?;
+
+  let scalars = messages_to_scalars(messages);
+  let (q_1, h_points) = message_generators::<B>(messages.len());
+  let domain = calculate_domain(public_key, &q_1, &h_points, header);
+  let b = signature_base::<B>(&q_1, &h_points, &domain, &scalars);
+
+  // e(A, W + BP2 * e) * e(B, -BP2) == 1
+  let w_e = B::G2::generator().mul(&e).add(&w);
+  Ok(B::pairings_are_identity(&a, &w_e, &b, &B::G2::generator().neg()))
+}
+
+fn random_scalar<S: Scalar, R: RngCore + CryptoRng>(rng: &mut R) -> S {
+  let mut bytes = [0u8; OKM_LENGTH];
+  rng.fill_bytes(&mut bytes);
+  let scalar = S::from_okm(&bytes);
+  bytes.zeroize();
+  scalar
+}
+
+/// `ProofChallengeCalculate`
+#[allow(clippy::too_many_arguments)]
+fn proof_challenge<G: Group>(
+  disclosed: &[(usize, G::Scalar)],
+  a_bar: &G,
+  b_bar: &G,
+  d: &G,
+  t1: &G,
+  t2: &G,
+  domain: &G::Scalar,
+  presentation_header: &[u8],
+) -> G::Scalar {
+  let mut c_octs = (disclosed.len() as u64).to_be_bytes().to_vec();
+  for (index, message) in disclosed {
+    c_octs.extend_from_slice(&(*index as u64).to_be_bytes());
+    c_octs.append(&mut serialize_scalar(message));
+  }
+  for point in &[a_bar, b_bar, d, t1, t2] {
+    c_octs.append(&mut point.to_compressed());
+  }
+  c_octs.append(&mut serialize_scalar(domain));
+  c_octs.extend_from_slice(&(presentation_header.len() as u64).to_be_bytes());
+  c_octs.extend_from_slice(presentation_header);
+
+  hash_to_scalar(&c_octs, &dst(b"H2S_"))
+}
+
+/// `ProofGen`, disclosing the messages at the sorted, unique `disclosed_indexes`
+pub fn proof_gen<B: CurveBackend, R: RngCore + CryptoRng>(
+  public_key: &[u8],
+  signature: &[u8],
+  header: &[u8],
+  presentation_header: &[u8],
+  messages: &[Vec<u8>],
+  disclosed_indexes: &[usize],
+  rng: &mut R,
+) -> Result<Vec<u8>, BBSError> {
+  check_message_count(messages.len())?;
+  let (a, e) = deserialize_signature::<B>(signature)?;
+  if disclosed_indexes.windows(2).any(|pair| pair[0] >= pair[1]) {
+    handle_err!("Disclosed indexes must be sorted and unique");
+  }
+  if disclosed_indexes.iter().any(|index| *index >= messages.len()) {
+    handle_err!("Disclosed index is out of bounds");
+  }
+
+  let scalars: Vec<B::Scalar> = messages_to_scalars(messages);
+  let (q_1, h_points) = message_generators::<B>(messages.len());
+  let domain = calculate_domain(public_key, &q_1, &h_points, header);
+  let undisclosed: Vec<usize> = (0..messages.len()).filter(|index| disclosed_indexes.binary_search(index).is_err()).collect();
+
+  let (r1, r2, e_tilde, r1_tilde, r3_tilde) = (
+    random_scalar::<B::Scalar, R>(rng),
+    random_scalar(rng),
+    random_scalar(rng),
+    random_scalar(rng),
+    random_scalar(rng),
+  );
+  let m_tilde: Vec<B::Scalar> = undisclosed.iter().map(|_| random_scalar(rng)).collect();
+
+  // ProofInit
+  let b = signature_base::<B>(&q_1, &h_points, &domain, &scalars);
+  let d = b.mul(&r2);
+  let a_bar = a.mul(&r1.mul(&r2));
+  let b_bar = d.mul(&r1).add(&a_bar.mul(&e).neg());
+
+  let t1 = sum_of_products(&[a_bar, d], &[e_tilde, r1_tilde]);
+  let undisclosed_generators: Vec<B::G1> = undisclosed.iter().map(|index| h_points[*index]).collect();
+  let t2 = d.mul(&r3_tilde).add(&sum_of_products(&undisclosed_generators, &m_tilde));
+
+  let disclosed: Vec<(usize, B::Scalar)> = disclosed_indexes.iter().map(|index| (*index, scalars[*index])).collect();
+  let challenge = proof_challenge(&disclosed, &a_bar, &b_bar, &d, &t1, &t2, &domain, presentation_header);
+
+  // ProofFinalize
+  let r3 = match r2.inverse() {
+    Some(r3) => r3,
+    None => { handle_err!("Failed generating proof"); }
+  };
+  let response = |tilde: &B::Scalar, secret: &B::Scalar, add: bool| {
+    let product = secret.mul(&challenge);
+    if add {
+      tilde.add(&product)
+    } else {
+      tilde.sub(&product)
+    }
+  };
+
+  let mut proof = Vec::with_capacity(PROOF_BASE_LENGTH + undisclosed.len() * SCALAR_LENGTH);
+  for point in &[a_bar, b_bar, d] {
+    proof.append(&mut point.to_compressed());
+  }
+  proof.append(&mut serialize_scalar(&response(&e_tilde, &e, true)));
+  proof.append(&mut serialize_scalar(&response(&r1_tilde, &r1, false)));
+  proof.append(&mut serialize_scalar(&response(&r3_tilde, &r3, false)));
+  for (m_tilde, index) in m_tilde.iter().zip(&undisclosed) {
+    proof.append(&mut serialize_scalar(&response(m_tilde, &scalars[*index], true)));
+  }
+  proof.append(&mut serialize_scalar(&challenge));
+  Ok(proof)
+}
+
+/// Number of messages a proof leaves undisclosed, from its length
+pub fn proof_undisclosed_count(proof: &[u8]) -> Result<usize, BBSError> {
+  if proof.len() < PROOF_BASE_LENGTH || !(proof.len() - PROOF_BASE_LENGTH).is_multiple_of(SCALAR_LENGTH) {
+    handle_err!("Invalid proof length");
+  }
+  Ok((proof.len() - PROOF_BASE_LENGTH) / SCALAR_LENGTH)
+}
+
+/// `ProofVerify`, given the disclosed messages by their index among all signed messages
+pub fn proof_verify<B: CurveBackend>(
+  public_key: &[u8],
+  proof: &[u8],
+  header: &[u8],
+  presentation_header: &[u8],
+  disclosed_messages: &[Vec<u8>],
+  disclosed_indexes: &[usize],
+) -> Result<bool, BBSError> {
+  let undisclosed_count = proof_undisclosed_count(proof)?;
+  let message_count = undisclosed_count + disclosed_indexes.len();
+  // the proof sets the generators derived, so its size can't set the work done
+  check_message_count(message_count)?;
+  if disclosed_messages.len() != disclosed_indexes.len() {
+    handle_err!("Number of disclosed messages is different from the number of disclosed indexes");
+  }
+  if disclosed_indexes.windows(2).any(|pair| pair[0] >= pair[1]) {
+    handle_err!("Disclosed indexes must be sorted and unique");
+  }
+  if disclosed_indexes.iter().any(|index| *index >= message_count) {
+    handle_err!("Disclosed index is out of bounds");
+  }
+  let w: B::G2 = deserialize_public_key(public_key)?;
+
+  let points = (0..3)
+    .map(|i| deserialize_g1(&proof[i * POINT_LENGTH..(i + 1) * POINT_LENGTH]))
+    .collect::<Result<Vec<B::G1>, BBSError>>()?;
+  let (a_bar, b_bar, d) = (points[0], points[1], points[2]);
+  let scalars = proof[3 * POINT_LENGTH..]
+    .chunks(SCALAR_LENGTH)
+    .map(deserialize_scalar)
+    .collect::<Result<Vec<B::Scalar>, BBSError>>()?;
+  let (e_hat, r1_hat, r3_hat) = (scalars[0], scalars[1], scalars[2]);
+  let m_hat = &scalars[3..3 + undisclosed_count];
+  let challenge = scalars[3 + undisclosed_count];
+
+  let disclosed_scalars: Vec<B::Scalar> = messages_to_scalars(disclosed_messages);
+  let (q_1, h_points) = message_generators::<B>(message_count);
+  let domain = calculate_domain(public_key, &q_1, &h_points, header);
+  let undisclosed: Vec<usize> = (0..message_count).filter(|index| disclosed_indexes.binary_search(index).is_err()).collect();
+
+  // ProofVerifyInit
+  let t1 = sum_of_products(&[b_bar, a_bar, d], &[challenge, e_hat, r1_hat]);
+  let disclosed_generators: Vec<B::G1> = disclosed_indexes.iter().map(|index| h_points[*index]).collect();
+  let bv = signature_base::<B>(&q_1, &disclosed_generators, &domain, &disclosed_scalars);
+  let undisclosed_generators: Vec<B::G1> = undisclosed.iter().map(|index| h_points[*index]).collect();
+  let t2 = sum_of_products(&[bv, d], &[challenge, r3_hat]).add(&sum_of_products(&undisclosed_generators, m_hat));
+
+  let disclosed: Vec<(usize, B::Scalar)> = disclosed_indexes.iter().cloned().zip(disclosed_scalars).collect();
+  if proof_challenge(&disclosed, &a_bar, &b_bar, &d, &t1, &t2, &domain, presentation_header) != challenge {
+    return Ok(false);
+  }
+
+  // e(Abar, W) * e(Bbar, -BP2) == 1
+  Ok(B::pairings_are_identity(&a_bar, &w, &b_bar, &B::G2::generator().neg()))
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use serde_json::{Map, Value, json};
+
+/// JSON Schema dialect of the documents returned by `bbs_get_schema`
+pub const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A type with a JSON Schema for its JSON form
+pub trait JsonSchema {
+  /// Whether an object property of this type may be left out
+  const OPTIONAL: bool = false;
+
+  fn json_schema() -> Value;
+}
+
+/// Schema of a closed object, from its `(name, schema, optional)` properties
+pub fn object_schema(description: &str, properties: Vec<(&str, Value, bool)>) -> Value {
+  let required: Vec<&str> = properties.iter()
+    .filter(|(_, _, optional)| !optional)
+    .map(|(name, _, _)| *name)
+    .collect();
+  let properties: Map<String, Value> = properties.into_iter()
+    .map(|(name, schema, _)| (name.to_string(), schema))
+    .collect();
+
+  let mut schema = json!({
+    "type": "object",
+    "properties": properties,
+    "required": required,
+    "additionalProperties": false,
+  });
+  if !description.is_empty() {
+    schema["description"] = Value::String(description.to_string());
+  }
+  schema
+}
+
+/// Join the lines of a doc comment into a description
+pub fn doc_description(lines: &[&str]) -> String {
+  lines.iter().map(|line| line.trim()).collect::<Vec<&str>>().join(" ")
+}
+
+/// Describe a property's schema, where it has a doc comment
+pub fn with_description(mut schema: Value, lines: &[&str]) -> Value {
+  if !lines.is_empty() {
+    schema["description"] = Value::String(doc_description(lines));
+  }
+  schema
+}
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+  const OPTIONAL: bool = true;
+
+  fn json_schema() -> Value {
+    json!({ "anyOf": [T::json_schema(), { "type": "null" }] })
+  }
+}
+
+impl<T: JsonSchema> JsonSchema for Vec<T> {
+  fn json_schema() -> Value {
+    json!({ "type": "array", "items": T::json_schema() })
+  }
+}
+
+impl JsonSchema for String {
+  fn json_schema() -> Value {
+    json!({ "type": "string" })
+  }
+}
+
+impl JsonSchema for bool {
+  fn json_schema() -> Value {
+    json!({ "type": "boolean" })
+  }
+}
+
+impl JsonSchema for u8 {
+  fn json_schema() -> Value {
+    json!({ "type": "integer", "minimum": 0, "maximum": u8::MAX })
+  }
+}
+
+impl JsonSchema for u32 {
+  fn json_schema() -> Value {
+    json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX })
+  }
+}
+
+impl JsonSchema for u64 {
+  fn json_schema() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+  }
+}
+
+impl JsonSchema for usize {
+  fn json_schema() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+  }
+}
+
+/// Any JSON value
+impl JsonSchema for Value {
+  fn json_schema() -> Value {
+    json!({})
+  }
+}
+
+impl JsonSchema for Map<String, Value> {
+  fn json_schema() -> Value {
+    json!({ "type": "object" })
+  }
+}
+
+impl<T: JsonSchema> JsonSchema for BTreeMap<String, T> {
+  fn json_schema() -> Value {
+    json!({ "type": "object", "additionalProperties": T::json_schema() })
+  }
+}
+
+/// Objects keyed by message index
+impl<T: JsonSchema> JsonSchema for BTreeMap<usize, T> {
+  fn json_schema() -> Value {
+    json!({
+      "type": "object",
+      "propertyNames": { "pattern": "^(0|[1-9][0-9]*)$" },
+      "additionalProperties": T::json_schema(),
+    })
+  }
+}
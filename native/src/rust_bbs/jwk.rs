@@ -0,0 +1,150 @@
+use bbs_plus::errors::BBSError;
+use bbs_plus::prelude::*;
+use bbs_plus::FR_COMPRESSED_SIZE;
+use pairing_plus::{
+    bls12_381::{G1, G2},
+    serdes::SerDes,
+};
+use serde::Serialize;
+use serde_json::Value;
+use zeroize::Zeroizing;
+use super::secret::{take_secret, decode_secret_config};
+
+/// BLS12-381 group a JWK's public key lies in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JwkCurve {
+  G1,
+  G2,
+}
+
+impl JwkCurve {
+  /// Curve for a compressed public key, by its length
+  pub fn from_public_key(public_key: &[u8]) -> Option<JwkCurve> {
+    match public_key.len() {
+      G1_COMPRESSED_SIZE => Some(JwkCurve::G1),
+      G2_COMPRESSED_SIZE => Some(JwkCurve::G2),
+      _ => None,
+    }
+  }
+
+  /// Accepts both the registered (`BLS12381G2`) and earlier (`Bls12381G2`, `BLS12381_G2`) curve names
+  pub fn from_name(name: &str) -> Option<JwkCurve> {
+    match name.to_ascii_uppercase().replace('_', "").as_str() {
+      "BLS12381G1" => Some(JwkCurve::G1),
+      "BLS12381G2" => Some(JwkCurve::G2),
+      _ => None,
+    }
+  }
+
+  pub fn name(self) -> &'static str {
+    match self {
+      JwkCurve::G1 => "BLS12381G1",
+      JwkCurve::G2 => "BLS12381G2",
+    }
+  }
+
+  pub fn public_key_size(self) -> usize {
+    match self {
+      JwkCurve::G1 => G1_COMPRESSED_SIZE,
+      JwkCurve::G2 => G2_COMPRESSED_SIZE,
+    }
+  }
+}
+
+/// JWK representation of a BLS12-381 key
+#[derive(Serialize)]
+pub struct Jwk<'a> {
+  pub kty: &'a str,
+  pub crv: &'a str,
+  pub x: &'a str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub d: Option<&'a str>,
+}
+
+pub fn base64url_encode<T: AsRef<[u8]>>(bytes: T) -> String {
+  base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)
+}
+
+fn base64url_decode(value: &str, property: &str) -> Result<Vec<u8>, BBSError> {
+  match base64::decode_config(value, base64::URL_SAFE_NO_PAD) {
+    Ok(bytes) => Ok(bytes),
+    Err(_) => { handle_err!(format!("Failed decoding base64url for JWK: '{}'", property)); }
+  }
+}
+
+/// Curve named by a BLS12-381 JWK, checking its key type
+pub fn jwk_curve(jwk: &Value) -> Result<JwkCurve, BBSError> {
+  match jwk["kty"].as_str() {
+    Some("OKP") | Some("EC") => (),
+    Some(kty) => { handle_err!(format!("Unsupported JWK key type: {}", kty)); },
+    None => { handle_err!("JWK property not set: 'kty'"); }
+  }
+
+  match jwk["crv"].as_str() {
+    Some(crv) => {
+      match JwkCurve::from_name(crv) {
+        Some(curve) => Ok(curve),
+        None => { handle_err!(format!("Unsupported JWK curve: {}", crv)); }
+      }
+    },
+    None => { handle_err!("JWK property not set: 'crv'"); }
+  }
+}
+
+/// Compressed public key bytes for a JWK, holding either a compressed `x`,
+/// or uncompressed `x` and `y` coordinates
+pub fn jwk_public_key(jwk: &Value) -> Result<Vec<u8>, BBSError> {
+  let curve = jwk_curve(jwk)?;
+
+  let x = match jwk["x"].as_str() {
+    Some(x) => base64url_decode(x, "x")?,
+    None => { handle_err!("JWK property not set: 'x'"); }
+  };
+
+  let y = match jwk["y"].as_str() {
+    Some(y) => base64url_decode(y, "y")?,
+    None => {
+      if x.len() != curve.public_key_size() {
+        handle_err!(format!("Invalid length for JWK: 'x', expected {} bytes", curve.public_key_size()));
+      }
+      return Ok(x);
+    }
+  };
+
+  if x.len() != curve.public_key_size() || y.len() != curve.public_key_size() {
+    handle_err!(format!("Invalid length for JWK: 'x' and 'y', expected {} bytes", curve.public_key_size()));
+  }
+
+  let mut uncompressed = x;
+  uncompressed.extend_from_slice(&y);
+
+  let mut compressed = Vec::with_capacity(curve.public_key_size());
+  let serialized = match curve {
+    JwkCurve::G1 => G1::deserialize(&mut uncompressed.as_slice(), false).map(|point| point.serialize(&mut compressed, true)),
+    JwkCurve::G2 => G2::deserialize(&mut uncompressed.as_slice(), false).map(|point| point.serialize(&mut compressed, true)),
+  };
+  match serialized {
+    Ok(Ok(())) => Ok(compressed),
+    _ => { handle_err!("Invalid JWK public key point"); }
+  }
+}
+
+/// Take the secret key `d` out of a JWK, so it is wiped when dropped
+pub fn take_jwk_secret_key(jwk: &mut Value) -> Result<Zeroizing<Vec<u8>>, BBSError> {
+  jwk_curve(jwk)?;
+
+  let d = match take_secret(jwk, "d") {
+    Some(d) => d,
+    None => { handle_err!("JWK property not set: 'd'"); }
+  };
+
+  let secret_key = match decode_secret_config(&d, base64::URL_SAFE_NO_PAD) {
+    Ok(secret_key) => secret_key,
+    Err(_) => { handle_err!("Failed decoding base64url for JWK: 'd'"); }
+  };
+  if secret_key.len() != FR_COMPRESSED_SIZE {
+    handle_err!(format!("Invalid length for JWK: 'd', expected {} bytes", FR_COMPRESSED_SIZE));
+  }
+
+  Ok(secret_key)
+}
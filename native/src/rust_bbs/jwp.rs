@@ -0,0 +1,197 @@
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use super::json_schema::JsonSchema;
+use super::jwk::base64url_encode;
+use super::limits::check_messages;
+
+// JSON Web Proof (draft-ietf-jose-json-web-proof) issued and presented forms, with the `BBS`
+// algorithm of draft-ietf-jose-json-proof-algorithms: the issuer protected header is the BBS
+// header, the payloads are the messages and the presentation protected header is the BBS
+// presentation header
+
+/// JSON Proof Algorithm for BLS12-381 SHA-256 BBS signatures and proofs
+pub const ALGORITHM: &str = "BBS";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Serialization {
+  /// `.` separated base64url parts, with `~` separated payloads
+  Compact,
+  /// JSON object of base64url members, with `null` undisclosed payloads
+  Json,
+}
+
+impl JsonSchema for Serialization {
+  fn json_schema() -> Value {
+    json!({ "enum": ["compact", "json"] })
+  }
+}
+
+/// JWP in either form, with its protected headers as their encoded JSON octets
+pub struct Jwp {
+  /// Presentation protected header, set only in the presented form
+  pub presentation_header: Option<Vec<u8>>,
+  pub issuer_header: Vec<u8>,
+  /// Payloads, `None` where a presentation doesn't disclose them
+  pub payloads: Vec<Option<Vec<u8>>>,
+  pub proof: Vec<u8>,
+}
+
+impl Jwp {
+  /// Parse a compact JWP string, or a JSON-serialized JWP object
+  pub fn from_value(jwp: &Value) -> Result<Jwp, BBSError> {
+    let jwp = match jwp {
+      Value::String(compact) => Jwp::from_compact(compact)?,
+      Value::Object(_) => Jwp::from_json(jwp)?,
+      _ => { handle_err!("Invalid 'jwp', expected a compact string or a JSON object"); }
+    };
+    // the payloads are the messages, so they're limited like a context's own
+    check_messages("'jwp' payload", jwp.payloads.iter().map(|payload| payload.as_ref().map_or(0, Vec::len)))?;
+    Ok(jwp)
+  }
+
+  fn from_compact(compact: &str) -> Result<Jwp, BBSError> {
+    let parts: Vec<&str> = compact.split('.').collect();
+    let (presentation_header, parts) = match parts.len() {
+      3 => (None, &parts[..]),
+      4 => (Some(decode_part(parts[0], "presentation header")?), &parts[1..]),
+      _ => { handle_err!("Invalid compact JWP, expected 3 (issued) or 4 (presented) parts"); }
+    };
+
+    // an empty payload list and a single empty payload can't be told apart, so the former wins
+    let payloads = match parts[1] {
+      "" => Vec::new(),
+      payloads => payloads.split('~')
+        .map(|payload| match payload {
+          "" if presentation_header.is_some() => Ok(None),
+          payload => decode_part(payload, "payload").map(Some),
+        })
+        .collect::<Result<Vec<Option<Vec<u8>>>, BBSError>>()?,
+    };
+
+    Ok(Jwp {
+      presentation_header,
+      issuer_header: decode_part(parts[0], "issuer header")?,
+      payloads,
+      proof: decode_proof(parts[2].split('~'))?,
+    })
+  }
+
+  fn from_json(jwp: &Value) -> Result<Jwp, BBSError> {
+    let presentation_header = match jwp["presentation"].as_str() {
+      Some(header) => Some(decode_part(header, "presentation header")?),
+      None => None,
+    };
+    let issuer_header = match jwp["issuer"].as_str() {
+      Some(header) => decode_part(header, "issuer header")?,
+      None => { handle_err!("JWP property not set: 'issuer'"); }
+    };
+
+    let payloads = match jwp["payloads"].as_array() {
+      Some(payloads) => payloads.iter()
+        .map(|payload| match payload {
+          Value::String(payload) => decode_part(payload, "payload").map(Some),
+          Value::Null if presentation_header.is_some() => Ok(None),
+          _ => { handle_err!("Invalid JWP payload, expected a base64url string"); }
+        })
+        .collect::<Result<Vec<Option<Vec<u8>>>, BBSError>>()?,
+      None => { handle_err!("JWP property not set: 'payloads'"); }
+    };
+
+    let proof = match jwp["proof"].as_array() {
+      Some(parts) if parts.iter().all(Value::is_string) => decode_proof(parts.iter().filter_map(Value::as_str))?,
+      _ => { handle_err!("Invalid JWP 'proof', expected an array of base64url strings"); }
+    };
+
+    Ok(Jwp { presentation_header, issuer_header, payloads, proof })
+  }
+
+  pub fn serialize(&self, serialization: Serialization) -> Result<Value, BBSError> {
+    match serialization {
+      Serialization::Compact => Ok(Value::String(self.to_compact()?)),
+      Serialization::Json => Ok(self.to_json()),
+    }
+  }
+
+  fn to_compact(&self) -> Result<String, BBSError> {
+    if self.presentation_header.is_some() && self.payloads.iter().any(|payload| payload.as_ref().is_some_and(Vec::is_empty)) {
+      handle_err!("A disclosed empty payload can't be told from an undisclosed one in a compact JWP, use the JSON serialization");
+    }
+
+    let payloads: Vec<String> = self.payloads.iter()
+      .map(|payload| payload.as_ref().map(base64url_encode).unwrap_or_default())
+      .collect();
+    let mut parts = Vec::with_capacity(4);
+    if let Some(header) = &self.presentation_header {
+      parts.push(base64url_encode(header));
+    }
+    parts.push(base64url_encode(&self.issuer_header));
+    parts.push(payloads.join("~"));
+    parts.push(base64url_encode(&self.proof));
+    Ok(parts.join("."))
+  }
+
+  fn to_json(&self) -> Value {
+    let mut jwp = json!({
+      "issuer": base64url_encode(&self.issuer_header),
+      "payloads": self.payloads.iter().map(|payload| payload.as_ref().map(base64url_encode)).collect::<Vec<Option<String>>>(),
+      "proof": [base64url_encode(&self.proof)],
+    });
+    if let Some(header) = &self.presentation_header {
+      jwp["presentation"] = Value::String(base64url_encode(header));
+    }
+    jwp
+  }
+
+  /// Disclosed payloads and their indices
+  pub fn disclosed_payloads(&self) -> (Vec<Vec<u8>>, Vec<usize>) {
+    self.payloads.iter().enumerate()
+      .filter_map(|(index, payload)| payload.as_ref().map(|payload| (payload.clone(), index)))
+      .unzip()
+  }
+}
+
+/// Encode a protected header object, setting its `alg` to `BBS`
+pub fn protected_header(mut header: Map<String, Value>, name: &str) -> Result<Vec<u8>, BBSError> {
+  match header.get("alg") {
+    None => { header.insert("alg".to_string(), Value::from(ALGORITHM)); },
+    Some(alg) if alg == ALGORITHM => (),
+    Some(alg) => { handle_err!(format!("Unsupported JWP algorithm: {}", alg)); }
+  }
+
+  match serde_json::to_vec(&header) {
+    Ok(header) => Ok(header),
+    Err(_) => { handle_err!(format!("Failed to encode '{}'", name)); }
+  }
+}
+
+/// Decode a protected header, checking its algorithm
+pub fn parse_protected_header(header: &[u8], name: &str) -> Result<Value, BBSError> {
+  let header: Value = match serde_json::from_slice(header) {
+    Ok(header @ Value::Object(_)) => header,
+    _ => { handle_err!(format!("Invalid JWP {}, expected a JSON object", name)); }
+  };
+
+  match header["alg"].as_str() {
+    Some(ALGORITHM) => Ok(header),
+    Some(alg) => { handle_err!(format!("Unsupported JWP algorithm: {}", alg)); },
+    None => { handle_err!(format!("JWP {} property not set: 'alg'", name)); }
+  }
+}
+
+fn decode_part(part: &str, name: &str) -> Result<Vec<u8>, BBSError> {
+  match base64::decode_config(part, base64::URL_SAFE_NO_PAD) {
+    Ok(bytes) => Ok(bytes),
+    Err(_) => { handle_err!(format!("Failed decoding base64url for JWP {}", name)); }
+  }
+}
+
+/// BBS proofs are a single part
+fn decode_proof<'a, I: Iterator<Item = &'a str>>(parts: I) -> Result<Vec<u8>, BBSError> {
+  let parts: Vec<&str> = parts.collect();
+  match parts[..] {
+    [proof] => decode_part(proof, "proof"),
+    _ => { handle_err!("Invalid JWP proof, expected a single part for the BBS algorithm"); }
+  }
+}
@@ -0,0 +1,258 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use bbs_plus::errors::BBSError;
+use bbs_plus::prelude::*;
+use bbs_plus::FR_COMPRESSED_SIZE;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use zeroize::Zeroizing;
+use super::json_schema::{JsonSchema, object_schema};
+
+/// Version of the encrypted key format written by `encrypt_key`
+pub const ENCRYPTED_KEY_VERSION: u64 = 1;
+
+const KDF_ARGON2ID: &str = "argon2id";
+const CIPHER_CHACHA20_POLY1305: &str = "chacha20-poly1305";
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+const KEY_SIZE: usize = 32;
+
+// Argon2id parameters for new exports (OWASP recommended minimum)
+const ARGON2_M_COST: u32 = 19 * 1024;
+const ARGON2_T_COST: u32 = 2;
+const ARGON2_P_COST: u32 = 1;
+
+// Upper bounds accepted on import, so a crafted export can't exhaust memory or CPU
+pub const ARGON2_MAX_M_COST: u32 = 1024 * 1024;
+pub const ARGON2_MAX_T_COST: u32 = 16;
+pub const ARGON2_MAX_P_COST: u32 = 16;
+
+/// Type of BLS key held in an encrypted export
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyType {
+  G1,
+  G2,
+  BlindedG1,
+  BlindedG2,
+}
+
+impl KeyType {
+  pub fn from_name(name: &str) -> Option<KeyType> {
+    match name {
+      "g1" => Some(KeyType::G1),
+      "g2" => Some(KeyType::G2),
+      "blinded_g1" => Some(KeyType::BlindedG1),
+      "blinded_g2" => Some(KeyType::BlindedG2),
+      _ => None,
+    }
+  }
+
+  pub fn name(self) -> &'static str {
+    match self {
+      KeyType::G1 => "g1",
+      KeyType::G2 => "g2",
+      KeyType::BlindedG1 => "blinded_g1",
+      KeyType::BlindedG2 => "blinded_g2",
+    }
+  }
+
+  pub fn public_key_size(self) -> usize {
+    match self {
+      KeyType::G1 | KeyType::BlindedG1 => G1_COMPRESSED_SIZE,
+      KeyType::G2 | KeyType::BlindedG2 => G2_COMPRESSED_SIZE,
+    }
+  }
+
+  /// Blinded keys hold a blinding factor alongside the secret key
+  pub fn is_blinded(self) -> bool {
+    matches!(self, KeyType::BlindedG1 | KeyType::BlindedG2)
+  }
+
+  /// Size of the encrypted secret material
+  pub fn secret_size(self) -> usize {
+    if self.is_blinded() { 2 * FR_COMPRESSED_SIZE } else { FR_COMPRESSED_SIZE }
+  }
+}
+
+/// Password based key derivation parameters
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KdfParams {
+  pub name: String,
+  pub salt: String,
+  pub m_cost: u32,
+  pub t_cost: u32,
+  pub p_cost: u32,
+}
+
+/// Authenticated encryption parameters
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CipherParams {
+  pub name: String,
+  pub nonce: String,
+}
+
+/// Self describing, password encrypted export of a BLS secret key
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EncryptedKey {
+  pub version: u64,
+  pub key_type: String,
+  pub public_key: String,
+  pub kdf: KdfParams,
+  pub cipher: CipherParams,
+  pub ciphertext: String,
+}
+
+impl JsonSchema for EncryptedKey {
+  fn json_schema() -> Value {
+    let kdf = object_schema("Password based key derivation parameters", vec![
+      ("name", String::json_schema(), false),
+      ("salt", String::json_schema(), false),
+      ("m_cost", u32::json_schema(), false),
+      ("t_cost", u32::json_schema(), false),
+      ("p_cost", u32::json_schema(), false),
+    ]);
+    let cipher = object_schema("Authenticated encryption parameters", vec![
+      ("name", String::json_schema(), false),
+      ("nonce", String::json_schema(), false),
+    ]);
+    object_schema("Password encrypted export of a BLS secret key", vec![
+      ("version", u64::json_schema(), false),
+      ("key_type", String::json_schema(), false),
+      ("public_key", String::json_schema(), false),
+      ("kdf", kdf, false),
+      ("cipher", cipher, false),
+      ("ciphertext", String::json_schema(), false),
+    ])
+  }
+}
+
+/// Encrypt `secret` (the secret key, followed by the blinding factor for blinded keys) under `password`
+pub fn encrypt_key<R: RngCore + CryptoRng>(
+  key_type: KeyType,
+  public_key: &[u8],
+  secret: &[u8],
+  password: &[u8],
+  rng: &mut R,
+) -> Result<EncryptedKey, BBSError> {
+  if public_key.len() != key_type.public_key_size() {
+    handle_err!(format!("Invalid length for: 'public_key', expected {} bytes", key_type.public_key_size()));
+  }
+  if secret.len() != key_type.secret_size() {
+    handle_err!("Invalid length of secret key material");
+  }
+
+  let mut salt = [0u8; SALT_SIZE];
+  let mut nonce = [0u8; NONCE_SIZE];
+  rng.fill_bytes(&mut salt);
+  rng.fill_bytes(&mut nonce);
+
+  let mut encrypted_key = EncryptedKey {
+    version: ENCRYPTED_KEY_VERSION,
+    key_type: key_type.name().to_string(),
+    public_key: base64::encode(public_key),
+    kdf: KdfParams {
+      name: KDF_ARGON2ID.to_string(),
+      salt: base64::encode(salt),
+      m_cost: ARGON2_M_COST,
+      t_cost: ARGON2_T_COST,
+      p_cost: ARGON2_P_COST,
+    },
+    cipher: CipherParams {
+      name: CIPHER_CHACHA20_POLY1305.to_string(),
+      nonce: base64::encode(nonce),
+    },
+    ciphertext: String::new(),
+  };
+
+  let key = derive_key(&encrypted_key.kdf, &salt, password)?;
+  let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_slice()));
+  let aad = associated_data(&encrypted_key);
+  let ciphertext = match cipher.encrypt(Nonce::from_slice(&nonce), Payload { msg: secret, aad: aad.as_bytes() }) {
+    Ok(ciphertext) => ciphertext,
+    Err(_) => { handle_err!("Failed to encrypt key"); }
+  };
+  encrypted_key.ciphertext = base64::encode(ciphertext);
+
+  Ok(encrypted_key)
+}
+
+/// Decrypt an encrypted key export with `password`, returning its key type and secret material
+pub fn decrypt_key(
+  encrypted_key: &EncryptedKey,
+  password: &[u8],
+) -> Result<(KeyType, Zeroizing<Vec<u8>>), BBSError> {
+  if encrypted_key.version != ENCRYPTED_KEY_VERSION {
+    handle_err!(format!("Unsupported encrypted key version: {}", encrypted_key.version));
+  }
+  let key_type = match KeyType::from_name(&encrypted_key.key_type) {
+    Some(key_type) => key_type,
+    None => { handle_err!(format!("Unsupported key type: {}", encrypted_key.key_type)); }
+  };
+  match base64::decode(&encrypted_key.public_key) {
+    Ok(public_key) if public_key.len() == key_type.public_key_size() => (),
+    _ => { handle_err!("Invalid encrypted key: 'public_key'"); }
+  }
+  if encrypted_key.kdf.name != KDF_ARGON2ID {
+    handle_err!(format!("Unsupported key derivation function: {}", encrypted_key.kdf.name));
+  }
+  if encrypted_key.cipher.name != CIPHER_CHACHA20_POLY1305 {
+    handle_err!(format!("Unsupported cipher: {}", encrypted_key.cipher.name));
+  }
+  if encrypted_key.kdf.m_cost > ARGON2_MAX_M_COST
+    || encrypted_key.kdf.t_cost > ARGON2_MAX_T_COST
+    || encrypted_key.kdf.p_cost > ARGON2_MAX_P_COST {
+    handle_err!("Key derivation parameters exceed supported limits");
+  }
+
+  let salt = match base64::decode(&encrypted_key.kdf.salt) {
+    Ok(salt) => salt,
+    Err(_) => { handle_err!("Failed decoding base64 for: 'kdf.salt'"); }
+  };
+  let nonce = match base64::decode(&encrypted_key.cipher.nonce) {
+    Ok(nonce) if nonce.len() == NONCE_SIZE => nonce,
+    _ => { handle_err!("Invalid encrypted key: 'cipher.nonce'"); }
+  };
+  let ciphertext = match base64::decode(&encrypted_key.ciphertext) {
+    Ok(ciphertext) => ciphertext,
+    Err(_) => { handle_err!("Failed decoding base64 for: 'ciphertext'"); }
+  };
+
+  let key = derive_key(&encrypted_key.kdf, &salt, password)?;
+  let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_slice()));
+  let aad = associated_data(encrypted_key);
+  let secret = match cipher.decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: aad.as_bytes() }) {
+    Ok(secret) => Zeroizing::new(secret),
+    Err(_) => { handle_err!("Failed to decrypt key: incorrect password or corrupted data"); }
+  };
+  if secret.len() != key_type.secret_size() {
+    handle_err!("Invalid length of secret key material");
+  }
+
+  Ok((key_type, secret))
+}
+
+fn derive_key(kdf: &KdfParams, salt: &[u8], password: &[u8]) -> Result<Zeroizing<[u8; KEY_SIZE]>, BBSError> {
+  let params = match Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(KEY_SIZE)) {
+    Ok(params) => params,
+    Err(_) => { handle_err!("Invalid key derivation parameters"); }
+  };
+
+  let mut key = Zeroizing::new([0u8; KEY_SIZE]);
+  if Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+    .hash_password_into(password, salt, key.as_mut())
+    .is_err() {
+    handle_err!("Failed to derive key from password");
+  }
+
+  Ok(key)
+}
+
+/// Authenticate the unencrypted properties, so they can't be swapped onto another key's ciphertext
+fn associated_data(encrypted_key: &EncryptedKey) -> String {
+  format!("{}|{}|{}", encrypted_key.version, encrypted_key.key_type, encrypted_key.public_key)
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use bbs_plus::prelude::*;
+use bbs_plus::errors::BBSError;
+use rand::{thread_rng, Rng};
+use super::bbs_plus_backend::deterministic_public_key;
+use super::curve::DefaultBackend;
+
+/// Handles are below 2^53, so they survive JSON numbers in JavaScript
+const KEY_HANDLE_LIMIT: u64 = 1 << 53;
+
+/// Secret key held in the keystore, along with its BLS public key. The secret key is boxed so it
+/// stays put as the keystore grows, and is only ever lent out
+pub struct StoredKey {
+  pub secret_key: Box<SecretKey>,
+  pub public_key: DeterministicPublicKey,
+}
+
+lazy_static! {
+  static ref KEYSTORE: Mutex<HashMap<u64, StoredKey>> = Mutex::new(HashMap::new());
+}
+
+/// Store a secret key, returning the opaque handle used to reference it
+pub fn insert_key(secret_key: SecretKey) -> Result<u64, BBSError> {
+  let public_key = deterministic_public_key::<DefaultBackend>(&secret_key)?;
+  let secret_key = Box::new(secret_key);
+
+  let mut keystore = lock_keystore();
+  // handles are random, so they can't be guessed from one another
+  let key_handle = loop {
+    let key_handle = thread_rng().gen_range(1, KEY_HANDLE_LIMIT);
+    if !keystore.contains_key(&key_handle) {
+      break key_handle;
+    }
+  };
+  keystore.insert(key_handle, StoredKey { secret_key, public_key });
+
+  Ok(key_handle)
+}
+
+/// Run `f` with the key stored under `key_handle`, which is only borrowed. The keystore is locked
+/// meanwhile, so the key can't be removed whilst in use
+pub fn with_key<T>(key_handle: u64, f: impl FnOnce(&StoredKey) -> T) -> Option<T> {
+  lock_keystore().get(&key_handle).map(f)
+}
+
+/// Look up the BLS public key of the key stored under `key_handle`
+pub fn get_public_key(key_handle: u64) -> Option<DeterministicPublicKey> {
+  with_key(key_handle, |stored_key| stored_key.public_key)
+}
+
+/// Remove the key stored under `key_handle`, returning whether it existed.
+/// The secret key is wiped as it is dropped
+pub fn remove_key(key_handle: u64) -> bool {
+  lock_keystore().remove(&key_handle).is_some()
+}
+
+fn lock_keystore() -> std::sync::MutexGuard<'static, HashMap<u64, StoredKey>> {
+  // a panic whilst holding the lock can't leave the keystore inconsistent, so recover from poisoning
+  match KEYSTORE.lock() {
+    Ok(keystore) => keystore,
+    Err(poisoned) => poisoned.into_inner(),
+  }
+}
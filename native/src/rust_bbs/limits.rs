@@ -0,0 +1,141 @@
+use std::convert::TryFrom;
+use std::sync::RwLock;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Starts the message of every error from exceeding a limit, and of no other error, so wrappers
+/// can give them their own code
+pub const LIMIT_EXCEEDED: &str = "Limit exceeded: ";
+
+/// Error code wrappers return for calls that exceed a limit
+pub const LIMIT_EXCEEDED_CODE: &str = "limit_exceeded";
+
+/// Properties holding messages, or claims which are signed as messages
+const MESSAGE_PROPERTIES: &[&str] = &["claims", "messages", "payloads"];
+
+/// Properties counting the messages a public key is derived for
+const MESSAGE_COUNT_PROPERTIES: &[&str] = &["blinded_message_count", "known_message_count", "message_count"];
+
+/// Properties listing message indices, or claim names
+const INDEX_PROPERTIES: &[&str] = &["blinded", "known", "mandatory_indexes", "reveal", "revealed", "selective_indexes"];
+
+/// Bounds on the input of a single call, checked before any expensive work starts
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Limits {
+  /// Most messages signed, proven or verified, and so the most generators derived for a public key
+  pub max_message_count: usize,
+  /// Most bytes in a single message
+  pub max_message_size: usize,
+  /// Most bytes in a wrapper's JSON or CBOR context
+  pub max_context_size: usize,
+  /// Most items in a batch, e.g. the message counts warmed by `bls_public_key_cache_warm`
+  pub max_batch_size: usize,
+}
+
+impl Default for Limits {
+  fn default() -> Limits {
+    Limits {
+      max_message_count: 1024,
+      max_message_size: 64 * 1024,
+      max_context_size: 4 * 1024 * 1024,
+      max_batch_size: 64,
+    }
+  }
+}
+
+lazy_static! {
+  static ref LIMITS: RwLock<Limits> = RwLock::new(Limits::default());
+}
+
+/// The limits currently in effect
+pub fn limits() -> Limits {
+  // the limits are plain values, so a panic elsewhere can't leave them inconsistent
+  match LIMITS.read() {
+    Ok(limits) => *limits,
+    Err(poisoned) => *poisoned.into_inner(),
+  }
+}
+
+/// Replace the limits of every later call
+pub fn set_limits(new_limits: Limits) {
+  match LIMITS.write() {
+    Ok(mut limits) => *limits = new_limits,
+    Err(poisoned) => *poisoned.into_inner() = new_limits,
+  }
+}
+
+/// Check a count or size against its limit
+pub fn check_limit(what: &str, value: usize, limit: usize) -> Result<(), BBSError> {
+  if value > limit {
+    handle_err!(format!("{}{} is {}, more than the limit of {}", LIMIT_EXCEEDED, what, value, limit));
+  }
+  Ok(())
+}
+
+pub fn check_message_count(message_count: usize) -> Result<(), BBSError> {
+  check_limit("message count", message_count, limits().max_message_count)
+}
+
+pub fn check_context_size(context_size: usize) -> Result<(), BBSError> {
+  check_limit("context size", context_size, limits().max_context_size)
+}
+
+/// Check messages read from inside a context property, e.g. the payloads of a 'jwp' or the
+/// statements of a 'document', against the limits on a context's own messages
+pub fn check_messages<I>(what: &str, message_sizes: I) -> Result<(), BBSError>
+where
+  I: IntoIterator<Item = usize>,
+  I::IntoIter: ExactSizeIterator,
+{
+  let limits = limits();
+  let message_sizes = message_sizes.into_iter();
+  check_limit(&format!("{} count", what), message_sizes.len(), limits.max_message_count)?;
+  for message_size in message_sizes {
+    check_limit(&format!("{} size", what), message_size, limits.max_message_size)?;
+  }
+  Ok(())
+}
+
+/// Check the messages, message counts and batches of a context against the limits
+pub fn check_context_limits(context_json: &Value) -> Result<(), BBSError> {
+  let limits = limits();
+
+  for property in MESSAGE_PROPERTIES {
+    let messages: Vec<&Value> = match &context_json[property] {
+      Value::Array(messages) => messages.iter().collect(),
+      Value::Object(claims) => claims.values().collect(),
+      _ => continue,
+    };
+    check_limit(&format!("'{}' count", property), messages.len(), limits.max_message_count)?;
+    for message in messages.into_iter().filter_map(Value::as_str) {
+      check_limit(&format!("'{}' message size", property), decoded_size(message), limits.max_message_size)?;
+    }
+  }
+
+  for property in MESSAGE_COUNT_PROPERTIES {
+    if let Some(message_count) = context_json[property].as_u64() {
+      check_limit(&format!("'{}'", property), usize::try_from(message_count).unwrap_or(usize::MAX), limits.max_message_count)?;
+    }
+  }
+
+  for property in INDEX_PROPERTIES {
+    if let Some(indexes) = context_json[property].as_array() {
+      check_limit(&format!("'{}' count", property), indexes.len(), limits.max_message_count)?;
+    }
+  }
+
+  if let Some(message_counts) = context_json["message_counts"].as_array() {
+    check_limit("'message_counts' batch size", message_counts.len(), limits.max_batch_size)?;
+    for message_count in message_counts.iter().filter_map(Value::as_u64) {
+      check_limit("'message_counts' message count", usize::try_from(message_count).unwrap_or(usize::MAX), limits.max_message_count)?;
+    }
+  }
+
+  Ok(())
+}
+
+/// Bytes held by a base64 string, without decoding it
+fn decoded_size(base64: &str) -> usize {
+  base64.trim_end_matches('=').len() * 3 / 4
+}
@@ -0,0 +1,96 @@
+use std::convert::TryFrom;
+use bbs_plus::errors::BBSError;
+use bbs_plus::prelude::*;
+use super::varint::{encode_varint, decode_varint};
+
+/// Multicodec code for a compressed BLS12-381 G1 public key
+pub const MULTICODEC_BLS12_381_G1_PUB: u64 = 0xea;
+/// Multicodec code for a compressed BLS12-381 G2 public key
+pub const MULTICODEC_BLS12_381_G2_PUB: u64 = 0xeb;
+
+const MULTIBASE_BASE58BTC: char = 'z';
+const DID_KEY_PREFIX: &str = "did:key:";
+
+/// Encode a compressed G1 or G2 public key as a base58btc multibase Multikey
+pub fn encode_multikey(public_key: &[u8]) -> Result<String, BBSError> {
+  let code = match public_key.len() {
+    G1_COMPRESSED_SIZE => MULTICODEC_BLS12_381_G1_PUB,
+    G2_COMPRESSED_SIZE => MULTICODEC_BLS12_381_G2_PUB,
+    _ => { handle_err!(format!("Invalid length for: 'public_key', expected {} or {} bytes", G1_COMPRESSED_SIZE, G2_COMPRESSED_SIZE)); }
+  };
+
+  let mut multikey = encode_varint(code);
+  multikey.extend_from_slice(public_key);
+
+  Ok(format!("{}{}", MULTIBASE_BASE58BTC, bs58::encode(multikey).into_string()))
+}
+
+/// Decode a base58btc multibase Multikey, returning its multicodec code and compressed public key
+pub fn decode_multikey(multikey: &str) -> Result<(u64, Vec<u8>), BBSError> {
+  let encoded = match multikey.strip_prefix(MULTIBASE_BASE58BTC) {
+    Some(encoded) => encoded,
+    None => { handle_err!("Unsupported multibase encoding, expected base58btc ('z')"); }
+  };
+  let decoded = match bs58::decode(encoded).into_vec() {
+    Ok(decoded) => decoded,
+    Err(_) => { handle_err!("Failed decoding base58btc for Multikey"); }
+  };
+
+  let (code, public_key) = match decode_varint(&decoded) {
+    Some((code, length)) => (code, &decoded[length..]),
+    None => { handle_err!("Invalid multicodec prefix for Multikey"); }
+  };
+
+  let expected_size = match code {
+    MULTICODEC_BLS12_381_G1_PUB => G1_COMPRESSED_SIZE,
+    MULTICODEC_BLS12_381_G2_PUB => G2_COMPRESSED_SIZE,
+    _ => { handle_err!(format!("Unsupported multicodec for Multikey: 0x{:x}", code)); }
+  };
+  if public_key.len() != expected_size {
+    handle_err!(format!("Invalid Multikey public key length, expected {} bytes", expected_size));
+  }
+
+  Ok((code, public_key.to_vec()))
+}
+
+/// `did:key` for a compressed G2 public key
+pub fn encode_did_key(public_key: &[u8]) -> Result<String, BBSError> {
+  if public_key.len() != G2_COMPRESSED_SIZE {
+    handle_err!(format!("Invalid length for: 'public_key', expected {} byte G2 key", G2_COMPRESSED_SIZE));
+  }
+  Ok(format!("{}{}", DID_KEY_PREFIX, encode_multikey(public_key)?))
+}
+
+pub fn is_did_key(value: &str) -> bool {
+  value.starts_with(DID_KEY_PREFIX)
+}
+
+/// Resolve a `did:key` (or one of its verification method IDs) locally to its
+/// DID, Multikey and `DeterministicPublicKey`
+pub fn resolve_did_key(did_url: &str) -> Result<(String, String, DeterministicPublicKey), BBSError> {
+  let (did, fragment) = match did_url.split_once('#') {
+    Some((did, fragment)) => (did, Some(fragment)),
+    None => (did_url, None),
+  };
+  let multikey = match did.strip_prefix(DID_KEY_PREFIX) {
+    Some(multikey) => multikey,
+    None => { handle_err!("Not a did:key"); }
+  };
+  if let Some(fragment) = fragment {
+    if fragment != multikey {
+      handle_err!("did:key fragment does not match its key");
+    }
+  }
+
+  let (code, public_key) = decode_multikey(multikey)?;
+  if code != MULTICODEC_BLS12_381_G2_PUB {
+    handle_err!("did:key is not a BLS12-381 G2 key");
+  }
+
+  let dpk = match DeterministicPublicKey::try_from(public_key) {
+    Ok(dpk) => dpk,
+    Err(_) => { handle_err!("Invalid did:key public key"); }
+  };
+
+  Ok((did.to_string(), multikey.to_string(), dpk))
+}
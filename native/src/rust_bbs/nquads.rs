@@ -0,0 +1,306 @@
+use std::fmt;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+
+/// RDF term of a quad
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Term {
+  Iri(String),
+  /// Blank node, by its label without the `_:` prefix
+  BlankNode(String),
+  Literal {
+    value: String,
+    /// Datatype IRI, `None` for `xsd:string` and language-tagged strings
+    datatype: Option<String>,
+    language: Option<String>,
+  },
+}
+
+impl Term {
+  pub fn blank_node(&self) -> Option<&str> {
+    match self {
+      Term::BlankNode(label) => Some(label),
+      _ => None,
+    }
+  }
+}
+
+/// Quad, with `graph` `None` in the default graph
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quad {
+  pub subject: Term,
+  pub predicate: Term,
+  pub object: Term,
+  pub graph: Option<Term>,
+}
+
+impl Quad {
+  /// Copy with each blank node relabeled by `relabel`
+  pub fn relabel<F: Fn(&str) -> String>(&self, relabel: F) -> Quad {
+    let term = |term: &Term| match term {
+      Term::BlankNode(label) => Term::BlankNode(relabel(label)),
+      term => term.clone(),
+    };
+    Quad {
+      subject: term(&self.subject),
+      predicate: self.predicate.clone(),
+      object: term(&self.object),
+      graph: self.graph.as_ref().map(term),
+    }
+  }
+
+  /// Blank nodes of the subject, object and graph, in that order
+  pub fn blank_nodes(&self) -> impl Iterator<Item = &str> {
+    Some(&self.subject).into_iter()
+      .chain(Some(&self.object))
+      .chain(self.graph.as_ref())
+      .filter_map(Term::blank_node)
+  }
+}
+
+impl fmt::Display for Term {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Term::Iri(iri) => write!(f, "<{}>", iri),
+      Term::BlankNode(label) => write!(f, "_:{}", label),
+      Term::Literal { value, datatype, language } => {
+        write!(f, "\"")?;
+        // canonical N-Quads escape only these characters
+        for c in value.chars() {
+          match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            c => write!(f, "{}", c)?,
+          }
+        }
+        write!(f, "\"")?;
+        match (language, datatype) {
+          (Some(language), _) => write!(f, "@{}", language),
+          (None, Some(datatype)) => write!(f, "^^<{}>", datatype),
+          (None, None) => Ok(()),
+        }
+      },
+    }
+  }
+}
+
+/// Canonical N-Quads statement, terminated by a newline
+impl fmt::Display for Quad {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} {} {} ", self.subject, self.predicate, self.object)?;
+    if let Some(graph) = &self.graph {
+      write!(f, "{} ", graph)?;
+    }
+    writeln!(f, ".")
+  }
+}
+
+/// Parse an N-Quads document, one statement per line
+pub fn parse_nquads(document: &str) -> Result<Vec<Quad>, BBSError> {
+  let mut quads = Vec::new();
+
+  for (number, line) in document.lines().enumerate() {
+    let mut parser = Parser { input: line, position: 0 };
+    parser.skip_whitespace();
+    if parser.at_end_of_statement() {
+      continue;
+    }
+
+    match parser.statement() {
+      Ok(quad) => quads.push(quad),
+      Err(error) => { handle_err!(format!("Invalid N-Quads on line {}: {}", number + 1, error)); }
+    }
+  }
+
+  Ok(quads)
+}
+
+struct Parser<'a> {
+  input: &'a str,
+  position: usize,
+}
+
+impl<'a> Parser<'a> {
+  fn rest(&self) -> &'a str {
+    &self.input[self.position..]
+  }
+
+  fn peek(&self) -> Option<char> {
+    self.rest().chars().next()
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+    self.position += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+  }
+
+  fn at_end_of_statement(&self) -> bool {
+    matches!(self.peek(), None | Some('#'))
+  }
+
+  fn expect(&mut self, c: char) -> Result<(), String> {
+    match self.peek() {
+      Some(next) if next == c => {
+        self.position += c.len_utf8();
+        Ok(())
+      },
+      _ => Err(format!("expected '{}' at column {}", c, self.position + 1)),
+    }
+  }
+
+  fn statement(&mut self) -> Result<Quad, String> {
+    let subject = match self.term()? {
+      term @ Term::Literal { .. } => return Err(format!("literal subject {}", term)),
+      term => term,
+    };
+    self.skip_whitespace();
+    let predicate = match self.term()? {
+      term @ Term::Iri(_) => term,
+      term => return Err(format!("predicate {} isn't an IRI", term)),
+    };
+    self.skip_whitespace();
+    let object = self.term()?;
+    self.skip_whitespace();
+
+    let graph = match self.peek() {
+      Some('.') => None,
+      _ => match self.term()? {
+        term @ Term::Literal { .. } => return Err(format!("literal graph {}", term)),
+        term => Some(term),
+      },
+    };
+    self.skip_whitespace();
+    self.expect('.')?;
+    self.skip_whitespace();
+    if !self.at_end_of_statement() {
+      return Err(format!("unexpected '{}' after statement", self.rest()));
+    }
+
+    Ok(Quad { subject, predicate, object, graph })
+  }
+
+  fn term(&mut self) -> Result<Term, String> {
+    match self.peek() {
+      Some('<') => Ok(Term::Iri(self.iri()?)),
+      Some('_') => self.blank_node(),
+      Some('"') => self.literal(),
+      _ => Err(format!("expected a term at column {}", self.position + 1)),
+    }
+  }
+
+  fn iri(&mut self) -> Result<String, String> {
+    self.expect('<')?;
+    let mut iri = String::new();
+    loop {
+      match self.next_char()? {
+        '>' => return Ok(iri),
+        '\\' => iri.push(self.unicode_escape()?),
+        c if c <= ' ' || "<\"{}|^`".contains(c) => return Err(format!("invalid IRI character {:?}", c)),
+        c => iri.push(c),
+      }
+    }
+  }
+
+  fn blank_node(&mut self) -> Result<Term, String> {
+    self.expect('_')?;
+    self.expect(':')?;
+    let rest = self.rest();
+    let end = rest.find(|c: char| c.is_whitespace() || "<\"#".contains(c)).unwrap_or(rest.len());
+    // a label can't end with '.', which is the end of the statement
+    let label = rest[..end].trim_end_matches('.');
+    let valid = label.chars().next().is_some_and(|c| c.is_alphanumeric() || c == '_')
+      && label.chars().all(|c| c.is_alphanumeric() || "_-.\u{b7}".contains(c));
+    if !valid {
+      return Err(format!("invalid blank node label '{}'", label));
+    }
+    self.position += label.len();
+    Ok(Term::BlankNode(label.to_string()))
+  }
+
+  fn literal(&mut self) -> Result<Term, String> {
+    self.expect('"')?;
+    let mut value = String::new();
+    loop {
+      match self.next_char()? {
+        '"' => break,
+        '\\' if matches!(self.peek(), Some('u') | Some('U')) => value.push(self.unicode_escape()?),
+        '\\' => match self.next_char()? {
+          't' => value.push('\t'),
+          'b' => value.push('\u{8}'),
+          'n' => value.push('\n'),
+          'r' => value.push('\r'),
+          'f' => value.push('\u{c}'),
+          '"' => value.push('"'),
+          '\'' => value.push('\''),
+          '\\' => value.push('\\'),
+          c => return Err(format!("invalid escape '\\{}'", c)),
+        },
+        '\n' | '\r' => return Err("unescaped line break in literal".to_string()),
+        c => value.push(c),
+      }
+    }
+
+    let (mut datatype, mut language) = (None, None);
+    match self.peek() {
+      Some('@') => {
+        self.position += 1;
+        let rest = self.rest();
+        let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-')).unwrap_or(rest.len());
+        if end == 0 {
+          return Err("empty language tag".to_string());
+        }
+        language = Some(rest[..end].to_string());
+        self.position += end;
+      },
+      Some('^') => {
+        self.expect('^')?;
+        self.expect('^')?;
+        let iri = self.iri()?;
+        if iri == RDF_LANG_STRING {
+          return Err("rdf:langString literal without a language tag".to_string());
+        }
+        if iri != XSD_STRING {
+          datatype = Some(iri);
+        }
+      },
+      _ => {},
+    }
+
+    Ok(Term::Literal { value, datatype, language })
+  }
+
+  fn next_char(&mut self) -> Result<char, String> {
+    match self.peek() {
+      Some(c) => {
+        self.position += c.len_utf8();
+        Ok(c)
+      },
+      None => Err("unexpected end of line".to_string()),
+    }
+  }
+
+  /// `\uXXXX` or `\UXXXXXXXX`, with the backslash already read
+  fn unicode_escape(&mut self) -> Result<char, String> {
+    let length = match self.next_char()? {
+      'u' => 4,
+      'U' => 8,
+      c => return Err(format!("invalid escape '\\{}'", c)),
+    };
+    let rest = self.rest();
+    let code = rest.get(..length)
+      .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+      .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+      .and_then(char::from_u32);
+    match code {
+      Some(c) => {
+        self.position += length;
+        Ok(c)
+      },
+      None => Err("invalid unicode escape".to_string()),
+    }
+  }
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use super::json_schema::{JsonSchema, object_schema};
+use super::cose::{decode_presentation, is_cose_sign1};
+use super::document::MessageFormat;
+
+/// Version of the presentation format written by `create_presentation`
+pub const PRESENTATION_VERSION: u64 = 1;
+
+/// Proof bundled with the messages it reveals, so it can be verified without any other context
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Presentation {
+  pub version: u64,
+  pub proof: String,
+  /// Revealed messages (base64) by their index in the signed messages
+  pub revealed_messages: BTreeMap<usize, String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub nonce: Option<String>,
+  /// ID of the signer's public key, e.g. a did:key key ID
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub key_id: Option<String>,
+  /// Set where the messages were flattened from a document, which the verifier can rebuild
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub message_format: Option<MessageFormat>,
+}
+
+impl JsonSchema for Presentation {
+  fn json_schema() -> Value {
+    object_schema("Proof bundled with the messages it reveals", vec![
+      ("version", u64::json_schema(), false),
+      ("proof", String::json_schema(), false),
+      ("revealed_messages", BTreeMap::<usize, String>::json_schema(), false),
+      ("nonce", String::json_schema(), true),
+      ("key_id", String::json_schema(), true),
+      ("message_format", MessageFormat::json_schema(), true),
+    ])
+  }
+}
+
+/// Bundle a proof with the `revealed` of the signed `messages`, and the nonce it's bound to
+pub fn create_presentation(
+  messages: &[String],
+  revealed: &[usize],
+  nonce: Option<String>,
+  proof: String,
+  key_id: Option<String>,
+  message_format: Option<MessageFormat>,
+) -> Result<Presentation, BBSError> {
+  let mut revealed_messages = BTreeMap::new();
+  for index in revealed {
+    match messages.get(*index) {
+      Some(message) => { revealed_messages.insert(*index, message.clone()); },
+      None => { handle_err!("Invalid index for 'revealed'"); }
+    }
+  }
+
+  Ok(Presentation {
+    version: PRESENTATION_VERSION,
+    proof,
+    revealed_messages,
+    nonce,
+    key_id,
+    message_format,
+  })
+}
+
+/// Read a presentation given as a JSON object, a JSON string or a base64 COSE envelope
+pub fn parse_presentation(presentation: &Value) -> Result<Presentation, BBSError> {
+  let parsed = match presentation {
+    Value::String(presentation) => match base64::decode(presentation) {
+      Ok(envelope) if is_cose_sign1(&envelope) => return decode_presentation(&envelope),
+      _ => serde_json::from_str::<Presentation>(presentation),
+    },
+    Value::Object(_) => serde_json::from_value::<Presentation>(presentation.clone()),
+    Value::Null => { handle_err!("Property not set: 'presentation'"); },
+    _ => { handle_err!("Invalid presentation"); }
+  };
+
+  match parsed {
+    Ok(presentation) if presentation.version == PRESENTATION_VERSION => Ok(presentation),
+    Ok(presentation) => { handle_err!(format!("Unsupported presentation version: {}", presentation.version)); },
+    Err(error) => { handle_err!(format!("Invalid presentation: {}", error)); }
+  }
+}
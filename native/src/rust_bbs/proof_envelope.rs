@@ -0,0 +1,187 @@
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use super::bbs_plus_backend::SignatureProof;
+use super::curve::CurveBackend;
+use super::limits::check_message_count;
+use super::varint::{encode_varint, decode_varint};
+
+/// Marks a versioned proof envelope. Legacy proofs start with a big-endian `u16` message count instead
+const PROOF_MAGIC: &[u8; 4] = b"BBSP";
+
+/// Unversioned `u16 message count + bitvector + proof` layout, produced before envelopes were added
+pub const PROOF_VERSION_LEGACY: u8 = 0;
+/// Envelope holding the ciphersuite, varint message count and an exactly sized revealed bitmap
+pub const PROOF_VERSION_2: u8 = 2;
+/// Version written by `encode_proof`
+pub const PROOF_VERSION: u8 = PROOF_VERSION_2;
+/// Versions read by `decode_proof`
+pub const PROOF_VERSIONS: &[u8] = &[PROOF_VERSION_LEGACY, PROOF_VERSION_2];
+
+/// Ciphersuite a proof was created with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ciphersuite {
+  /// BBS+ over BLS12-381 (G2 public keys), with messages and challenges hashed by Blake2b
+  Bls12381G2Blake2b,
+}
+
+impl Ciphersuite {
+  /// Every ciphersuite proofs can be created and verified with
+  pub const ALL: &'static [Ciphersuite] = &[Ciphersuite::Bls12381G2Blake2b];
+
+  pub fn from_id(id: u8) -> Option<Ciphersuite> {
+    match id {
+      1 => Some(Ciphersuite::Bls12381G2Blake2b),
+      _ => None,
+    }
+  }
+
+  pub fn id(self) -> u8 {
+    match self {
+      Ciphersuite::Bls12381G2Blake2b => 1,
+    }
+  }
+
+  pub fn name(self) -> &'static str {
+    match self {
+      Ciphersuite::Bls12381G2Blake2b => "BBS+_BLS12381G2_BLAKE2B",
+    }
+  }
+}
+
+/// Decoded proof, borrowing the serialized proof of knowledge of the signature from the encoded bytes
+pub struct ProofEnvelope<'a> {
+  pub version: u8,
+  pub ciphersuite: Ciphersuite,
+  pub message_count: usize,
+  pub revealed: BTreeSet<usize>,
+  pub proof: &'a [u8],
+}
+
+impl ProofEnvelope<'_> {
+  /// The proof of knowledge of the signature
+  pub fn signature_proof<B: CurveBackend>(&self) -> Result<SignatureProof<B>, BBSError> {
+    SignatureProof::from_bytes(self.proof)
+  }
+}
+
+/// Wrap a serialized proof of knowledge of a signature in an envelope of the current version
+pub fn encode_proof(
+  ciphersuite: Ciphersuite,
+  message_count: usize,
+  revealed: &BTreeSet<usize>,
+  proof: &[u8],
+) -> Result<Vec<u8>, BBSError> {
+  let mut bytes = PROOF_MAGIC.to_vec();
+  bytes.push(PROOF_VERSION);
+  bytes.push(ciphersuite.id());
+  bytes.append(&mut encode_varint(message_count as u64));
+  bytes.append(&mut revealed_to_bitmap(message_count, revealed)?);
+  bytes.extend_from_slice(proof);
+  Ok(bytes)
+}
+
+/// Decode a versioned proof envelope, or a legacy proof
+pub fn decode_proof(bytes: &[u8]) -> Result<ProofEnvelope<'_>, BBSError> {
+  let (version, ciphersuite, body) = match bytes.strip_prefix(PROOF_MAGIC) {
+    Some([version, ciphersuite, body @ ..]) => {
+      let ciphersuite = match Ciphersuite::from_id(*ciphersuite) {
+        Some(ciphersuite) => ciphersuite,
+        None => { handle_err!(format!("Unsupported proof ciphersuite: {}", ciphersuite)); }
+      };
+      (*version, ciphersuite, body)
+    },
+    Some(_) => { handle_err!("Invalid proof: truncated header"); },
+    // legacy proofs were only ever created with the Blake2b BBS+ ciphersuite
+    None => (PROOF_VERSION_LEGACY, Ciphersuite::Bls12381G2Blake2b, bytes),
+  };
+
+  let (message_count, revealed, proof) = match version {
+    PROOF_VERSION_LEGACY => decode_legacy_revealed(body)?,
+    PROOF_VERSION_2 => decode_revealed(body)?,
+    _ => { handle_err!(format!("Unsupported proof version: {}", version)); }
+  };
+  // verifiers size their work, and BLS keys their generators, by the proof's message count
+  check_message_count(message_count)?;
+
+  Ok(ProofEnvelope {
+    version,
+    ciphersuite,
+    message_count,
+    revealed,
+    proof,
+  })
+}
+
+/// Decode a varint message count and exactly sized bitmap, returning them with the remaining bytes
+fn decode_revealed(body: &[u8]) -> Result<(usize, BTreeSet<usize>, &[u8]), BBSError> {
+  let (message_count, offset) = match decode_varint(body) {
+    Some((message_count, offset)) => match usize::try_from(message_count) {
+      Ok(message_count) => (message_count, offset),
+      Err(_) => { handle_err!("Invalid proof: message count too large"); }
+    },
+    None => { handle_err!("Invalid proof: malformed message count"); }
+  };
+
+  let bitmap = &body[offset..];
+  let bitmap_length = message_count.div_ceil(8);
+  if bitmap.len() < bitmap_length {
+    handle_err!("Invalid proof: truncated revealed messages");
+  }
+
+  let revealed = bitmap_to_revealed(&bitmap[..bitmap_length], message_count)?;
+  Ok((message_count, revealed, &bitmap[bitmap_length..]))
+}
+
+/// Decode the `u16` message count and big-endian bitvector used by legacy proofs, which holds
+/// one more byte than needed when the message count is a multiple of 8
+fn decode_legacy_revealed(body: &[u8]) -> Result<(usize, BTreeSet<usize>, &[u8]), BBSError> {
+  if body.len() < 2 {
+    handle_err!("Invalid proof: truncated header");
+  }
+  let message_count = u16::from_be_bytes([body[0], body[1]]) as usize;
+  let offset = 2 + (message_count / 8) + 1;
+  if body.len() < offset {
+    handle_err!("Invalid proof: truncated revealed messages");
+  }
+
+  let mut bitmap = body[2..offset].to_vec();
+  bitmap.reverse();
+
+  let revealed = bitmap_to_revealed(&bitmap, message_count)?;
+  Ok((message_count, revealed, &body[offset..]))
+}
+
+/// Bitmap with bit `i % 8` of byte `i / 8` set for each revealed message index `i`
+fn revealed_to_bitmap(message_count: usize, revealed: &BTreeSet<usize>) -> Result<Vec<u8>, BBSError> {
+  let mut bitmap = vec![0u8; message_count.div_ceil(8)];
+
+  for index in revealed {
+    if *index >= message_count {
+      handle_err!(format!("Revealed message index {} is out of bounds for {} messages", index, message_count));
+    }
+    bitmap[index / 8] |= 1u8 << (index % 8);
+  }
+
+  Ok(bitmap)
+}
+
+/// Revealed message indices set in `bitmap`, rejecting any bit beyond `message_count`
+fn bitmap_to_revealed(bitmap: &[u8], message_count: usize) -> Result<BTreeSet<usize>, BBSError> {
+  let mut revealed = BTreeSet::new();
+
+  for (i, byte) in bitmap.iter().enumerate() {
+    for bit in 0..8 {
+      if byte & (1u8 << bit) == 0 {
+        continue;
+      }
+      let index = i * 8 + bit;
+      if index >= message_count {
+        handle_err!("Invalid proof: revealed message index out of bounds");
+      }
+      revealed.insert(index);
+    }
+  }
+
+  Ok(revealed)
+}
@@ -0,0 +1,108 @@
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde::Serialize;
+use serde_json::{Value, json};
+use super::json_schema::{JsonSchema, object_schema};
+
+/// Why a proof failed to verify
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofFailure {
+  /// The proof bytes couldn't be decoded
+  MalformedProof,
+  /// The number of given messages differs from the number revealed by the proof
+  MessageCountMismatch,
+  /// The proof's total message count differs from the public key's
+  KeyMessageCountMismatch,
+  /// The recomputed challenge differs from the proof's, e.g. a revealed message or the nonce differs
+  ChallengeMismatch,
+  /// The proof of knowledge of the signature failed, e.g. it was signed by another key
+  InvalidSignature,
+  /// A presentation's nonce differs from the one the verifier expects
+  NonceMismatch,
+  /// A presentation's revealed message indices differ from those revealed by its proof
+  RevealedMismatch,
+}
+
+impl ProofFailure {
+  pub fn code(self) -> &'static str {
+    match self {
+      ProofFailure::MalformedProof => "malformed_proof",
+      ProofFailure::MessageCountMismatch => "message_count_mismatch",
+      ProofFailure::KeyMessageCountMismatch => "key_message_count_mismatch",
+      ProofFailure::ChallengeMismatch => "challenge_mismatch",
+      ProofFailure::InvalidSignature => "invalid_signature",
+      ProofFailure::NonceMismatch => "nonce_mismatch",
+      ProofFailure::RevealedMismatch => "revealed_mismatch",
+    }
+  }
+}
+
+/// Outcome of verifying a proof, with what was decoded from it for diagnosing failures
+#[derive(Serialize)]
+pub struct ProofVerification {
+  pub verified: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason: Option<&'static str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub detail: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<u8>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message_count: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub revealed: Option<Vec<usize>>,
+  /// Base64 nonce the proof was checked against, or `None` where no nonce was given
+  pub nonce: Option<String>,
+}
+
+impl ProofVerification {
+  pub fn new(nonce: Option<String>) -> ProofVerification {
+    ProofVerification {
+      verified: false,
+      reason: None,
+      detail: None,
+      version: None,
+      message_count: None,
+      revealed: None,
+      nonce,
+    }
+  }
+
+  pub fn fail<D: ToString>(&mut self, failure: ProofFailure, detail: D) {
+    self.verified = false;
+    self.reason = Some(failure.code());
+    self.detail = Some(detail.to_string());
+  }
+}
+
+impl JsonSchema for ProofVerification {
+  fn json_schema() -> Value {
+    let failures = [
+      ProofFailure::MalformedProof,
+      ProofFailure::MessageCountMismatch,
+      ProofFailure::KeyMessageCountMismatch,
+      ProofFailure::ChallengeMismatch,
+      ProofFailure::InvalidSignature,
+      ProofFailure::NonceMismatch,
+      ProofFailure::RevealedMismatch,
+    ];
+    let reasons: Vec<&str> = failures.iter().map(|failure| failure.code()).collect();
+
+    object_schema("Outcome of verifying a proof", vec![
+      ("verified", bool::json_schema(), false),
+      ("reason", json!({ "enum": reasons }), true),
+      ("detail", String::json_schema(), true),
+      ("version", u8::json_schema(), true),
+      ("message_count", usize::json_schema(), true),
+      ("revealed", Vec::<usize>::json_schema(), true),
+      ("nonce", Option::<String>::json_schema(), false),
+    ])
+  }
+}
+
+/// Message of a `BBSError`, without the `Error: ... Caused by:` formatting of its `Display`
+pub fn error_detail(error: &BBSError) -> String {
+  match error.kind() {
+    BBSErrorKind::GeneralError { msg } => msg,
+    kind => kind.to_string(),
+  }
+}
@@ -0,0 +1,73 @@
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use bbs_plus::prelude::*;
+use bbs_plus::errors::BBSError;
+use lru::LruCache;
+use super::bbs_plus_backend;
+use super::curve::DefaultBackend;
+use super::limits::check_message_count;
+
+/// Maximum number of derived BBS public keys held in the cache
+pub const PUBLIC_KEY_CACHE_CAPACITY: usize = 128;
+
+type CacheKey = ([u8; DETERMINISTIC_PUBLIC_KEY_COMPRESSED_SIZE], usize);
+type PublicKey = bbs_plus_backend::PublicKey<DefaultBackend>;
+
+lazy_static! {
+  static ref PUBLIC_KEY_CACHE: Mutex<LruCache<CacheKey, PublicKey>> = Mutex::new(
+    LruCache::new(NonZeroUsize::new(PUBLIC_KEY_CACHE_CAPACITY).unwrap())
+  );
+}
+
+/// Convert a deterministic public key to a BBS public key for `message_count` messages,
+/// reusing previously derived generators where available
+pub fn cached_public_key(
+  dpk: &DeterministicPublicKey,
+  message_count: usize,
+) -> Result<PublicKey, BBSError> {
+  // a generator is derived for each message
+  check_message_count(message_count)?;
+  let key = (dpk.to_bytes_compressed_form(), message_count);
+
+  if let Some(pk) = lock_cache().get(&key) {
+    return Ok(pk.clone());
+  }
+
+  // derive outside of the lock, so other callers aren't blocked by hash to curve
+  let pk = PublicKey::from_deterministic(dpk, message_count)?;
+  lock_cache().put(key, pk.clone());
+
+  Ok(pk)
+}
+
+/// Derive and cache BBS public keys for each of the given message counts
+pub fn warm_public_key_cache(
+  dpk: &DeterministicPublicKey,
+  message_counts: &[usize],
+) -> Result<(), BBSError> {
+  for message_count in message_counts {
+    cached_public_key(dpk, *message_count)?;
+  }
+  Ok(())
+}
+
+/// Remove all entries from the cache, returning the number removed
+pub fn clear_public_key_cache() -> usize {
+  let mut cache = lock_cache();
+  let count = cache.len();
+  cache.clear();
+  count
+}
+
+/// Number of entries currently held in the cache
+pub fn public_key_cache_len() -> usize {
+  lock_cache().len()
+}
+
+fn lock_cache() -> std::sync::MutexGuard<'static, LruCache<CacheKey, PublicKey>> {
+  // a panic whilst holding the lock can't leave the cache inconsistent, so recover from poisoning
+  match PUBLIC_KEY_CACHE.lock() {
+    Ok(cache) => cache,
+    Err(poisoned) => poisoned.into_inner(),
+  }
+}
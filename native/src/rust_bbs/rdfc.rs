@@ -0,0 +1,251 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use sha2::{Digest, Sha256};
+use super::nquads::Quad;
+
+// RDF Dataset Canonicalization (RDFC-1.0), https://www.w3.org/TR/rdf-canon/
+
+/// Most Hash N-Degree Quads calls a dataset may need, bounding the work poison datasets can cause
+pub const MAX_N_DEGREE_CALLS: usize = 10_000;
+
+/// Issues identifiers `<prefix>0`, `<prefix>1`, ... to blank nodes, remembering their order
+#[derive(Clone)]
+struct IdentifierIssuer {
+  prefix: &'static str,
+  issued: Vec<String>,
+  identifiers: HashMap<String, String>,
+}
+
+impl IdentifierIssuer {
+  fn new(prefix: &'static str) -> IdentifierIssuer {
+    IdentifierIssuer { prefix, issued: Vec::new(), identifiers: HashMap::new() }
+  }
+
+  fn issue(&mut self, blank_node: &str) -> String {
+    if let Some(identifier) = self.identifiers.get(blank_node) {
+      return identifier.clone();
+    }
+    let identifier = format!("{}{}", self.prefix, self.issued.len());
+    self.issued.push(blank_node.to_string());
+    self.identifiers.insert(blank_node.to_string(), identifier.clone());
+    identifier
+  }
+
+  fn get(&self, blank_node: &str) -> Option<&String> {
+    self.identifiers.get(blank_node)
+  }
+}
+
+struct Canonicalizer<'a> {
+  blank_node_quads: BTreeMap<&'a str, Vec<&'a Quad>>,
+  canonical_issuer: IdentifierIssuer,
+  first_degree_hashes: HashMap<&'a str, String>,
+  n_degree_calls: usize,
+}
+
+/// Canonically labeled dataset
+pub struct CanonicalDataset {
+  /// Canonical N-Quads statements, sorted
+  pub quads: Vec<String>,
+  /// Canonical label (`c14n<n>`) of each input blank node label
+  pub labels: HashMap<String, String>,
+}
+
+/// Canonicalize a dataset, deduplicating its quads
+pub fn canonicalize(quads: &[Quad]) -> Result<CanonicalDataset, BBSError> {
+  let labels = canonical_labels(quads)?;
+
+  let canonical: BTreeSet<String> = quads.iter()
+    .map(|quad| quad.relabel(|label| labels[label].clone()).to_string())
+    .collect();
+
+  Ok(CanonicalDataset { quads: canonical.into_iter().collect(), labels })
+}
+
+/// Canonical label of each blank node in the dataset
+pub fn canonical_labels(quads: &[Quad]) -> Result<HashMap<String, String>, BBSError> {
+  let mut state = Canonicalizer {
+    blank_node_quads: BTreeMap::new(),
+    canonical_issuer: IdentifierIssuer::new("c14n"),
+    first_degree_hashes: HashMap::new(),
+    n_degree_calls: 0,
+  };
+
+  let unique: BTreeSet<&Quad> = quads.iter().collect();
+  for quad in unique {
+    for blank_node in quad.blank_nodes() {
+      let blank_node_quads = state.blank_node_quads.entry(blank_node).or_default();
+      if blank_node_quads.last() != Some(&quad) {
+        blank_node_quads.push(quad);
+      }
+    }
+  }
+
+  let blank_nodes: Vec<&str> = state.blank_node_quads.keys().cloned().collect();
+  let mut hash_to_blank_nodes: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+  for blank_node in blank_nodes {
+    let hash = state.hash_first_degree_quads(blank_node);
+    hash_to_blank_nodes.entry(hash).or_default().push(blank_node);
+  }
+
+  // blank nodes with a unique first degree hash are labeled first, in hash order
+  for blank_nodes in hash_to_blank_nodes.values() {
+    if let [blank_node] = blank_nodes[..] {
+      state.canonical_issuer.issue(blank_node);
+    }
+  }
+
+  for blank_nodes in hash_to_blank_nodes.values().filter(|blank_nodes| blank_nodes.len() > 1) {
+    let mut hash_path_list = Vec::new();
+    for blank_node in blank_nodes {
+      if state.canonical_issuer.get(blank_node).is_some() {
+        continue;
+      }
+      let mut temporary_issuer = IdentifierIssuer::new("b");
+      temporary_issuer.issue(blank_node);
+      hash_path_list.push(state.hash_n_degree_quads(blank_node, temporary_issuer)?);
+    }
+
+    hash_path_list.sort_by(|(hash, _), (other_hash, _)| hash.cmp(other_hash));
+    for (_, issuer) in hash_path_list {
+      for blank_node in &issuer.issued {
+        state.canonical_issuer.issue(blank_node);
+      }
+    }
+  }
+
+  Ok(state.canonical_issuer.identifiers)
+}
+
+fn sha256_hex(data: &str) -> String {
+  Sha256::digest(data.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+impl<'a> Canonicalizer<'a> {
+  /// Hash First Degree Quads, 4.6
+  fn hash_first_degree_quads(&mut self, reference: &'a str) -> String {
+    if let Some(hash) = self.first_degree_hashes.get(reference) {
+      return hash.clone();
+    }
+
+    let mut nquads: Vec<String> = self.blank_node_quads[reference].iter()
+      .map(|quad| quad.relabel(|label| if label == reference { "a" } else { "z" }.to_string()).to_string())
+      .collect();
+    nquads.sort_unstable();
+
+    let hash = sha256_hex(&nquads.concat());
+    self.first_degree_hashes.insert(reference, hash.clone());
+    hash
+  }
+
+  /// Hash Related Blank Node, 4.7
+  fn hash_related_blank_node(&mut self, related: &'a str, quad: &Quad, issuer: &IdentifierIssuer, position: char) -> String {
+    let identifier = match (self.canonical_issuer.get(related), issuer.get(related)) {
+      (Some(identifier), _) | (None, Some(identifier)) => format!("_:{}", identifier),
+      (None, None) => self.hash_first_degree_quads(related),
+    };
+
+    let mut input = position.to_string();
+    if position != 'g' {
+      input.push_str(&quad.predicate.to_string());
+    }
+    input.push_str(&identifier);
+    sha256_hex(&input)
+  }
+
+  /// Hash N-Degree Quads, 4.8
+  fn hash_n_degree_quads(&mut self, identifier: &'a str, mut issuer: IdentifierIssuer) -> Result<(String, IdentifierIssuer), BBSError> {
+    self.n_degree_calls += 1;
+    if self.n_degree_calls > MAX_N_DEGREE_CALLS {
+      handle_err!("Dataset is too complex to canonicalize");
+    }
+
+    let mut related_blank_nodes: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+    let quads = self.blank_node_quads[identifier].clone();
+    for quad in quads {
+      let components = [('s', Some(&quad.subject)), ('o', Some(&quad.object)), ('g', quad.graph.as_ref())];
+      for (position, component) in components.iter() {
+        let related = match component.and_then(|term| term.blank_node()) {
+          Some(related) if related != identifier => related,
+          _ => continue,
+        };
+        // borrow the label from the map, which lives as long as the dataset
+        let related = *self.blank_node_quads.keys().find(|label| **label == related).unwrap();
+        let hash = self.hash_related_blank_node(related, quad, &issuer, *position);
+        related_blank_nodes.entry(hash).or_default().push(related);
+      }
+    }
+
+    let mut data_to_hash = String::new();
+    for (related_hash, blank_nodes) in related_blank_nodes {
+      data_to_hash.push_str(&related_hash);
+      let mut chosen_path = String::new();
+      let mut chosen_issuer = None;
+
+      'permutations: for permutation in permutations(&blank_nodes) {
+        let mut issuer_copy = issuer.clone();
+        let mut path = String::new();
+        let mut recursion_list = Vec::new();
+
+        for related in permutation.iter() {
+          match self.canonical_issuer.get(related) {
+            Some(canonical) => path.push_str(&format!("_:{}", canonical)),
+            None => {
+              if issuer_copy.get(related).is_none() {
+                recursion_list.push(*related);
+              }
+              path.push_str(&format!("_:{}", issuer_copy.issue(related)));
+            }
+          }
+          if is_longer_path(&path, &chosen_path) {
+            continue 'permutations;
+          }
+        }
+
+        for related in recursion_list {
+          let (hash, result_issuer) = self.hash_n_degree_quads(related, issuer_copy.clone())?;
+          issuer_copy = result_issuer;
+          path.push_str(&format!("_:{}<{}>", issuer_copy.issue(related), hash));
+          if is_longer_path(&path, &chosen_path) {
+            continue 'permutations;
+          }
+        }
+
+        if chosen_issuer.is_none() || path < chosen_path {
+          chosen_path = path;
+          chosen_issuer = Some(issuer_copy);
+        }
+      }
+
+      data_to_hash.push_str(&chosen_path);
+      if let Some(chosen_issuer) = chosen_issuer {
+        issuer = chosen_issuer;
+      }
+    }
+
+    Ok((sha256_hex(&data_to_hash), issuer))
+  }
+}
+
+/// Whether `path` can no longer be chosen over `chosen_path`
+fn is_longer_path(path: &str, chosen_path: &str) -> bool {
+  !chosen_path.is_empty() && path.len() >= chosen_path.len() && path > chosen_path
+}
+
+/// Every ordering of `items`
+fn permutations<T: Copy>(items: &[T]) -> Vec<Vec<T>> {
+  if items.len() <= 1 {
+    return vec![items.to_vec()];
+  }
+
+  let mut orderings = Vec::new();
+  for (i, first) in items.iter().enumerate() {
+    let mut rest = items.to_vec();
+    rest.remove(i);
+    for mut ordering in permutations(&rest) {
+      ordering.insert(0, *first);
+      orderings.push(ordering);
+    }
+  }
+  orderings
+}
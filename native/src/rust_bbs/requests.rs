@@ -0,0 +1,690 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde::Deserialize;
+use serde::de::{self, DeserializeOwned, Deserializer, Visitor};
+use serde_json::{Map, Value, json};
+use zeroize::Zeroizing;
+use super::json_schema::{JsonSchema, doc_description, object_schema, with_description};
+use super::cose::Algorithm;
+use super::jwp::Serialization;
+use super::limits::check_context_limits;
+use super::secret::wipe_json;
+
+// Request types for each operation's context. Operations read their context into them before
+// doing anything else, so a misspelt or mistyped property is an error rather than being taken
+// as unset. They are shared by the C, Java and Node wrappers, which all call the operations
+
+/// An operation's request, read from its JSON context
+pub trait Request: Sized {
+  /// The properties the context may set
+  const PROPERTIES: &'static [&'static str];
+
+  /// Read and type check a context, rejecting properties the operation doesn't take
+  fn from_context(context_json: &Value) -> Result<Self, BBSError>;
+}
+
+/// Read a context into an operation's request type, checking the limits on its size. The
+/// context is wiped, as the request holds its own copies of any secrets
+pub fn check_request<R: Request>(mut context_json: Value) -> Result<R, BBSError> {
+  let request = R::from_context(&context_json)
+    .and_then(|request| check_context_limits(&context_json).map(|_| request));
+  wipe_json(&mut context_json);
+  request
+}
+
+macro_rules! requests {
+  ($(
+    $(#[doc = $doc:literal])*
+    $name:ident {
+      $( $(#[doc = $property_doc:literal])* $property:ident: $ty:ty, )*
+    }
+  )*) => {$(
+    $(#[doc = $doc])*
+    pub struct $name {
+      $( $(#[doc = $property_doc])* pub $property: $ty, )*
+    }
+
+    impl Request for $name {
+      const PROPERTIES: &'static [&'static str] = &[$(stringify!($property)),*];
+
+      fn from_context(context_json: &Value) -> Result<Self, BBSError> {
+        check_properties(context_json, Self::PROPERTIES)?;
+        Ok($name {
+          $( $property: context_property(context_json, stringify!($property))?, )*
+        })
+      }
+    }
+
+    impl JsonSchema for $name {
+      fn json_schema() -> Value {
+        object_schema(&doc_description(&[$($doc),*]), vec![
+          $( (
+            stringify!($property),
+            with_description(<$ty as JsonSchema>::json_schema(), &[$($property_doc),*]),
+            <$ty as JsonSchema>::OPTIONAL,
+          ), )*
+          ("encoding", encoding_schema(), true),
+        ])
+      }
+    }
+  )*};
+}
+
+/// Every context may set the encoding of its byte properties
+fn encoding_schema() -> Value {
+  json!({
+    "description": "Encoding of the context's and result's byte properties. A CBOR context sets 'cbor', and holds them as byte strings",
+    "enum": ["base64", "base64url", "hex", "cbor"],
+  })
+}
+
+fn check_properties(context_json: &Value, properties: &[&str]) -> Result<(), BBSError> {
+  let context = match context_json.as_object() {
+    Some(context) => context,
+    None => { handle_err!("Invalid context, expected a JSON object"); }
+  };
+
+  match context.keys().find(|property| !properties.contains(&property.as_str())) {
+    Some(property) => {
+      let expected = properties.iter().map(|property| format!("'{}'", property)).collect::<Vec<String>>();
+      handle_err!(format!("Unknown property: '{}', expected one of {}", property, expected.join(", ")));
+    },
+    None => Ok(()),
+  }
+}
+
+/// Deserialize a property without copying it, so secrets are only held where typed as such
+fn context_property<T: DeserializeOwned>(context_json: &Value, property: &str) -> Result<T, BBSError> {
+  let value = context_json.get(property).unwrap_or(&Value::Null);
+  match T::deserialize(value) {
+    Ok(value) => Ok(value),
+    Err(_) if value.is_null() => { handle_err!(format!("Property not set: '{}'", property)); },
+    Err(error) => {
+      let (path, error) = error_path::<T>(value, property).unwrap_or_else(|| (property.to_string(), error));
+      handle_err!(format!("Invalid '{}': {}", path, error));
+    }
+  }
+}
+
+/// Find the array item or object member of a property that fails to deserialize
+fn error_path<T: DeserializeOwned>(value: &Value, property: &str) -> Option<(String, serde_json::Error)> {
+  match value {
+    // items of an array are read in order, so the first failing prefix ends with the failing item
+    Value::Array(items) if T::deserialize(&Value::Array(Vec::new())).is_ok() => {
+      let (mut valid, mut invalid) = (0, items.len());
+      while invalid - valid > 1 {
+        let middle = (valid + invalid) / 2;
+        match T::deserialize(&Value::Array(items[..middle].to_vec())) {
+          Ok(_) => valid = middle,
+          Err(_) => invalid = middle,
+        }
+      }
+      T::deserialize(&Value::Array(items[..invalid].to_vec())).err()
+        .map(|error| (format!("{}[{}]", property, invalid - 1), error))
+    },
+    Value::Object(members) if T::deserialize(&Value::Object(Map::new())).is_ok() => members.iter()
+      .find_map(|(name, member)| {
+        let mut single = Map::new();
+        single.insert(name.clone(), member.clone());
+        T::deserialize(&Value::Object(single)).err().map(|error| (format!("{}.{}", property, name), error))
+      }),
+    _ => None,
+  }
+}
+
+/// A secret string, wiped when dropped
+pub struct Secret(pub Zeroizing<String>);
+
+impl<'de> Deserialize<'de> for Secret {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Secret, D::Error> {
+    String::deserialize(deserializer).map(|secret| Secret(Zeroizing::new(secret)))
+  }
+}
+
+impl JsonSchema for Secret {
+  fn json_schema() -> Value {
+    json!({ "type": "string", "writeOnly": true })
+  }
+}
+
+/// Seed of a debug build's RNG, so identical contexts give identical signatures and proofs
+pub struct DeterministicSeed(pub Secret);
+
+impl<'de> Deserialize<'de> for DeterministicSeed {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DeterministicSeed, D::Error> {
+    Secret::deserialize(deserializer).map(DeterministicSeed)
+  }
+}
+
+impl JsonSchema for DeterministicSeed {
+  fn json_schema() -> Value {
+    json!({
+      "type": "string",
+      "writeOnly": true,
+      "description": "Seed of the randomness drawn by the call, which only debug builds take, so identical contexts give identical results in tests",
+    })
+  }
+}
+
+/// A public key as an encoded string or did:key, or as a JWK
+pub enum PublicKeyInput {
+  Encoded(String),
+  Jwk(Value),
+}
+
+impl<'de> Deserialize<'de> for PublicKeyInput {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PublicKeyInput, D::Error> {
+    match Value::deserialize(deserializer)? {
+      Value::String(public_key) => Ok(PublicKeyInput::Encoded(public_key)),
+      jwk @ Value::Object(_) => Ok(PublicKeyInput::Jwk(jwk)),
+      _ => Err(de::Error::custom("expected an encoded public key, did:key or JWK object")),
+    }
+  }
+}
+
+impl JsonSchema for PublicKeyInput {
+  fn json_schema() -> Value {
+    json!({ "anyOf": [
+      { "type": "string", "description": "Encoded public key or did:key" },
+      { "type": "object", "description": "Public JWK" },
+    ] })
+  }
+}
+
+/// A private JWK, wiped when dropped
+pub struct SecretJwk(pub Value);
+
+impl Drop for SecretJwk {
+  fn drop(&mut self) {
+    wipe_json(&mut self.0);
+  }
+}
+
+/// A secret key as an encoded string, or as a private JWK
+pub enum SecretKeyInput {
+  Encoded(Secret),
+  Jwk(SecretJwk),
+}
+
+impl<'de> Deserialize<'de> for SecretKeyInput {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<SecretKeyInput, D::Error> {
+    match Value::deserialize(deserializer)? {
+      Value::String(secret_key) => Ok(SecretKeyInput::Encoded(Secret(Zeroizing::new(secret_key)))),
+      jwk @ Value::Object(_) => Ok(SecretKeyInput::Jwk(SecretJwk(jwk))),
+      _ => Err(de::Error::custom("expected an encoded secret key or JWK object")),
+    }
+  }
+}
+
+impl JsonSchema for SecretKeyInput {
+  fn json_schema() -> Value {
+    json!({ "anyOf": [
+      { "type": "string", "description": "Encoded secret key", "writeOnly": true },
+      { "type": "object", "description": "Private JWK", "writeOnly": true },
+    ] })
+  }
+}
+
+/// A message, by its index or, with a schema, by its claim name
+pub enum MessageSelector {
+  Index(u32),
+  Claim(String),
+}
+
+impl<'de> Deserialize<'de> for MessageSelector {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<MessageSelector, D::Error> {
+    struct SelectorVisitor;
+
+    impl<'de> Visitor<'de> for SelectorVisitor {
+      type Value = MessageSelector;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a message index (u32) or claim name")
+      }
+
+      fn visit_u64<E: de::Error>(self, index: u64) -> Result<MessageSelector, E> {
+        match u32::try_from(index) {
+          Ok(index) => Ok(MessageSelector::Index(index)),
+          Err(_) => Err(de::Error::invalid_value(de::Unexpected::Unsigned(index), &self)),
+        }
+      }
+
+      fn visit_i64<E: de::Error>(self, index: i64) -> Result<MessageSelector, E> {
+        match u32::try_from(index) {
+          Ok(index) => Ok(MessageSelector::Index(index)),
+          Err(_) => Err(de::Error::invalid_value(de::Unexpected::Signed(index), &self)),
+        }
+      }
+
+      fn visit_str<E: de::Error>(self, name: &str) -> Result<MessageSelector, E> {
+        Ok(MessageSelector::Claim(name.to_string()))
+      }
+    }
+
+    deserializer.deserialize_any(SelectorVisitor)
+  }
+}
+
+impl JsonSchema for MessageSelector {
+  fn json_schema() -> Value {
+    json!({ "anyOf": [
+      { "type": "integer", "minimum": 0, "maximum": u32::MAX, "description": "Message index" },
+      { "type": "string", "description": "Claim name" },
+    ] })
+  }
+}
+
+/// Claim values by name, as base64 messages
+pub type Claims = BTreeMap<String, String>;
+
+requests! {
+  /// Generate a key pair, from a 'seed' where given
+  GenerateKeyRequest {
+    seed: Option<Secret>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  SecretKeyToBbsKeyRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    message_count: u32,
+  }
+
+  PublicKeyToBbsKeyRequest {
+    public_key: PublicKeyInput,
+    /// Defaults to the schema's message count
+    message_count: Option<u32>,
+    schema: Option<Value>,
+  }
+
+  PublicKeyCacheWarmRequest {
+    public_key: PublicKeyInput,
+    message_counts: Vec<u32>,
+  }
+
+  KeystoreImportRequest {
+    secret_key: SecretKeyInput,
+    public_key: Option<PublicKeyInput>,
+  }
+
+  /// Refer to a key in the keystore
+  KeystoreKeyRequest {
+    key_handle: u64,
+  }
+
+  ExportEncryptedKeyRequest {
+    key_type: String,
+    public_key: PublicKeyInput,
+    secret_key: SecretKeyInput,
+    /// Required for blinded key types
+    blinding_factor: Option<Secret>,
+    password: Secret,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  ImportEncryptedKeyRequest {
+    encrypted_key: Map<String, Value>,
+    password: Secret,
+  }
+
+  KeyToJwkRequest {
+    public_key: String,
+    secret_key: Option<Secret>,
+  }
+
+  JwkToKeyRequest {
+    jwk: SecretKeyInput,
+  }
+
+  /// Encode a public key as a Multikey or did:key
+  PublicKeyEncodingRequest {
+    public_key: PublicKeyInput,
+  }
+
+  MultikeyToPublicKeyRequest {
+    public_key_multibase: String,
+  }
+
+  ResolveDidKeyRequest {
+    did: String,
+  }
+
+  SchemaHashRequest {
+    schema: Value,
+  }
+
+  /// Sign 'messages', named 'claims' of a 'schema' or a JSON 'document'
+  BbsSignRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    public_key: String,
+    messages: Option<Vec<String>>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BlsSignRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    public_key: Option<PublicKeyInput>,
+    messages: Option<Vec<String>>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BbsVerifyRequest {
+    public_key: String,
+    signature: Option<String>,
+    messages: Option<Vec<String>>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    credential: Option<String>,
+  }
+
+  BlsVerifyRequest {
+    public_key: PublicKeyInput,
+    signature: Option<String>,
+    messages: Option<Vec<String>>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    credential: Option<String>,
+  }
+
+  IetfSignRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    public_key: Option<PublicKeyInput>,
+    header: Option<String>,
+    messages: Vec<String>,
+  }
+
+  IetfVerifyRequest {
+    public_key: PublicKeyInput,
+    signature: Option<String>,
+    header: Option<String>,
+    messages: Option<Vec<String>>,
+    /// A base64 COSE envelope of the signature, its header and messages
+    credential: Option<String>,
+  }
+
+  IetfCreateProofRequest {
+    public_key: PublicKeyInput,
+    signature: Option<String>,
+    header: Option<String>,
+    presentation_header: Option<String>,
+    messages: Option<Vec<String>>,
+    /// A base64 COSE envelope of the signature, its header and messages
+    credential: Option<String>,
+    revealed: Vec<u32>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  IetfVerifyProofRequest {
+    public_key: PublicKeyInput,
+    proof: String,
+    header: Option<String>,
+    presentation_header: Option<String>,
+    /// The revealed messages
+    messages: Vec<String>,
+    revealed: Vec<u32>,
+  }
+
+  RdfCanonizeRequest {
+    /// N-Quads
+    document: String,
+  }
+
+  Bbs2023CreateBaseProofRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    public_key: Option<PublicKeyInput>,
+    document: String,
+    proof_config: String,
+    mandatory_indexes: Option<Vec<u32>>,
+    hmac_key: Option<Secret>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  Bbs2023CreateDerivedProofRequest {
+    document: String,
+    proof_value: String,
+    selective_indexes: Vec<u32>,
+    presentation_header: Option<String>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  Bbs2023VerifyDerivedProofRequest {
+    public_key: PublicKeyInput,
+    document: String,
+    proof_value: String,
+    proof_config: String,
+  }
+
+  JwpIssueRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    public_key: Option<PublicKeyInput>,
+    header: Option<Map<String, Value>>,
+    payloads: Vec<String>,
+    serialization: Option<Serialization>,
+  }
+
+  JwpPresentRequest {
+    public_key: PublicKeyInput,
+    /// Compact or JSON serialized
+    jwp: Value,
+    revealed: Vec<u32>,
+    presentation_header: Option<Map<String, Value>>,
+    nonce: Option<String>,
+    audience: Option<String>,
+    serialization: Option<Serialization>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  JwpVerifyRequest {
+    public_key: PublicKeyInput,
+    jwp: Value,
+    nonce: Option<String>,
+    audience: Option<String>,
+  }
+
+  /// Prove knowledge of a signature, revealing messages by index, claim name or JSON Pointer
+  BbsCreateProofRequest {
+    public_key: String,
+    signature: Option<String>,
+    messages: Option<Vec<String>>,
+    revealed: Option<Vec<u32>>,
+    reveal: Option<Vec<String>>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    credential: Option<String>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BlsCreateProofRequest {
+    public_key: PublicKeyInput,
+    signature: Option<String>,
+    messages: Option<Vec<String>>,
+    revealed: Option<Vec<u32>>,
+    reveal: Option<Vec<String>>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    credential: Option<String>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BbsVerifyProofRequest {
+    public_key: String,
+    proof: String,
+    /// The revealed messages
+    messages: Option<Vec<String>>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+  }
+
+  BlsVerifyProofRequest {
+    public_key: PublicKeyInput,
+    proof: String,
+    messages: Option<Vec<String>>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+  }
+
+  BbsCreatePresentationRequest {
+    public_key: String,
+    signature: Option<String>,
+    messages: Option<Vec<String>>,
+    revealed: Option<Vec<u32>>,
+    reveal: Option<Vec<String>>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    credential: Option<String>,
+    key_id: Option<String>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BlsCreatePresentationRequest {
+    public_key: PublicKeyInput,
+    signature: Option<String>,
+    messages: Option<Vec<String>>,
+    revealed: Option<Vec<u32>>,
+    reveal: Option<Vec<String>>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    document: Option<Value>,
+    credential: Option<String>,
+    key_id: Option<String>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BbsVerifyPresentationRequest {
+    public_key: String,
+    /// An object, a JSON string or a base64 COSE envelope
+    presentation: Value,
+    nonce: Option<String>,
+    schema: Option<Value>,
+  }
+
+  BlsVerifyPresentationRequest {
+    public_key: PublicKeyInput,
+    presentation: Value,
+    nonce: Option<String>,
+    schema: Option<Value>,
+  }
+
+  CoseCredentialRequest {
+    /// Defaults to 'bbs_plus'
+    algorithm: Option<Algorithm>,
+    signature: String,
+    /// Header of a 'bbs_ietf' signature
+    header: Option<String>,
+    messages: Vec<String>,
+    /// Unprotected, as the signature doesn't cover it
+    key_id: Option<String>,
+  }
+
+  CosePresentationRequest {
+    presentation: Value,
+  }
+
+  BbsBlindSignatureCommitmentRequest {
+    public_key: String,
+    /// The messages to blind
+    messages: Option<Vec<String>>,
+    blinded: Vec<MessageSelector>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BlsBlindSignatureCommitmentRequest {
+    public_key: PublicKeyInput,
+    messages: Option<Vec<String>>,
+    blinded: Vec<MessageSelector>,
+    /// Defaults to the schema's claims that aren't blinded
+    known_message_count: Option<u32>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BbsVerifyBlindSignatureProofRequest {
+    public_key: String,
+    commitment: String,
+    challenge_hash: String,
+    proof_of_hidden_messages: String,
+    blinded: Vec<MessageSelector>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+  }
+
+  BlsVerifyBlindSignatureProofRequest {
+    public_key: PublicKeyInput,
+    commitment: String,
+    challenge_hash: String,
+    proof_of_hidden_messages: String,
+    blinded: Vec<MessageSelector>,
+    known_message_count: Option<u32>,
+    nonce: Option<String>,
+    schema: Option<Value>,
+  }
+
+  BbsBlindSignRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    public_key: String,
+    commitment: String,
+    /// The known messages
+    messages: Option<Vec<String>>,
+    known: Vec<MessageSelector>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  BlsBlindSignRequest {
+    secret_key: Option<SecretKeyInput>,
+    key_handle: Option<u64>,
+    public_key: Option<PublicKeyInput>,
+    commitment: String,
+    messages: Option<Vec<String>>,
+    known: Vec<MessageSelector>,
+    /// Defaults to the schema's claims that aren't known
+    blinded_message_count: Option<u32>,
+    schema: Option<Value>,
+    claims: Option<Claims>,
+    deterministic_seed: Option<DeterministicSeed>,
+  }
+
+  /// Set the global limits on the input of later calls, keeping those that aren't given
+  SetLimitsRequest {
+    max_message_count: Option<u64>,
+    max_message_size: Option<u64>,
+    max_context_size: Option<u64>,
+    max_batch_size: Option<u64>,
+  }
+
+  UnblindSignatureRequest {
+    blind_signature: String,
+    blinding_factor: Secret,
+  }
+}
@@ -0,0 +1,218 @@
+// operations still build their results as JSON, so response types only describe them
+#![allow(dead_code)]
+
+use std::collections::BTreeMap;
+use serde_json::{Map, Value};
+use super::json_schema::{JsonSchema, doc_description, object_schema, with_description};
+use super::key_export::EncryptedKey;
+use super::presentation::Presentation;
+use super::proof_verification::ProofVerification;
+
+// Response types for each operation's result, from which the published JSON Schemas are
+// generated. Byte properties are base64, or in the context's 'encoding' where it sets one
+
+macro_rules! responses {
+  ($(
+    $(#[doc = $doc:literal])*
+    $name:ident {
+      $( $(#[doc = $property_doc:literal])* $property:ident: $ty:ty, )*
+    }
+  )*) => {$(
+    $(#[doc = $doc])*
+    pub struct $name {
+      $( $(#[doc = $property_doc])* pub $property: $ty, )*
+    }
+
+    impl JsonSchema for $name {
+      fn json_schema() -> Value {
+        object_schema(&doc_description(&[$($doc),*]), vec![
+          $( (
+            // raw identifiers name properties that are Rust keywords
+            stringify!($property).trim_start_matches("r#"),
+            with_description(<$ty as JsonSchema>::json_schema(), &[$($property_doc),*]),
+            <$ty as JsonSchema>::OPTIONAL,
+          ), )*
+        ])
+      }
+    }
+  )*};
+}
+
+responses! {
+  /// A BLS key pair
+  KeyPairResponse {
+    public_key: String,
+    secret_key: String,
+  }
+
+  /// A blinded BLS key pair
+  BlindedKeyPairResponse {
+    blinding_factor: String,
+    public_key: String,
+    secret_key: String,
+  }
+
+  PublicKeyResponse {
+    public_key: String,
+  }
+
+  PublicKeyCacheResponse {
+    /// Number of public keys held by the cache
+    cache_size: usize,
+  }
+
+  /// A key held by the keystore
+  StoredKeyResponse {
+    key_handle: u64,
+    public_key: String,
+  }
+
+  KeystoreDestroyResponse {
+    /// Whether the handle referred to a key
+    destroyed: bool,
+  }
+
+  ImportedKeyResponse {
+    /// Set for blinded key types
+    blinding_factor: Option<String>,
+    key_type: String,
+    public_key: String,
+    secret_key: String,
+  }
+
+  /// An OKP JWK, private where 'd' is set
+  JwkResponse {
+    kty: String,
+    crv: String,
+    x: String,
+    d: Option<String>,
+  }
+
+  /// A public key, with the secret key of a private JWK
+  JwkKeyResponse {
+    public_key: String,
+    secret_key: Option<String>,
+  }
+
+  MultikeyResponse {
+    public_key_multibase: String,
+  }
+
+  DidKeyResponse {
+    did: String,
+    key_id: String,
+  }
+
+  MultikeyPublicKeyResponse {
+    /// 'g1' or 'g2'
+    key_type: String,
+    public_key: String,
+  }
+
+  /// Verification method of a did:key
+  ResolvedDidKeyResponse {
+    did: String,
+    key_id: String,
+    r#type: String,
+    public_key_multibase: String,
+    public_key: String,
+  }
+
+  SchemaHashResponse {
+    schema_hash: String,
+    message_count: usize,
+  }
+
+  SignatureResponse {
+    signature: String,
+  }
+
+  VerifiedResponse {
+    verified: bool,
+  }
+
+  ProofResponse {
+    proof: String,
+  }
+
+  /// Canonical N-Quads
+  CanonicalDocumentResponse {
+    document: String,
+  }
+
+  BaseProofResponse {
+    proof_value: String,
+  }
+
+  DerivedProofResponse {
+    /// N-Quads of the disclosed statements
+    document: String,
+    proof_value: String,
+  }
+
+  JwpResponse {
+    /// Compact or JSON serialized
+    jwp: Value,
+  }
+
+  JwpVerifyResponse {
+    verified: bool,
+    header: Map<String, Value>,
+    /// Disclosed payloads, with `null` undisclosed ones
+    payloads: Vec<Option<String>>,
+    presentation_header: Option<Map<String, Value>>,
+  }
+
+  PresentationResponse {
+    presentation: Presentation,
+  }
+
+  /// Outcome of verifying a presentation, with its disclosed messages once verified
+  PresentationVerifyResponse {
+    verified: bool,
+    revealed_messages: Option<BTreeMap<usize, String>>,
+    nonce: Option<String>,
+    key_id: Option<String>,
+    /// Revealed claims by name, where a schema is given
+    claims: Option<BTreeMap<String, String>>,
+    /// Disclosed document, for messages flattened from one
+    document: Option<Value>,
+    reason: Option<String>,
+    detail: Option<String>,
+  }
+
+  CoseCredentialResponse {
+    /// Base64 COSE envelope
+    credential: String,
+  }
+
+  CosePresentationResponse {
+    /// Base64 COSE_Sign1 envelope
+    presentation: String,
+  }
+
+  BlindSignatureCommitmentResponse {
+    blinding_factor: String,
+    challenge_hash: String,
+    commitment: String,
+    proof_of_hidden_messages: String,
+  }
+
+  /// Bounds on the input of a single call
+  LimitsResponse {
+    max_message_count: usize,
+    max_message_size: usize,
+    max_context_size: usize,
+    max_batch_size: usize,
+  }
+
+  BlindSignatureResponse {
+    blind_signature: String,
+  }
+}
+
+/// Encrypted key exports are returned as is
+pub type EncryptedKeyResponse = EncryptedKey;
+
+/// Proof verifications are returned as is
+pub type ProofVerificationResponse = ProofVerification;
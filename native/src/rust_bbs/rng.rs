@@ -0,0 +1,69 @@
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use rand::prelude::*;
+use rand::rngs::{StdRng, ThreadRng};
+use sha2::{Digest, Sha256};
+use super::decode_base64;
+use super::requests::DeterministicSeed;
+
+/// Context property seeding the randomness of a call, so identical contexts give identical results
+pub const DETERMINISTIC_SEED: &str = "deterministic_seed";
+
+/// Source of the randomness drawn by an operation: the thread's RNG, or a CSPRNG seeded by the
+/// context's `deterministic_seed` to reproduce signatures, proofs and fixtures in tests
+pub enum ContextRng {
+  Thread(ThreadRng),
+  Seeded(Box<StdRng>),
+}
+
+impl RngCore for ContextRng {
+  fn next_u32(&mut self) -> u32 {
+    match self {
+      ContextRng::Thread(rng) => rng.next_u32(),
+      ContextRng::Seeded(rng) => rng.next_u32(),
+    }
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    match self {
+      ContextRng::Thread(rng) => rng.next_u64(),
+      ContextRng::Seeded(rng) => rng.next_u64(),
+    }
+  }
+
+  fn fill_bytes(&mut self, dest: &mut [u8]) {
+    match self {
+      ContextRng::Thread(rng) => rng.fill_bytes(dest),
+      ContextRng::Seeded(rng) => rng.fill_bytes(dest),
+    }
+  }
+
+  fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+    match self {
+      ContextRng::Thread(rng) => rng.try_fill_bytes(dest),
+      ContextRng::Seeded(rng) => rng.try_fill_bytes(dest),
+    }
+  }
+}
+
+impl CryptoRng for ContextRng {}
+
+/// The RNG an operation draws its randomness from, where `label` names the operation
+///
+/// Only debug builds take a `deterministic_seed`, as repeating the randomness of a proof
+/// for different challenges gives away its hidden messages
+pub fn context_rng(seed: &Option<DeterministicSeed>, label: &str) -> Result<ContextRng, BBSError> {
+  let seed = match seed {
+    None => return Ok(ContextRng::Thread(thread_rng())),
+    Some(DeterministicSeed(seed)) if cfg!(debug_assertions) => decode_base64(&seed.0, DETERMINISTIC_SEED)?,
+    Some(_) => { handle_err!(format!("Invalid '{}', only debug builds are seeded", DETERMINISTIC_SEED)); },
+  };
+
+  // each operation of a call draws from its own stream, e.g. the proof of a presentation
+  let mut hasher = Sha256::new();
+  hasher.input(label.as_bytes());
+  hasher.input([0u8]);
+  hasher.input(&seed);
+  let mut rng_seed = <StdRng as SeedableRng>::Seed::default();
+  rng_seed.copy_from_slice(&hasher.result());
+  Ok(ContextRng::Seeded(Box::new(StdRng::from_seed(rng_seed))))
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeSet;
+use bbs_plus::errors::{BBSError, BBSErrorKind};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use super::requests::{Claims, MessageSelector};
+
+/// Domain separation tag hashed ahead of the claim names
+const SCHEMA_HASH_DST: &[u8] = b"BBS_CLAIM_SCHEMA_V1";
+
+/// Index of the schema hash message, signed ahead of the claims and always revealed
+pub const SCHEMA_HASH_INDEX: usize = 0;
+
+/// Ordered list of claim names, mapping each claim to a message index
+pub struct Schema {
+  names: Vec<String>,
+}
+
+impl Schema {
+  pub fn from_json(schema: &Value) -> Result<Schema, BBSError> {
+    let names = match schema.as_array() {
+      Some(names) if !names.is_empty() => names,
+      Some(_) => { handle_err!("Schema must name at least one claim"); },
+      None => { handle_err!("Invalid schema, expected an array of claim names"); }
+    };
+
+    let mut unique = BTreeSet::new();
+    let mut schema_names = Vec::with_capacity(names.len());
+    for name in names {
+      match name.as_str() {
+        Some(name) if !name.is_empty() && unique.insert(name) => schema_names.push(name.to_string()),
+        Some(name) if !name.is_empty() => { handle_err!(format!("Duplicate claim name in schema: {}", name)); },
+        _ => { handle_err!("Invalid schema, claim names must be non-empty strings"); }
+      }
+    }
+
+    Ok(Schema { names: schema_names })
+  }
+
+  /// SHA-256 of the length prefixed claim names, so any change to names or order changes the hash
+  pub fn hash(&self) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.input(SCHEMA_HASH_DST);
+    hasher.input((self.names.len() as u32).to_be_bytes());
+    for name in &self.names {
+      hasher.input((name.len() as u32).to_be_bytes());
+      hasher.input(name.as_bytes());
+    }
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(hasher.result().as_slice());
+    hash
+  }
+
+  /// Base64 schema hash, as signed at `SCHEMA_HASH_INDEX`
+  pub fn hash_message(&self) -> String {
+    base64::encode(self.hash())
+  }
+
+  /// Total number of signed messages: the schema hash and each claim
+  pub fn message_count(&self) -> usize {
+    self.names.len() + 1
+  }
+
+  /// Message index of a claim
+  pub fn index_of(&self, name: &str) -> Result<usize, BBSError> {
+    match self.names.iter().position(|schema_name| schema_name == name) {
+      Some(position) => Ok(position + 1),
+      None => { handle_err!(format!("Claim not in schema: {}", name)); }
+    }
+  }
+
+  /// Claim name at a message index, `None` for the schema hash
+  pub fn name_at(&self, index: usize) -> Option<&str> {
+    match index {
+      SCHEMA_HASH_INDEX => None,
+      _ => self.names.get(index - 1).map(String::as_str),
+    }
+  }
+}
+
+/// Messages of every claim of the schema, led by the schema hash, as signed
+pub fn signed_claim_messages(schema: &Schema, claims: &Claims) -> Result<Vec<String>, BBSError> {
+  if let Some(name) = claims.keys().find(|name| schema.index_of(name).is_err()) {
+    handle_err!(format!("Claim not in schema: {}", name));
+  }
+  claim_messages(schema, claims, &schema.names, true)
+}
+
+/// Messages of the revealed claims a proof is verified against, led by the schema hash
+pub fn revealed_claim_messages(schema: &Schema, claims: &Claims) -> Result<Vec<String>, BBSError> {
+  let names: Vec<String> = claims.keys().cloned().collect();
+  claim_messages(schema, claims, &names, true)
+}
+
+/// Message indices of the claims named to 'reveal', with the schema hash
+pub fn reveal_claim_indices(schema: &Schema, reveal: &[String]) -> Result<Vec<usize>, BBSError> {
+  check_claim_names(reveal, "reveal")?;
+  claim_indices(schema, reveal, true)
+}
+
+/// Blinded claims of a blind signature commitment: their messages where the 'claims' are given,
+/// and their indices, in the order the claims are named
+pub fn blinded_claims(
+  schema: &Schema,
+  claims: Option<&Claims>,
+  blinded: &[MessageSelector],
+) -> Result<(Option<Vec<String>>, Vec<usize>), BBSError> {
+  let names = selector_claim_names(blinded, "blinded")?;
+  let messages = match claims {
+    Some(claims) => Some(claim_messages(schema, claims, &names, false)?),
+    None => None,
+  };
+  Ok((messages, claim_indices(schema, &names, false)?))
+}
+
+/// Known claims of a blind signature: their messages and indices, led by the schema hash which
+/// the issuer signs alongside them
+pub fn known_claims(
+  schema: &Schema,
+  claims: Option<&Claims>,
+  known: &[MessageSelector],
+) -> Result<(Vec<String>, Vec<usize>), BBSError> {
+  let names = selector_claim_names(known, "known")?;
+  let messages = claim_messages(schema, claims.unwrap_or(&Claims::new()), &names, true)?;
+  Ok((messages, claim_indices(schema, &names, true)?))
+}
+
+/// Named claims by message index, for revealed messages by index
+pub fn claims_by_name(schema: &Schema, messages: &[(usize, &String)]) -> Result<Map<String, Value>, BBSError> {
+  let mut claims = Map::new();
+  for (index, message) in messages {
+    match (index, schema.name_at(*index)) {
+      (&SCHEMA_HASH_INDEX, _) => {
+        if **message != schema.hash_message() {
+          handle_err!("Schema hash is different from that of the expected schema");
+        }
+      },
+      (_, Some(name)) => { claims.insert(name.to_string(), Value::String(message.to_string())); },
+      (_, None) => { handle_err!(format!("Message index {} is not in the schema", index)); }
+    }
+  }
+  Ok(claims)
+}
+
+/// Claim names of 'blinded' or 'known' selectors, which are all names where there's a schema
+fn selector_claim_names(selectors: &[MessageSelector], property: &str) -> Result<Vec<String>, BBSError> {
+  let mut names = Vec::with_capacity(selectors.len());
+  for selector in selectors {
+    match selector {
+      MessageSelector::Claim(name) => names.push(name.clone()),
+      MessageSelector::Index(_) => { handle_err!(format!("Invalid '{}', expected an array of claim names", property)); }
+    }
+  }
+  check_claim_names(&names, property)?;
+  Ok(names)
+}
+
+fn check_claim_names(names: &[String], property: &str) -> Result<(), BBSError> {
+  for (position, name) in names.iter().enumerate() {
+    if names[..position].contains(name) {
+      handle_err!(format!("Duplicate claim name in '{}': {}", property, name));
+    }
+  }
+  Ok(())
+}
+
+/// Message indices for the named claims, in schema order, led by the schema hash where `with_schema_hash`.
+/// Blinded claims keep the order they're given in, which their messages are aligned to
+fn claim_indices(schema: &Schema, names: &[String], with_schema_hash: bool) -> Result<Vec<usize>, BBSError> {
+  let mut indices = Vec::with_capacity(names.len() + 1);
+  for name in names {
+    indices.push(schema.index_of(name)?);
+  }
+
+  if with_schema_hash {
+    indices.push(SCHEMA_HASH_INDEX);
+    indices.sort_unstable();
+    indices.dedup();
+  }
+  Ok(indices)
+}
+
+/// Messages for the named claims, ordered as `claim_indices` orders them
+fn claim_messages(
+  schema: &Schema,
+  claims: &Claims,
+  names: &[String],
+  with_schema_hash: bool,
+) -> Result<Vec<String>, BBSError> {
+  let mut named: Vec<(usize, &str)> = Vec::with_capacity(names.len());
+  for name in names {
+    match claims.get(name) {
+      Some(value) => named.push((schema.index_of(name)?, value)),
+      None => { handle_err!(format!("Claim not set: {}", name)); }
+    }
+  }
+
+  let mut messages = Vec::with_capacity(named.len() + 1);
+  if with_schema_hash {
+    named.sort_unstable_by_key(|(index, _)| *index);
+    messages.push(schema.hash_message());
+  }
+  messages.extend(named.into_iter().map(|(_, value)| value.to_string()));
+
+  Ok(messages)
+}
@@ -0,0 +1,57 @@
+use serde::Serialize;
+use serde_json::Value;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Space allowed for property names, quotes and separators when serializing secret output
+const JSON_OVERHEAD: usize = 128;
+
+/// Move a string property out of the context, so it is wiped when dropped
+/// rather than left behind in the `Value`
+pub fn take_secret(context_json: &mut Value, property: &str) -> Option<Zeroizing<String>> {
+  match context_json.get_mut(property).map(Value::take) {
+    Some(Value::String(secret)) => Some(Zeroizing::new(secret)),
+    _ => None,
+  }
+}
+
+/// Decode base64 secret material into a buffer that is wiped when dropped
+pub fn decode_secret(secret_b64: &str) -> Result<Zeroizing<Vec<u8>>, base64::DecodeError> {
+  decode_secret_config(secret_b64, base64::STANDARD)
+}
+
+/// Decode secret material with the given base64 alphabet into a buffer that is wiped when dropped
+pub fn decode_secret_config(
+  secret_b64: &str,
+  config: base64::Config,
+) -> Result<Zeroizing<Vec<u8>>, base64::DecodeError> {
+  // size the buffer up front, as growing it would leave copies in freed memory
+  let mut secret = Zeroizing::new(vec![0u8; secret_b64.len().div_ceil(4) * 3]);
+  let length = base64::decode_config_slice(secret_b64, config, secret.as_mut_slice())?;
+  secret.truncate(length);
+  Ok(secret)
+}
+
+/// Encode secret material as base64 into a string that is wiped when dropped
+pub fn encode_secret(secret: &[u8]) -> Zeroizing<String> {
+  Zeroizing::new(base64::encode(secret))
+}
+
+/// Serialize output holding secret material to a JSON string, without leaving partial
+/// copies behind in reallocated buffers. `values` are the output's string properties
+pub fn secret_to_json<T: Serialize>(output: &T, values: &[&str]) -> serde_json::Result<String> {
+  let capacity = values.iter().map(|value| value.len()).sum::<usize>() + JSON_OVERHEAD;
+  let mut json_bytes = Zeroizing::new(Vec::with_capacity(capacity));
+  serde_json::to_writer(&mut *json_bytes, output)?;
+  // serde_json only writes valid UTF-8
+  Ok(String::from_utf8(std::mem::take(&mut *json_bytes)).unwrap())
+}
+
+/// Wipe a JSON value's strings before it's dropped
+pub fn wipe_json(value: &mut Value) {
+  match value {
+    Value::String(text) => text.zeroize(),
+    Value::Array(values) => values.iter_mut().for_each(wipe_json),
+    Value::Object(values) => values.values_mut().for_each(wipe_json),
+    _ => (),
+  }
+}
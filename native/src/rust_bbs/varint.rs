@@ -0,0 +1,29 @@
+/// Longest encoding accepted by `decode_varint`, as used by multiformats (63 bit values)
+const MAX_VARINT_LENGTH: usize = 9;
+
+/// Encode an unsigned LEB128 varint
+pub fn encode_varint(mut value: u64) -> Vec<u8> {
+  let mut bytes = Vec::new();
+  while value >= 0x80 {
+    bytes.push((value as u8) | 0x80);
+    value >>= 7;
+  }
+  bytes.push(value as u8);
+  bytes
+}
+
+/// Decode an unsigned varint, returning its value and encoded length.
+/// Non-minimal encodings are rejected, so each value has exactly one encoding
+pub fn decode_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+  let mut value = 0u64;
+  for (i, byte) in bytes.iter().enumerate().take(MAX_VARINT_LENGTH) {
+    value |= u64::from(byte & 0x7f) << (7 * i);
+    if byte & 0x80 == 0 {
+      if i > 0 && *byte == 0 {
+        return None;
+      }
+      return Some((value, i + 1));
+    }
+  }
+  None
+}
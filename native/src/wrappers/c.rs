@@ -18,12 +18,30 @@ use crate::rust_bbs::{
   rust_bls_public_key_to_bbs_key,
   rust_bbs_sign,
   rust_bls_sign,
+  rust_bbs_ietf_sign,
+  rust_bbs_ietf_verify,
+  rust_bbs_ietf_create_proof,
+  rust_bbs_ietf_verify_proof,
+  rust_rdf_canonize,
+  rust_bbs_2023_create_base_proof,
+  rust_bbs_2023_create_derived_proof,
+  rust_bbs_2023_verify_derived_proof,
+  rust_jwp_issue,
+  rust_jwp_present,
+  rust_jwp_verify,
   rust_bbs_verify,
   rust_bls_verify,
   rust_bbs_create_proof,
   rust_bls_create_proof,
   rust_bbs_verify_proof,
   rust_bls_verify_proof,
+  rust_bbs_create_presentation,
+  rust_bls_create_presentation,
+  rust_bbs_verify_presentation,
+  rust_bls_verify_presentation,
+  rust_bbs_cose_credential,
+  rust_bbs_cose_presentation,
+  rust_bbs_schema_hash,
   rust_bbs_blind_signature_commitment,
   rust_bls_blind_signature_commitment,
   rust_bbs_verify_blind_signature_proof,
@@ -31,15 +49,48 @@ use crate::rust_bbs::{
   rust_bbs_blind_sign,
   rust_bls_blind_sign,
   rust_bbs_unblind_signature,
+  rust_bls_public_key_cache_warm,
+  rust_bls_public_key_cache_clear,
+  rust_bls_keystore_import,
+  rust_bls_keystore_generate,
+  rust_bls_keystore_public_key,
+  rust_bls_keystore_destroy,
+  rust_bls_export_encrypted_key,
+  rust_bls_import_encrypted_key,
+  rust_bls_key_to_jwk,
+  rust_bls_jwk_to_key,
+  rust_bls_public_key_to_multikey,
+  rust_bls_multikey_to_public_key,
+  rust_bls_public_key_to_did_key,
+  rust_bls_resolve_did_key,
+  rust_bbs_set_limits,
+  parse_context,
+  rust_call_cbor,
+  rust_bbs_get_schema,
+  rust_bbs_library_info,
 };
+use ffi_support::ByteBuffer;
+use std::ffi::CStr;
 use std::os::raw::c_char;
 use serde_json::{Value, json};
+use zeroize::{Zeroize, Zeroizing};
 
 #[repr(C)]
 pub struct JsonString {
   ptr: *const c_char,
 }
 
+/// Point `json_string` at a null terminated copy of `string`, allocated at its exact size
+/// as it may hold secret material. Growing the string to add the terminator, or shrinking it
+/// into a box, would reallocate and leave unwiped copies in freed memory
+fn set_json_string(json_string: &mut JsonString, string: String) {
+  let string = Zeroizing::new(string);
+  // `vec!` allocates exactly the length asked for, so boxing it doesn't reallocate
+  let mut bytes = vec![0u8; string.len() + 1].into_boxed_slice();
+  bytes[..string.len()].copy_from_slice(string.as_bytes());
+  json_string.ptr = Box::into_raw(bytes).cast();
+}
+
 /// Free memory for C string
 ///
 /// # SAFETY
@@ -47,7 +98,72 @@ pub struct JsonString {
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
 pub unsafe extern "C" fn ffi_bbs_signatures_free_json_string(json_string: JsonString) {
-  let _ = Box::from_raw(json_string.ptr as *mut c_char);
+  // reclaim the whole boxed string (including null terminator), as it may hold secret material
+  let length = CStr::from_ptr(json_string.ptr).to_bytes_with_nul().len();
+  let mut boxed: Box<[u8]> = Box::from_raw(std::ptr::slice_from_raw_parts_mut(json_string.ptr as *mut u8, length));
+  boxed.zeroize();
+}
+
+/// Call an operation by name with a CBOR (or JSON) context, setting `output` to its CBOR
+/// result. Errors are returned as a CBOR `error` map, like the JSON ones
+///
+/// # SAFETY
+/// The `operation` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`, and `output` must be freed with
+/// `ffi_bbs_signatures_free_byte_buffer`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_call_cbor(
+  operation: *const c_char,
+  context: ffi::ByteArray,
+  output: &mut ByteBuffer,
+) -> i32 {
+  let operation = CStr::from_ptr(operation).to_str().unwrap_or_default();
+  *output = ByteBuffer::from_vec(rust_call_cbor(operation, &Zeroizing::new(context.to_vec())));
+  0
+}
+
+/// Get the JSON Schemas of an operation's context and result
+///
+/// # SAFETY
+/// The `operation` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_get_schema(
+  operation: *const c_char,
+  json_string: &mut JsonString,
+) -> i32 {
+  let operation = CStr::from_ptr(operation).to_str().unwrap_or_default();
+
+  match rust_bbs_get_schema(operation) {
+    Ok(schema_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, schema_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Unable to get schema: {:?}", error), error, json_string); }
+  }
+}
+
+/// Describe the library's version, features, formats and limits
+#[no_mangle]
+pub extern "C" fn bbs_library_info(json_string: &mut JsonString) -> i32 {
+  match rust_bbs_library_info() {
+    Ok(info_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, info_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Unable to get library info: {:?}", error), error, json_string); }
+  }
+}
+
+/// Free memory for a CBOR byte buffer
+#[no_mangle]
+pub extern "C" fn ffi_bbs_signatures_free_byte_buffer(buffer: ByteBuffer) {
+  // the result may hold secret material
+  buffer.destroy_into_vec().zeroize();
 }
 
 /// Get size of G1 public key
@@ -96,32 +212,21 @@ pub unsafe extern "C" fn bls_generate_blinded_g1_key(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize blinded G1 key to a JSON string
   match rust_bls_generate_blinded_g1_key(context_json) {
-    Ok(mut blinded_g1_key_string) => {
-      // add null terminator (for C-string)
-      blinded_g1_key_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = blinded_g1_key_string.into_boxed_str();
-    
-      // set json_string pointer to boxed blinded_g1_key_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(blinded_g1_key_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, blinded_g1_key_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify Blinded G1 key: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify Blinded G1 key: {:?}", error), error, json_string); }
   }
 }
 
@@ -135,32 +240,21 @@ pub unsafe extern "C" fn bls_generate_blinded_g2_key(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize blinded G2 key to a JSON string
   match rust_bls_generate_blinded_g2_key(context_json) {
-    Ok(mut blinded_g2_key_string) => {
-      // add null terminator (for C-string)
-      blinded_g2_key_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = blinded_g2_key_string.into_boxed_str();
-    
-      // set json_string pointer to boxed blinded_g2_key_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(blinded_g2_key_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, blinded_g2_key_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify Blinded G2 key: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify Blinded G2 key: {:?}", error), error, json_string); }
   }
 }
 
@@ -174,32 +268,21 @@ pub unsafe extern "C" fn bls_generate_g1_key(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize G1 key to a JSON string
   match rust_bls_generate_g1_key(context_json) {
-    Ok(mut g1_key_string) => {
-      // add null terminator (for C-string)
-      g1_key_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = g1_key_string.into_boxed_str();
-    
-      // set json_string pointer to boxed g1_key_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(g1_key_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, g1_key_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify G1 key: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify G1 key: {:?}", error), error, json_string); }
   }
 }
 
@@ -213,32 +296,21 @@ pub unsafe extern "C" fn bls_generate_g2_key(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize G2 key to a JSON string
   match rust_bls_generate_g2_key(context_json) {
-    Ok(mut g2_key_string) => {
-      // add null terminator (for C-string)
-      g2_key_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = g2_key_string.into_boxed_str();
-    
-      // set json_string pointer to boxed g2_key_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(g2_key_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, g2_key_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify G2 key: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify G2 key: {:?}", error), error, json_string); }
   }
 }
 
@@ -252,32 +324,21 @@ pub unsafe extern "C" fn bls_secret_key_to_bbs_key(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
   match rust_bls_secret_key_to_bbs_key(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), error, json_string); }
   }
 }
 
@@ -291,32 +352,21 @@ pub unsafe extern "C" fn bls_public_key_to_bbs_key(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
   match rust_bls_public_key_to_bbs_key(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), error, json_string); }
   }
 }
 
@@ -330,32 +380,21 @@ pub unsafe extern "C" fn bbs_sign(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
   match rust_bbs_sign(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify BBS Signature: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS Signature: {:?}", error), error, json_string); }
   }
 }
 
@@ -369,538 +408,1286 @@ pub unsafe extern "C" fn bls_sign(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
   match rust_bls_sign(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to stringify BBS Signature: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS Signature: {:?}", error), error, json_string); }
   }
 }
 
-/// BBS Create Proof
+/// Sign messages with an IETF BBS (BLS12-381 SHA-256) signature
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bbs_create_proof(
+pub unsafe extern "C" fn bbs_ietf_sign(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bbs_create_proof(context_json) {
-    Ok(mut g2_key_string) => {
-      // add null terminator (for C-string)
-      g2_key_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = g2_key_string.into_boxed_str();
-    
-      // set json_string pointer to boxed g1_key_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_bbs_ietf_sign(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to sign messages: {:?}", error), error, json_string); }
   }
 }
 
-/// BLS Create Proof
+/// Verify an IETF BBS signature
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bls_create_proof(
+pub unsafe extern "C" fn bbs_ietf_verify(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bls_create_proof(context_json) {
-    Ok(mut g2_key_string) => {
-      // add null terminator (for C-string)
-      g2_key_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = g2_key_string.into_boxed_str();
-    
-      // set json_string pointer to boxed g1_key_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_bbs_ietf_verify(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to verify signature: {:?}", error), error, json_string); }
   }
 }
 
-/// BBS Verify Proof
+/// Create an IETF BBS proof disclosing the revealed messages
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bbs_verify_proof(
+pub unsafe extern "C" fn bbs_ietf_create_proof(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bbs_verify_proof(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_bbs_ietf_create_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to create proof: {:?}", error), error, json_string); }
   }
 }
 
-/// BLS Verify Proof
+/// Verify an IETF BBS proof against its revealed messages
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bls_verify_proof(
+pub unsafe extern "C" fn bbs_ietf_verify_proof(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bls_verify_proof(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_bbs_ietf_verify_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to verify proof: {:?}", error), error, json_string); }
   }
 }
 
-/// Generate Blind Signature Commitment JSON
+/// Canonicalize an N-Quads document with RDFC-1.0
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bbs_blind_signature_commitment(
+pub unsafe extern "C" fn rdf_canonize(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bbs_blind_signature_commitment(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_rdf_canonize(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to generate blind signature commitment: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to canonicalize document: {:?}", error), error, json_string); }
   }
 }
 
-/// Generate Blind Signature Commitment JSON
+/// Create a bbs-2023 base proof over an N-Quads document
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bls_blind_signature_commitment(
+pub unsafe extern "C" fn bbs_2023_create_base_proof(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bls_blind_signature_commitment(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_bbs_2023_create_base_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to generate blind signature commitment: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to create base proof: {:?}", error), error, json_string); }
   }
 }
 
-/// Verify Blind Signature Commitment Context
+/// Derive a bbs-2023 proof selectively disclosing statements of an N-Quads document
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bbs_verify_blind_signature_proof(
+pub unsafe extern "C" fn bbs_2023_create_derived_proof(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bbs_verify_blind_signature_proof(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_bbs_2023_create_derived_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to verify blind signature commitment: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to create derived proof: {:?}", error), error, json_string); }
   }
 }
 
-/// Verify Blind Signature Commitment Context
+/// Verify a bbs-2023 derived proof over its revealed N-Quads document
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bls_verify_blind_signature_proof(
+pub unsafe extern "C" fn bbs_2023_verify_derived_proof(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bls_verify_blind_signature_proof(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
-
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  match rust_bbs_2023_verify_derived_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to verify blind signature commitment: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to verify derived proof: {:?}", error), error, json_string); }
   }
 }
 
-/// Blind Sign Messages
+/// Issue a JSON Web Proof over payloads
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bbs_blind_sign(
+pub unsafe extern "C" fn jwp_issue(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", json_string); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bbs_blind_sign(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
+  match rust_jwp_issue(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to issue JWP: {:?}", error), error, json_string); }
+  }
+}
+
+/// Present an issued JSON Web Proof, disclosing selected payloads
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn jwp_present(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
 
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  // Serialize response to a JSON string
+  match rust_jwp_present(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to generate blind signature: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to present JWP: {:?}", error), error, json_string); }
   }
 }
 
-/// Blind Sign Messages
+/// Verify an issued or presented JSON Web Proof
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bls_blind_sign(
+pub unsafe extern "C" fn jwp_verify(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_jwp_verify(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
     },
-    Err(_) => { handle_err!("Context not set", json_string); }
+    Err(error) => { handle_err!(format!("Failed to verify JWP: {:?}", error), error, json_string); }
+  }
+}
+
+/// BBS Create Proof
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_create_proof(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bls_blind_sign(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
+  match rust_bbs_create_proof(context_json) {
+    Ok(g2_key_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, g2_key_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), error, json_string); }
+  }
+}
+
+/// BLS Create Proof
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_create_proof(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
 
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  // Serialize response to a JSON string
+  match rust_bls_create_proof(context_json) {
+    Ok(g2_key_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, g2_key_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to generate blind signature: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), error, json_string); }
   }
 }
 
-/// Unblind blinded signature
+/// BBS Verify Proof
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bbs_get_unblinded_signature(
+pub unsafe extern "C" fn bbs_verify_proof(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_verify_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
     },
-    Err(_) => { handle_err!("Context not set", json_string); }
+    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), error, json_string); }
+  }
+}
+
+/// BLS Verify Proof
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_verify_proof(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bbs_unblind_signature(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
+  match rust_bls_verify_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), error, json_string); }
+  }
+}
+
+/// Create a presentation bundling a BBS proof with its revealed messages
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_create_presentation(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
 
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  // Serialize response to a JSON string
+  match rust_bbs_create_presentation(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to unblind signature: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to create presentation: {:?}", error), error, json_string); }
   }
 }
 
-/// Verify signature
+/// Create a presentation bundling a BBS proof with its revealed messages, for a BLS public key
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bbs_verify(
+pub unsafe extern "C" fn bls_create_presentation(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_create_presentation(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
     },
-    Err(_) => { handle_err!("Context not set", json_string); }
+    Err(error) => { handle_err!(format!("Failed to create presentation: {:?}", error), error, json_string); }
+  }
+}
+
+/// Verify a presentation against a trusted BBS public key
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_verify_presentation(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bbs_verify(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
+  match rust_bbs_verify_presentation(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to verify presentation: {:?}", error), error, json_string); }
+  }
+}
+
+/// Verify a presentation against a trusted BLS public key
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_verify_presentation(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
 
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+  // Serialize response to a JSON string
+  match rust_bls_verify_presentation(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to verify signature: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to verify presentation: {:?}", error), error, json_string); }
   }
 }
 
-/// Verify signature
+/// Envelope a signature and its messages as a COSE_Sign1 credential
 ///
 /// # SAFETY
 /// The `json_string.ptr` pointer needs to follow the same safety requirements
 /// as Rust's `std::ffi::CStr::from_ptr`
 #[no_mangle]
-pub unsafe extern "C" fn bls_verify(
+pub unsafe extern "C" fn bbs_cose_credential(
   context: ffi::ByteArray,
   json_string: &mut JsonString,
 ) -> i32 {
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", json_string); }
-      }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_cose_credential(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
     },
-    Err(_) => { handle_err!("Context not set", json_string); }
+    Err(error) => { handle_err!(format!("Failed to create COSE credential: {:?}", error), error, json_string); }
+  }
+}
+
+/// Envelope a presentation as a COSE_Sign1
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_cose_presentation(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
   };
 
   // Serialize response to a JSON string
-  match rust_bls_verify(context_json) {
-    Ok(mut output_string) => {
-      // add null terminator (for C-string)
-      output_string.push('\0');
+  match rust_bbs_cose_presentation(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to create COSE presentation: {:?}", error), error, json_string); }
+  }
+}
 
-      // box the string, so string isn't de-allocated on leaving the scope of this fn
-      let boxed: Box<str> = output_string.into_boxed_str();
-    
-      // set json_string pointer to boxed output_string
-      json_string.ptr = Box::into_raw(boxed).cast();
+/// Get the hash of a claim schema, signed as its first message
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_schema_hash(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_schema_hash(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to hash schema: {:?}", error), error, json_string); }
+  }
+}
+
+/// Generate Blind Signature Commitment JSON
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_blind_signature_commitment(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_blind_signature_commitment(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to generate blind signature commitment: {:?}", error), error, json_string); }
+  }
+}
+
+/// Generate Blind Signature Commitment JSON
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_blind_signature_commitment(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_blind_signature_commitment(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to generate blind signature commitment: {:?}", error), error, json_string); }
+  }
+}
+
+/// Verify Blind Signature Commitment Context
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_verify_blind_signature_proof(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_verify_blind_signature_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to verify blind signature commitment: {:?}", error), error, json_string); }
+  }
+}
+
+/// Verify Blind Signature Commitment Context
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_verify_blind_signature_proof(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_verify_blind_signature_proof(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to verify blind signature commitment: {:?}", error), error, json_string); }
+  }
+}
+
+/// Blind Sign Messages
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_blind_sign(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_blind_sign(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to generate blind signature: {:?}", error), error, json_string); }
+  }
+}
+
+/// Blind Sign Messages
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_blind_sign(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_blind_sign(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to generate blind signature: {:?}", error), error, json_string); }
+  }
+}
+
+/// Unblind blinded signature
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_get_unblinded_signature(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_unblind_signature(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to unblind signature: {:?}", error), error, json_string); }
+  }
+}
+
+/// Verify signature
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_verify(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_verify(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to verify signature: {:?}", error), error, json_string); }
+  }
+}
+
+/// Verify signature
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_verify(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_verify(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to verify signature: {:?}", error), error, json_string); }
+  }
+}
+
+/// Pre-derive BBS public keys for a BLS public key, for the given message counts
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_public_key_cache_warm(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_public_key_cache_warm(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to warm public key cache: {:?}", error), error, json_string); }
+  }
+}
+
+/// Clear cached BBS public keys, returning the number of entries removed
+#[no_mangle]
+pub extern "C" fn bls_public_key_cache_clear() -> i32 {
+  rust_bls_public_key_cache_clear()
+}
+
+/// Import a BLS secret key into the keystore, returning its key handle
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_keystore_import(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_keystore_import(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to import key into keystore: {:?}", error), error, json_string); }
+  }
+}
+
+/// Generate a BLS G2 key inside the keystore, returning its key handle
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_keystore_generate(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_keystore_generate(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to generate keystore key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Get the BLS public key for a keystore key handle
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_keystore_public_key(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_keystore_public_key(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to get keystore public key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Remove a key from the keystore, wiping its secret key
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_keystore_destroy(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_keystore_destroy(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to destroy keystore key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Export a BLS secret key, encrypted under a password
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_export_encrypted_key(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_export_encrypted_key(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to export encrypted key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Import a BLS secret key from a password encrypted export
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_import_encrypted_key(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_import_encrypted_key(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to import encrypted key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Convert a BLS key pair to a JWK
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_key_to_jwk(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_key_to_jwk(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to convert key to JWK: {:?}", error), error, json_string); }
+  }
+}
+
+/// Convert a BLS12-381 JWK to a BLS key pair
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_jwk_to_key(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_jwk_to_key(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to convert JWK to key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Encode a BLS public key as a multibase Multikey
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_public_key_to_multikey(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_public_key_to_multikey(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to encode Multikey: {:?}", error), error, json_string); }
+  }
+}
+
+/// Decode a multibase Multikey to a BLS public key
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_multikey_to_public_key(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_multikey_to_public_key(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to decode Multikey: {:?}", error), error, json_string); }
+  }
+}
+
+/// Create a did:key from a BLS G2 public key
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_public_key_to_did_key(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_public_key_to_did_key(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to create did:key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Resolve a did:key locally to its BLS G2 public key
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bls_resolve_did_key(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bls_resolve_did_key(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
+
+      0
+    },
+    Err(error) => { handle_err!(format!("Failed to resolve did:key: {:?}", error), error, json_string); }
+  }
+}
+
+/// Set the limits on the input of every later call
+///
+/// # SAFETY
+/// The `json_string.ptr` pointer needs to follow the same safety requirements
+/// as Rust's `std::ffi::CStr::from_ptr`
+#[no_mangle]
+pub unsafe extern "C" fn bbs_set_limits(
+  context: ffi::ByteArray,
+  json_string: &mut JsonString,
+) -> i32 {
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&Zeroizing::new(context.to_vec())) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, json_string); }
+  };
+
+  // Serialize response to a JSON string
+  match rust_bbs_set_limits(context_json) {
+    Ok(output_string) => {
+      // copy into a null terminated C string, wiping the result
+      set_json_string(json_string, output_string);
 
       0
     },
-    Err(error) => { handle_err!(format!("Failed to verify signature: {:?}", error), json_string); }
+    Err(error) => { handle_err!(format!("Failed to set limits: {:?}", error), error, json_string); }
   }
 }
@@ -1,26 +1,29 @@
 macro_rules! handle_err {
     ($e:expr, $json_string:expr) => {
-        let err = json!({
+        let message = $e.to_string();
+        handle_err!(message, message, $json_string);
+    };
+    // the code is read from the error, not from a message framing it
+    ($e:expr, $error:expr, $json_string:expr) => {
+        let code = crate::rust_bbs::error_code(&$error);
+        let message = $e.to_string();
+        let mut err = json!({
           "error": {
             "name": "RustError",
-            "message": $e
+            "message": message
           }
         });
+        // e.g. a limit was exceeded
+        if let Some(code) = code {
+          err["error"]["code"] = json!(code);
+        }
 
         match serde_json::to_string(&err) {
-          Ok(mut blind_commitment_context_string) => {
-            // add null terminator (for C-string)
-            blind_commitment_context_string.push('\0');
-      
-            // box the string, so string isn't de-allocated on leaving the scope of this fn
-            let boxed: Box<str> = blind_commitment_context_string.into_boxed_str();
-          
-            // set json_string pointer to boxed blind_commitment_context_string
-            $json_string.ptr = Box::into_raw(boxed).cast();
-          },
+          Ok(error_string) => set_json_string($json_string, error_string),
           Err(_) => ()
         }
 
-        return 1;
+        // callers can tell exceeded limits apart by the return value too
+        return if code.is_some() { 2 } else { 1 };
     };
 }
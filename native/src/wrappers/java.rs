@@ -16,12 +16,30 @@ use crate::rust_bbs::{
   rust_bls_public_key_to_bbs_key,
   rust_bbs_sign,
   rust_bls_sign,
+  rust_bbs_ietf_sign,
+  rust_bbs_ietf_verify,
+  rust_bbs_ietf_create_proof,
+  rust_bbs_ietf_verify_proof,
+  rust_rdf_canonize,
+  rust_bbs_2023_create_base_proof,
+  rust_bbs_2023_create_derived_proof,
+  rust_bbs_2023_verify_derived_proof,
+  rust_jwp_issue,
+  rust_jwp_present,
+  rust_jwp_verify,
   rust_bbs_verify,
   rust_bls_verify,
   rust_bbs_create_proof,
   rust_bls_create_proof,
   rust_bbs_verify_proof,
   rust_bls_verify_proof,
+  rust_bbs_create_presentation,
+  rust_bls_create_presentation,
+  rust_bbs_verify_presentation,
+  rust_bls_verify_presentation,
+  rust_bbs_cose_credential,
+  rust_bbs_cose_presentation,
+  rust_bbs_schema_hash,
   rust_bbs_blind_signature_commitment,
   rust_bls_blind_signature_commitment,
   rust_bbs_verify_blind_signature_proof,
@@ -29,8 +47,28 @@ use crate::rust_bbs::{
   rust_bbs_blind_sign,
   rust_bls_blind_sign,
   rust_bbs_unblind_signature,
+  rust_bls_public_key_cache_warm,
+  rust_bls_public_key_cache_clear,
+  rust_bls_keystore_import,
+  rust_bls_keystore_generate,
+  rust_bls_keystore_public_key,
+  rust_bls_keystore_destroy,
+  rust_bls_export_encrypted_key,
+  rust_bls_import_encrypted_key,
+  rust_bls_key_to_jwk,
+  rust_bls_jwk_to_key,
+  rust_bls_public_key_to_multikey,
+  rust_bls_multikey_to_public_key,
+  rust_bls_public_key_to_did_key,
+  rust_bls_resolve_did_key,
+  rust_bbs_set_limits,
+  parse_context,
+  rust_call_cbor,
+  rust_bbs_get_schema,
+  rust_bbs_library_info,
 };
 use serde_json::{Value, json};
+use zeroize::Zeroizing;
 
 // This is the interface to the JVM that we'll
 // call the majority of our methods on.
@@ -110,6 +148,67 @@ pub extern "system" fn Java_bbs_signatures_Bbs_bbs_1blind_1signature_1size(
   rust_bbs_blind_signature_size()
 }
 
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_call_1cbor(
+  env: JNIEnv,
+  _class: JClass,
+  operation: JString,
+  ctx: jbyteArray,
+) -> jbyteArray {
+  // an unreadable operation or context is reported as a CBOR error by `rust_call_cbor`
+  let operation: String = env.get_string(operation).map(Into::into).unwrap_or_default();
+  let context_bytes = Zeroizing::new(env.convert_byte_array(ctx).unwrap_or_default());
+
+  let output = Zeroizing::new(rust_call_cbor(&operation, &context_bytes));
+  match env.byte_array_from_slice(&output) {
+    Ok(output) => output,
+    Err(_) => JObject::null().into_inner(),
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1get_1schema(
+  env: JNIEnv,
+  _class: JClass,
+  operation: JString,
+) -> jstring {
+  let operation: String = match env.get_string(operation) {
+    Ok(operation) => operation.into(),
+    Err(_) => { handle_err!("Failed converting `operation` to string", env); }
+  };
+
+  match rust_bbs_get_schema(&operation) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from schema data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Unable to get schema: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1library_1info(
+  env: JNIEnv,
+  _class: JClass,
+) -> jstring {
+  match rust_bbs_library_info() {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from library info");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Unable to get library info: {:?}", error), error, env); }
+  }
+}
+
 #[allow(non_snake_case)]
 #[no_mangle]
 pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1generate_1blinded_1g1_1key(
@@ -120,29 +219,25 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1generate_1blinded_1g1_1key(
   let context_bytes;
   match env.convert_byte_array(ctx) {
       Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-      Ok(bc) => context_bytes = bc,
+      Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bls_generate_blinded_g1_key(context_json) {
     Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
       let output = env
-        .new_string(output_string)
+        .new_string(output_string.as_str())
         .expect("Unable to create string from blinded G1 key data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify Blinded G1 key: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify Blinded G1 key: {:?}", error), error, env); }
   }
 }
 
@@ -156,29 +251,25 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1generate_1blinded_1g2_1key(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bls_generate_blinded_g2_key(context_json) {
     Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
       let output = env
-        .new_string(output_string)
+        .new_string(output_string.as_str())
         .expect("Unable to create string from blinded G2 key data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify Blinded G2 key: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify Blinded G2 key: {:?}", error), error, env); }
   }
 }
 
@@ -192,29 +283,25 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1generate_1g1_1key(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bls_generate_g1_key(context_json) {
     Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
       let output = env
-        .new_string(output_string)
+        .new_string(output_string.as_str())
         .expect("Unable to create string from G1 key data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify G1 key: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify G1 key: {:?}", error), error, env); }
   }
 }
 
@@ -228,29 +315,25 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1generate_1g2_1key(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bls_generate_g2_key(context_json) {
     Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
       let output = env
-        .new_string(output_string)
+        .new_string(output_string.as_str())
         .expect("Unable to create string from G2 key data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify G2 key: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify G2 key: {:?}", error), error, env); }
   }
 }
 
@@ -264,18 +347,13 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1secret_1key_1to_1bbs_1key(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bls_secret_key_to_bbs_key(context_json) {
@@ -286,7 +364,7 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1secret_1key_1to_1bbs_1key(
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), error, env); }
   }
 }
 
@@ -300,18 +378,13 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1public_1key_1to_1bbs_1key(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bls_public_key_to_bbs_key(context_json) {
@@ -322,7 +395,7 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1public_1key_1to_1bbs_1key(
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS key: {:?}", error), error, env); }
   }
 }
 
@@ -336,18 +409,13 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1sign(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bbs_sign(context_json) {
@@ -358,7 +426,7 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1sign(
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify BBS signature: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS signature: {:?}", error), error, env); }
   }
 }
 
@@ -372,18 +440,13 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1sign(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
   match rust_bls_sign(context_json) {
@@ -394,337 +457,343 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1sign(
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed to stringify BBS signature: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to stringify BBS signature: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1create_1proof(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1ietf_1sign(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bbs_create_proof(context_json) {
+  match rust_bbs_ietf_sign(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from BBS proof data");
+        .expect("Unable to create string from signing data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to sign messages: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1create_1proof(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1ietf_1verify(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bls_create_proof(context_json) {
+  match rust_bbs_ietf_verify(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from BBS proof data");
+        .expect("Unable to create string from verification data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to verify signature: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify_1proof(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1ietf_1create_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bbs_verify_proof(context_json) {
+  match rust_bbs_ietf_create_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from BBS verification outcome");
+        .expect("Unable to create string from proof data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to create proof: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify_1proof(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1ietf_1verify_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bls_verify_proof(context_json) {
+  match rust_bbs_ietf_verify_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from BLS verification outcome");
+        .expect("Unable to create string from proof verification data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to verify proof: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1blind_1signature_1commitment(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_rdf_1canonize(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bbs_blind_signature_commitment(context_json) {
+  match rust_rdf_canonize(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from blind commitment data");
+        .expect("Unable to create string from canonicalization data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed generating blind commitment: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to canonicalize document: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1blind_1signature_1commitment(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_12023_1create_1base_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bls_blind_signature_commitment(context_json) {
+  match rust_bbs_2023_create_base_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from blind commitment data");
+        .expect("Unable to create string from base proof data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed generating blind commitment: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to create base proof: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify_1blind_1signature_1proof(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_12023_1create_1derived_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bbs_verify_blind_signature_proof(context_json) {
+  match rust_bbs_2023_create_derived_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from blind commitment verification data");
+        .expect("Unable to create string from derived proof data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed verifying blind commitment: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to create derived proof: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify_1blind_1signature_1proof(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_12023_1verify_1derived_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bls_verify_blind_signature_proof(context_json) {
+  match rust_bbs_2023_verify_derived_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from blind commitment verification data");
+        .expect("Unable to create string from derived proof verification data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed verifying blind commitment: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to verify derived proof: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1blind_1sign(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_jwp_1issue(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
 ) -> jstring {
-  let context_bytes;
-  match env.convert_byte_array(ctx) {
+  let context_bytes = match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bbs_blind_sign(context_json) {
+  match rust_jwp_issue(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from blind signature data");
+        .expect("Unable to create string from JWP issuance data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed generating blind signature: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to issue JWP: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1blind_1sign(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_jwp_1present(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_jwp_present(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from JWP presentation data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to present JWP: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_jwp_1verify(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_jwp_verify(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from JWP verification data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to verify JWP: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1create_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
@@ -732,35 +801,30 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1blind_1sign(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bls_blind_sign(context_json) {
+  match rust_bbs_create_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from blind signature data");
+        .expect("Unable to create string from BBS proof data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed generating blind signature: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1get_1unblinded_1signature(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1create_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
@@ -768,35 +832,30 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1get_1unblinded_1signature(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bbs_unblind_signature(context_json) {
+  match rust_bls_create_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from unblind signature data");
+        .expect("Unable to create string from BBS proof data");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed unblinding blinded signature: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed generating proof of knowledge: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
@@ -804,35 +863,30 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bbs_verify(context_json) {
+  match rust_bbs_verify_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from signature verification outcome");
+        .expect("Unable to create string from BBS verification outcome");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed verifying messages: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), error, env); }
   }
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify(
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify_1proof(
   env: JNIEnv,
   _class: JClass,
   ctx: jbyteArray,
@@ -840,28 +894,946 @@ pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify(
   let context_bytes;
   match env.convert_byte_array(ctx) {
     Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
-    Ok(bc) => context_bytes = bc,
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
   };
 
-  // convert JSON string to JSON
-  let context_json: Value = match String::from_utf8(context_bytes.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context) => context,
-        Err(_) => { handle_err!("Failed parsing JSON context", env); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", env); }
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
   };
 
-  match rust_bls_verify(context_json) {
+  match rust_bls_verify_proof(context_json) {
     Ok(output_string) => {
       let output = env
         .new_string(output_string)
-        .expect("Unable to create string from signature verification outcome");
+        .expect("Unable to create string from BLS verification outcome");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed verifying proof of knowledge: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1create_1presentation(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_create_presentation(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from presentation data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to create presentation: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1create_1presentation(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_create_presentation(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from presentation data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to create presentation: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify_1presentation(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_verify_presentation(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from presentation verification data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to verify presentation: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify_1presentation(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_verify_presentation(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from presentation verification data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to verify presentation: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1cose_1credential(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_cose_credential(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from COSE credential data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to create COSE credential: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1cose_1presentation(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_cose_presentation(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from COSE presentation data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to create COSE presentation: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1schema_1hash(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_schema_hash(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from schema hash data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to hash schema: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1blind_1signature_1commitment(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_blind_signature_commitment(context_json) {
+    Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
+      let output = env
+        .new_string(output_string.as_str())
+        .expect("Unable to create string from blind commitment data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed generating blind commitment: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1blind_1signature_1commitment(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_blind_signature_commitment(context_json) {
+    Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
+      let output = env
+        .new_string(output_string.as_str())
+        .expect("Unable to create string from blind commitment data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed generating blind commitment: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify_1blind_1signature_1proof(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_verify_blind_signature_proof(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from blind commitment verification data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed verifying blind commitment: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify_1blind_1signature_1proof(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_verify_blind_signature_proof(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from blind commitment verification data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed verifying blind commitment: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1blind_1sign(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_blind_sign(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from blind signature data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed generating blind signature: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1blind_1sign(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_blind_sign(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from blind signature data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed generating blind signature: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1get_1unblinded_1signature(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_unblind_signature(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from unblind signature data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed unblinding blinded signature: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1verify(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_verify(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from signature verification outcome");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed verifying messages: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1verify(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes;
+  match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => context_bytes = Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_verify(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from signature verification outcome");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed verifying messages: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1public_1key_1cache_1warm(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_public_key_cache_warm(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from public key cache data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to warm public key cache: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1public_1key_1cache_1clear(
+  _: JNIEnv,
+  _class: JClass,
+) -> jint {
+  rust_bls_public_key_cache_clear()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1keystore_1import(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_keystore_import(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from key handle data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to import key into keystore: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1keystore_1generate(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_keystore_generate(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from key handle data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to generate keystore key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1keystore_1public_1key(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_keystore_public_key(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from public key data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to get keystore public key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1keystore_1destroy(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_keystore_destroy(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from destroy outcome data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to destroy keystore key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1export_1encrypted_1key(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_export_encrypted_key(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from encrypted key data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to export encrypted key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1import_1encrypted_1key(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_import_encrypted_key(context_json) {
+    Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
+      let output = env
+        .new_string(output_string.as_str())
+        .expect("Unable to create string from imported key data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to import encrypted key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1key_1to_1jwk(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_key_to_jwk(context_json) {
+    Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
+      let output = env
+        .new_string(output_string.as_str())
+        .expect("Unable to create string from JWK data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to convert key to JWK: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1jwk_1to_1key(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_jwk_to_key(context_json) {
+    Ok(output_string) => {
+      let output_string = Zeroizing::new(output_string);
+      let output = env
+        .new_string(output_string.as_str())
+        .expect("Unable to create string from BLS key data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to convert JWK to key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1public_1key_1to_1multikey(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_public_key_to_multikey(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from Multikey data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to encode Multikey: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1multikey_1to_1public_1key(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_multikey_to_public_key(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from public key data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to decode Multikey: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1public_1key_1to_1did_1key(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_public_key_to_did_key(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from did:key data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to create did:key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bls_1resolve_1did_1key(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bls_resolve_did_key(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from did:key resolution data");
+
+      output.into_inner()
+    }
+    Err(error) => { handle_err!(format!("Failed to resolve did:key: {:?}", error), error, env); }
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_life_nuggets_rs_Bbs_bbs_1set_1limits(
+  env: JNIEnv,
+  _class: JClass,
+  ctx: jbyteArray,
+) -> jstring {
+  let context_bytes = match env.convert_byte_array(ctx) {
+    Err(_) => { handle_err!("Failed converting `ctx` to byte array", env); }
+    Ok(bc) => Zeroizing::new(bc),
+  };
+
+  // parse the JSON (or CBOR) context
+  let context_json: Value = match parse_context(&context_bytes) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, env); }
+  };
+
+  match rust_bbs_set_limits(context_json) {
+    Ok(output_string) => {
+      let output = env
+        .new_string(output_string)
+        .expect("Unable to create string from limits");
 
       output.into_inner()
     }
-    Err(error) => { handle_err!(format!("Failed verifying messages: {:?}", error), env); }
+    Err(error) => { handle_err!(format!("Failed to set limits: {:?}", error), error, env); }
   }
 }
@@ -1,11 +1,22 @@
 macro_rules! handle_err {
     ($e:expr, $env:expr) => {
-        let err = json!({
+        let message = $e.to_string();
+        handle_err!(message, message, $env);
+    };
+    // the code is read from the error, not from a message framing it
+    ($e:expr, $error:expr, $env:expr) => {
+        let code = crate::rust_bbs::error_code(&$error);
+        let message = $e.to_string();
+        let mut err = json!({
           "error": {
             "name": "RustError",
-            "message": $e
+            "message": message
           }
         });
+        // e.g. a limit was exceeded
+        if let Some(code) = code {
+          err["error"]["code"] = json!(code);
+        }
         let output;
         match serde_json::to_string(&err) {
           Ok(err_string) => {
@@ -17,12 +17,30 @@ use crate::rust_bbs::{
   rust_bls_public_key_to_bbs_key,
   rust_bbs_sign,
   rust_bls_sign,
+  rust_bbs_ietf_sign,
+  rust_bbs_ietf_verify,
+  rust_bbs_ietf_create_proof,
+  rust_bbs_ietf_verify_proof,
+  rust_rdf_canonize,
+  rust_bbs_2023_create_base_proof,
+  rust_bbs_2023_create_derived_proof,
+  rust_bbs_2023_verify_derived_proof,
+  rust_jwp_issue,
+  rust_jwp_present,
+  rust_jwp_verify,
   rust_bbs_verify,
   rust_bls_verify,
   rust_bbs_create_proof,
   rust_bls_create_proof,
   rust_bbs_verify_proof,
   rust_bls_verify_proof,
+  rust_bbs_create_presentation,
+  rust_bls_create_presentation,
+  rust_bbs_verify_presentation,
+  rust_bls_verify_presentation,
+  rust_bbs_cose_credential,
+  rust_bbs_cose_presentation,
+  rust_bbs_schema_hash,
   rust_bbs_blind_signature_commitment,
   rust_bls_blind_signature_commitment,
   rust_bbs_verify_blind_signature_proof,
@@ -30,8 +48,28 @@ use crate::rust_bbs::{
   rust_bbs_blind_sign,
   rust_bls_blind_sign,
   rust_bbs_unblind_signature,
+  rust_bls_public_key_cache_warm,
+  rust_bls_public_key_cache_clear,
+  rust_bls_keystore_import,
+  rust_bls_keystore_generate,
+  rust_bls_keystore_public_key,
+  rust_bls_keystore_destroy,
+  rust_bls_export_encrypted_key,
+  rust_bls_import_encrypted_key,
+  rust_bls_key_to_jwk,
+  rust_bls_jwk_to_key,
+  rust_bls_public_key_to_multikey,
+  rust_bls_multikey_to_public_key,
+  rust_bls_public_key_to_did_key,
+  rust_bls_resolve_did_key,
+  rust_bbs_set_limits,
+  parse_context,
+  rust_call_cbor,
+  rust_bbs_get_schema,
+  rust_bbs_library_info,
 };
 use serde_json::{json};
+use zeroize::Zeroizing;
 
 /// Get size of G1 public key
 fn node_bls_public_key_g1_size(mut cx: FunctionContext) -> JsResult<JsNumber> {
@@ -69,24 +107,48 @@ fn node_bbs_blind_signature_size(mut cx: FunctionContext) -> JsResult<JsNumber>
   Ok(cx.number(size))
 }
 
+/// Call an operation by name with a CBOR (or JSON) context, returning its CBOR result
+fn node_call_cbor(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
+  let operation = cx.argument::<JsString>(0)?.value();
+  let context = arg_to_slice!(cx, 1);
+
+  let result = Zeroizing::new(rust_call_cbor(&operation, &context));
+  let mut output = cx.array_buffer(result.len() as u32)?;
+  cx.borrow_mut(&mut output, |data| data.as_mut_slice::<u8>().copy_from_slice(&result));
+  Ok(output)
+}
+
+/// Get the JSON Schemas of an operation's context and result
+fn node_bbs_get_schema(mut cx: FunctionContext) -> JsResult<JsString> {
+  let operation = cx.argument::<JsString>(0)?.value();
+
+  match rust_bbs_get_schema(&operation) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to get schema", error, cx); }
+  }
+}
+
+/// Describe the library's version, features, formats and limits
+fn node_bbs_library_info(mut cx: FunctionContext) -> JsResult<JsString> {
+  match rust_bbs_library_info() {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to get library info", error, cx); }
+  }
+}
+
 /// Generate Blinded G1 key
 fn node_bls_generate_blinded_g1_key(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_generate_blinded_g1_key(context_json) {
-    Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate blinded G1 key", cx); }
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to generate blinded G1 key", error, cx); }
   }
 }
 
@@ -94,20 +156,15 @@ fn node_bls_generate_blinded_g1_key(mut cx: FunctionContext) -> JsResult<JsStrin
 fn node_bls_generate_blinded_g2_key(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_generate_blinded_g2_key(context_json) {
-    Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate blinded G2 key", cx); }
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to generate blinded G2 key", error, cx); }
   }
 }
 
@@ -115,20 +172,15 @@ fn node_bls_generate_blinded_g2_key(mut cx: FunctionContext) -> JsResult<JsStrin
 fn node_bls_generate_g1_key(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_generate_g1_key(context_json) {
-    Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate G1 key", cx); }
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to generate G1 key", error, cx); }
   }
 }
 
@@ -136,20 +188,15 @@ fn node_bls_generate_g1_key(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bls_generate_g2_key(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_generate_g2_key(context_json) {
-    Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate G2 key", cx); }
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to generate G2 key", error, cx); }
   }
 }
 
@@ -157,20 +204,15 @@ fn node_bls_generate_g2_key(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bls_secret_key_to_bbs_key(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_secret_key_to_bbs_key(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to convert to BBS key", cx); }
+    Err(error) => { handle_err!("Unable to convert to BBS key", error, cx); }
   }
 }
 
@@ -178,20 +220,15 @@ fn node_bls_secret_key_to_bbs_key(mut cx: FunctionContext) -> JsResult<JsString>
 fn node_bls_public_key_to_bbs_key(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_public_key_to_bbs_key(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to convert to BBS key", cx); }
+    Err(error) => { handle_err!("Unable to convert to BBS key", error, cx); }
   }
 }
 
@@ -199,20 +236,15 @@ fn node_bls_public_key_to_bbs_key(mut cx: FunctionContext) -> JsResult<JsString>
 fn node_bbs_sign(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_sign(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to sign messages", cx); }
+    Err(error) => { handle_err!("Unable to sign messages", error, cx); }
   }
 }
 
@@ -220,20 +252,191 @@ fn node_bbs_sign(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bls_sign(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_sign(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to sign messages", cx); }
+    Err(error) => { handle_err!("Unable to sign messages", error, cx); }
+  }
+}
+
+/// Sign messages with an IETF BBS (BLS12-381 SHA-256) signature
+fn node_bbs_ietf_sign(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_ietf_sign(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to sign messages", error, cx); }
+  }
+}
+
+/// Verify an IETF BBS signature
+fn node_bbs_ietf_verify(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_ietf_verify(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to verify signature", error, cx); }
+  }
+}
+
+/// Create an IETF BBS proof disclosing the revealed messages
+fn node_bbs_ietf_create_proof(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_ietf_create_proof(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create proof", error, cx); }
+  }
+}
+
+/// Verify an IETF BBS proof against its revealed messages
+fn node_bbs_ietf_verify_proof(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_ietf_verify_proof(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to verify proof", error, cx); }
+  }
+}
+
+/// Canonicalize an N-Quads document with RDFC-1.0
+fn node_rdf_canonize(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_rdf_canonize(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to canonicalize document", error, cx); }
+  }
+}
+
+/// Create a bbs-2023 base proof over an N-Quads document
+fn node_bbs_2023_create_base_proof(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_2023_create_base_proof(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create base proof", error, cx); }
+  }
+}
+
+/// Derive a bbs-2023 proof selectively disclosing statements of an N-Quads document
+fn node_bbs_2023_create_derived_proof(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_2023_create_derived_proof(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create derived proof", error, cx); }
+  }
+}
+
+/// Verify a bbs-2023 derived proof over its revealed N-Quads document
+fn node_bbs_2023_verify_derived_proof(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_2023_verify_derived_proof(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to verify derived proof", error, cx); }
+  }
+}
+
+/// Issue a JSON Web Proof over payloads
+fn node_jwp_issue(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_jwp_issue(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to issue JWP", error, cx); }
+  }
+}
+
+/// Present an issued JSON Web Proof, disclosing selected payloads
+fn node_jwp_present(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_jwp_present(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to present JWP", error, cx); }
+  }
+}
+
+/// Verify an issued or presented JSON Web Proof
+fn node_jwp_verify(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_jwp_verify(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to verify JWP", error, cx); }
   }
 }
 
@@ -241,20 +444,15 @@ fn node_bls_sign(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bbs_verify(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_verify(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(error) => { handle_err!(format!("Unable to verify signed messages: {:?}", error), cx); }
+    Err(error) => { handle_err!("Unable to verify signed messages", error, cx); }
   }
 }
 
@@ -262,20 +460,15 @@ fn node_bbs_verify(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bls_verify(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_verify(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(error) => { handle_err!(format!("Unable to verify signed messages: {:?}", error), cx); }
+    Err(error) => { handle_err!("Unable to verify signed messages", error, cx); }
   }
 }
 
@@ -283,20 +476,15 @@ fn node_bls_verify(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bbs_create_proof(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_create_proof(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate proof", cx); }
+    Err(error) => { handle_err!("Unable to generate proof", error, cx); }
   }
 }
 
@@ -304,20 +492,15 @@ fn node_bbs_create_proof(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bls_create_proof(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_create_proof(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate proof", cx); }
+    Err(error) => { handle_err!("Unable to generate proof", error, cx); }
   }
 }
 
@@ -325,20 +508,127 @@ fn node_bls_create_proof(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bls_verify_proof(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_verify_proof(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to verify proof", cx); }
+    Err(error) => { handle_err!("Unable to verify proof", error, cx); }
+  }
+}
+
+/// Create a presentation bundling a BBS proof with its revealed messages
+fn node_bbs_create_presentation(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_create_presentation(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create presentation", error, cx); }
+  }
+}
+
+/// Create a presentation bundling a BBS proof with its revealed messages, for a BLS public key
+fn node_bls_create_presentation(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_create_presentation(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create presentation", error, cx); }
+  }
+}
+
+/// Verify a presentation against a trusted BBS public key
+fn node_bbs_verify_presentation(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_verify_presentation(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to verify presentation", error, cx); }
+  }
+}
+
+/// Verify a presentation against a trusted BLS public key
+fn node_bls_verify_presentation(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_verify_presentation(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to verify presentation", error, cx); }
+  }
+}
+
+/// Envelope a signature and its messages as a COSE_Sign1 credential
+fn node_bbs_cose_credential(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_cose_credential(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create COSE credential", error, cx); }
+  }
+}
+
+/// Envelope a presentation as a COSE_Sign1
+fn node_bbs_cose_presentation(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_cose_presentation(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create COSE presentation", error, cx); }
+  }
+}
+
+/// Get the hash of a claim schema, signed as its first message
+fn node_bbs_schema_hash(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_schema_hash(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to hash schema", error, cx); }
   }
 }
 
@@ -346,20 +636,15 @@ fn node_bls_verify_proof(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bbs_verify_proof(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_verify_proof(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to verify proof", cx); }
+    Err(error) => { handle_err!("Unable to verify proof", error, cx); }
   }
 }
 
@@ -367,20 +652,15 @@ fn node_bbs_verify_proof(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bbs_blind_signature_commitment(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_blind_signature_commitment(context_json) {
-    Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate blind signing commitment", cx); }
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to generate blind signing commitment", error, cx); }
   }
 }
 
@@ -388,20 +668,15 @@ fn node_bbs_blind_signature_commitment(mut cx: FunctionContext) -> JsResult<JsSt
 fn node_bls_blind_signature_commitment(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_blind_signature_commitment(context_json) {
-    Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to generate blind signing commitment", cx); }
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to generate blind signing commitment", error, cx); }
   }
 }
 
@@ -409,20 +684,15 @@ fn node_bls_blind_signature_commitment(mut cx: FunctionContext) -> JsResult<JsSt
 fn node_bbs_verify_blind_signature_proof(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_verify_blind_signature_proof(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to verify blind signing commitment", cx); }
+    Err(error) => { handle_err!("Unable to verify blind signing commitment", error, cx); }
   }
 }
 
@@ -430,20 +700,15 @@ fn node_bbs_verify_blind_signature_proof(mut cx: FunctionContext) -> JsResult<Js
 fn node_bls_verify_blind_signature_proof(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_verify_blind_signature_proof(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to verify blind signing commitment", cx); }
+    Err(error) => { handle_err!("Unable to verify blind signing commitment", error, cx); }
   }
 }
 
@@ -451,20 +716,15 @@ fn node_bls_verify_blind_signature_proof(mut cx: FunctionContext) -> JsResult<Js
 fn node_bbs_blind_sign(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_blind_sign(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to blind sign messages", cx); }
+    Err(error) => { handle_err!("Unable to blind sign messages", error, cx); }
   }
 }
 
@@ -472,20 +732,15 @@ fn node_bbs_blind_sign(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bls_blind_sign(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bls_blind_sign(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to blind sign messages", cx); }
+    Err(error) => { handle_err!("Unable to blind sign messages", error, cx); }
   }
 }
 
@@ -493,25 +748,253 @@ fn node_bls_blind_sign(mut cx: FunctionContext) -> JsResult<JsString> {
 fn node_bbs_get_unblinded_signature(mut cx: FunctionContext) -> JsResult<JsString> {
   let context = arg_to_slice!(cx, 0);
 
-  // convert JSON string to JSON
-  let context_json: serde_json::Value = match String::from_utf8(context.to_vec()) {
-    Ok(context_string) => {
-      match serde_json::from_str(&context_string) {
-        Ok(context_json) => context_json,
-        Err(_) => { handle_err!("Failed parsing JSON for context", cx); }
-      }
-    },
-    Err(_) => { handle_err!("Context not set", cx); }
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
   };
 
   match rust_bbs_unblind_signature(context_json) {
     Ok(output_string) => Ok(cx.string(output_string)),
-    Err(_) => { handle_err!("Unable to unblind blinded signature", cx); }
+    Err(error) => { handle_err!("Unable to unblind blinded signature", error, cx); }
+  }
+}
+
+/// Pre-derive BBS public keys for a BLS public key
+fn node_bls_public_key_cache_warm(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_public_key_cache_warm(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to warm public key cache", error, cx); }
+  }
+}
+
+/// Clear cached BBS public keys
+fn node_bls_public_key_cache_clear(mut cx: FunctionContext) -> JsResult<JsNumber> {
+  let cleared = rust_bls_public_key_cache_clear();
+  Ok(cx.number(cleared))
+}
+
+/// Import a BLS secret key into the keystore, returning its key handle
+fn node_bls_keystore_import(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_keystore_import(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to import key into keystore", error, cx); }
+  }
+}
+
+/// Generate a BLS G2 key inside the keystore, returning its key handle
+fn node_bls_keystore_generate(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_keystore_generate(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to generate keystore key", error, cx); }
+  }
+}
+
+/// Get the BLS public key for a keystore key handle
+fn node_bls_keystore_public_key(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_keystore_public_key(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to get keystore public key", error, cx); }
+  }
+}
+
+/// Remove a key from the keystore, wiping its secret key
+fn node_bls_keystore_destroy(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_keystore_destroy(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to destroy keystore key", error, cx); }
+  }
+}
+
+/// Export a BLS secret key, encrypted under a password
+fn node_bls_export_encrypted_key(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_export_encrypted_key(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to export encrypted key", error, cx); }
+  }
+}
+
+/// Import a BLS secret key from a password encrypted export
+fn node_bls_import_encrypted_key(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_import_encrypted_key(context_json) {
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to import encrypted key", error, cx); }
+  }
+}
+
+/// Convert a BLS key pair to a JWK
+fn node_bls_key_to_jwk(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_key_to_jwk(context_json) {
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to convert key to JWK", error, cx); }
+  }
+}
+
+/// Convert a BLS12-381 JWK to a BLS key pair
+fn node_bls_jwk_to_key(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_jwk_to_key(context_json) {
+    Ok(output_string) => Ok(cx.string(Zeroizing::new(output_string).as_str())),
+    Err(error) => { handle_err!("Unable to convert JWK to key", error, cx); }
+  }
+}
+
+/// Encode a BLS public key as a multibase Multikey
+fn node_bls_public_key_to_multikey(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_public_key_to_multikey(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to encode Multikey", error, cx); }
+  }
+}
+
+/// Decode a multibase Multikey to a BLS public key
+fn node_bls_multikey_to_public_key(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_multikey_to_public_key(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to decode Multikey", error, cx); }
+  }
+}
+
+/// Create a did:key from a BLS G2 public key
+fn node_bls_public_key_to_did_key(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_public_key_to_did_key(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to create did:key", error, cx); }
+  }
+}
+
+/// Resolve a did:key locally to its BLS G2 public key
+fn node_bls_resolve_did_key(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bls_resolve_did_key(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to resolve did:key", error, cx); }
+  }
+}
+
+/// Set the limits on the input of every later call
+fn node_bbs_set_limits(mut cx: FunctionContext) -> JsResult<JsString> {
+  let context = arg_to_slice!(cx, 0);
+
+  // parse the JSON (or CBOR) context
+  let context_json: serde_json::Value = match parse_context(&context) {
+    Ok(context_json) => context_json,
+    Err(error) => { handle_err!(error, cx); }
+  };
+
+  match rust_bbs_set_limits(context_json) {
+    Ok(output_string) => Ok(cx.string(output_string)),
+    Err(error) => { handle_err!("Unable to set limits", error, cx); }
   }
 }
 
 register_module!(mut cx, {
   cx.export_function("bbs_blind_signature_size", node_bbs_blind_signature_size)?;
+  cx.export_function("call_cbor", node_call_cbor)?;
+  cx.export_function("bbs_get_schema", node_bbs_get_schema)?;
+  cx.export_function("bbs_library_info", node_bbs_library_info)?;
   cx.export_function("bbs_blinding_factor_size", node_bbs_blinding_factor_size)?;
   cx.export_function("bls_public_key_g1_size", node_bls_public_key_g1_size)?;
   cx.export_function("bls_public_key_g2_size", node_bls_public_key_g2_size)?;
@@ -525,12 +1008,30 @@ register_module!(mut cx, {
   cx.export_function("bls_public_key_to_bbs_key", node_bls_public_key_to_bbs_key)?;
   cx.export_function("bbs_sign", node_bbs_sign)?;
   cx.export_function("bls_sign", node_bls_sign)?;
+  cx.export_function("bbs_ietf_sign", node_bbs_ietf_sign)?;
+  cx.export_function("bbs_ietf_verify", node_bbs_ietf_verify)?;
+  cx.export_function("bbs_ietf_create_proof", node_bbs_ietf_create_proof)?;
+  cx.export_function("bbs_ietf_verify_proof", node_bbs_ietf_verify_proof)?;
+  cx.export_function("rdf_canonize", node_rdf_canonize)?;
+  cx.export_function("bbs_2023_create_base_proof", node_bbs_2023_create_base_proof)?;
+  cx.export_function("bbs_2023_create_derived_proof", node_bbs_2023_create_derived_proof)?;
+  cx.export_function("bbs_2023_verify_derived_proof", node_bbs_2023_verify_derived_proof)?;
+  cx.export_function("jwp_issue", node_jwp_issue)?;
+  cx.export_function("jwp_present", node_jwp_present)?;
+  cx.export_function("jwp_verify", node_jwp_verify)?;
   cx.export_function("bbs_verify", node_bbs_verify)?;
   cx.export_function("bls_verify", node_bls_verify)?;
   cx.export_function("bbs_create_proof", node_bbs_create_proof)?;
   cx.export_function("bls_create_proof", node_bls_create_proof)?;
   cx.export_function("bbs_verify_proof", node_bbs_verify_proof)?;
   cx.export_function("bls_verify_proof", node_bls_verify_proof)?;
+  cx.export_function("bbs_create_presentation", node_bbs_create_presentation)?;
+  cx.export_function("bls_create_presentation", node_bls_create_presentation)?;
+  cx.export_function("bbs_verify_presentation", node_bbs_verify_presentation)?;
+  cx.export_function("bls_verify_presentation", node_bls_verify_presentation)?;
+  cx.export_function("bbs_cose_credential", node_bbs_cose_credential)?;
+  cx.export_function("bbs_cose_presentation", node_bbs_cose_presentation)?;
+  cx.export_function("bbs_schema_hash", node_bbs_schema_hash)?;
   cx.export_function(
       "bbs_blind_signature_commitment",
       node_bbs_blind_signature_commitment,
@@ -550,5 +1051,20 @@ register_module!(mut cx, {
   cx.export_function("bbs_blind_sign", node_bbs_blind_sign)?;
   cx.export_function("bls_blind_sign", node_bls_blind_sign)?;
   cx.export_function("bbs_get_unblinded_signature", node_bbs_get_unblinded_signature)?;
+  cx.export_function("bls_public_key_cache_warm", node_bls_public_key_cache_warm)?;
+  cx.export_function("bls_public_key_cache_clear", node_bls_public_key_cache_clear)?;
+  cx.export_function("bls_keystore_import", node_bls_keystore_import)?;
+  cx.export_function("bls_keystore_generate", node_bls_keystore_generate)?;
+  cx.export_function("bls_keystore_public_key", node_bls_keystore_public_key)?;
+  cx.export_function("bls_keystore_destroy", node_bls_keystore_destroy)?;
+  cx.export_function("bls_export_encrypted_key", node_bls_export_encrypted_key)?;
+  cx.export_function("bls_import_encrypted_key", node_bls_import_encrypted_key)?;
+  cx.export_function("bls_key_to_jwk", node_bls_key_to_jwk)?;
+  cx.export_function("bls_jwk_to_key", node_bls_jwk_to_key)?;
+  cx.export_function("bls_public_key_to_multikey", node_bls_public_key_to_multikey)?;
+  cx.export_function("bls_multikey_to_public_key", node_bls_multikey_to_public_key)?;
+  cx.export_function("bls_public_key_to_did_key", node_bls_public_key_to_did_key)?;
+  cx.export_function("bls_resolve_did_key", node_bls_resolve_did_key)?;
+  cx.export_function("bbs_set_limits", node_bbs_set_limits)?;
   Ok(())
 });
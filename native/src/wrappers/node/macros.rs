@@ -2,18 +2,44 @@
 macro_rules! arg_to_slice {
   ($cx:expr, $i:expr) => {{
       let arg: Handle<JsArrayBuffer> = $cx.argument::<JsArrayBuffer>($i)?;
-      $cx.borrow(&arg, |d| d.as_slice::<u8>()).to_vec()
+      Zeroizing::new($cx.borrow(&arg, |d| d.as_slice::<u8>()).to_vec())
   }};
 }
 
 macro_rules! handle_err {
+  ($e:expr, $detail:expr, $cx:expr) => {
+      // the code is read from the error, not from its framed message
+      let code = crate::rust_bbs::error_code(&$detail);
+      let detail = $detail.to_string();
+      let mut err = json!({
+        "error": {
+          "name": "RustError",
+          "message": $e,
+          "detail": detail
+        }
+      });
+      // e.g. a limit was exceeded
+      if let Some(code) = code {
+        err["error"]["code"] = json!(code);
+      }
+
+      match serde_json::to_string(&err) {
+        Ok(err_string) => { return Ok($cx.string(err_string)) },
+        Err(_) => { return Ok($cx.string("Unable to create string for error")) } 
+      }
+  };
   ($e:expr, $cx:expr) => {
-      let err = json!({
+      let message = $e.to_string();
+      let mut err = json!({
         "error": {
           "name": "RustError",
-          "message": $e
+          "message": message
         }
       });
+      // e.g. a limit was exceeded
+      if let Some(code) = crate::rust_bbs::error_code(&message) {
+        err["error"]["code"] = json!(code);
+      }
 
       match serde_json::to_string(&err) {
         Ok(err_string) => { return Ok($cx.string(err_string)) },
@@ -0,0 +1,373 @@
+//! IETF BBS (BLS12-381 SHA-256) generator, scalar, signature and mocked-RNG proof vectors,
+//! RDFC-1.0 canonicalization examples and `bbs-2023` proof round trips, all offline.
+
+use bbs::rust_bbs::curve::{CurveBackend, DefaultBackend, Group, Scalar};
+use bbs::rust_bbs::ietf_bbs;
+use bbs::rust_bbs::{
+  rust_bbs_2023_create_base_proof,
+  rust_bbs_2023_create_derived_proof,
+  rust_bbs_2023_verify_derived_proof,
+  rust_bbs_ietf_create_proof,
+  rust_bbs_ietf_sign,
+  rust_bbs_ietf_verify,
+  rust_bbs_ietf_verify_proof,
+  rust_rdf_canonize,
+};
+use pairing_plus::hash_to_field::{ExpandMsg, ExpandMsgXmd};
+use rand::{CryptoRng, RngCore};
+use serde_json::{Value, json};
+
+// draft-irtf-cfrg-bbs-signatures, BLS12-381-SHA-256 fixtures
+const SECRET_KEY: &str = "60e55110f76883a13d030b2f6bd11883422d5abde717569fc0731f51237169fc";
+const PUBLIC_KEY: &str = "a820f230f6ae38503b86c70dc50b61c58a77e45c39ab25c0652bbaa8fa136f2851bd4781c9dcde39fc9d1d52c9e60268061e7d7632171d91aa8d460acee0e96f1e7c4cfb12d3ff9ab5d5dc91c277db75c845d649ef3c4f63aebc364cd55ded0c";
+const HEADER: &str = "11223344556677889900aabbccddeeff";
+const MESSAGES: [&str; 10] = [
+  "9872ad089e452c7b6e283dfac2a80d58e8d0ff71cc4d5e310a1debdda4a45f02",
+  "c344136d9ab02da4dd5908bbba913ae6f58c2cc844b802a6f811f5fb075f9b80",
+  "7372e9daa5ed31e6cd5c825eac1b855e84476a1d94932aa348e07b73",
+  "77fe97eb97a1ebe2e81e4e3597a3ee740a66e9ef2412472c",
+  "496694774c5604ab1b2544eababcf0f53278ff50",
+  "515ae153e22aae04ad16f759e07237b4",
+  "d183ddc6e2665aa4e2f088af",
+  "ac55fb33a75909ed",
+  "96012096",
+  "",
+];
+const SINGLE_MESSAGE_SIGNATURE: &str = "84773160b824e194073a57493dac1a20b667af70cd2352d8af241c77658da5253aa8458317cca0eae615690d55b1f27164657dcafee1d5c1973947aa70e2cfbb4c892340be5969920d0916067b4565a0";
+const MULTI_MESSAGE_SIGNATURE: &str = "8339b285a4acd89dec7777c09543a43e3cc60684b0a6f8ab335da4825c96e1463e28f8c5f4fd0641d19cec5920d3a8ff4bedb6c9691454597bbd298288abed3632078557b2ace7d44caed846e1a0a1e8";
+
+const P1: &str = "a8ce256102840821a3e94ea9025e4662b205762f9776b3a766c872b948f1fd225e7c59698588e70d11406d161b4e28c9";
+const Q_1: &str = "a9ec65b70a7fbe40c874c9eb041c2cb0a7af36ccec1bea48fa2ba4c2eb67ef7f9ecb17ed27d38d27cdeddff44c8137be";
+const H: [&str; 10] = [
+  "98cd5313283aaf5db1b3ba8611fe6070d19e605de4078c38df36019fbaad0bd28dd090fd24ed27f7f4d22d5ff5dea7d4",
+  "a31fbe20c5c135bcaa8d9fc4e4ac665cc6db0226f35e737507e803044093f37697a9d452490a970eea6f9ad6c3dcaa3a",
+  "b479263445f4d2108965a9086f9d1fdc8cde77d14a91c856769521ad3344754cc5ce90d9bc4c696dffbc9ef1d6ad1b62",
+  "ac0401766d2128d4791d922557c7b4d1ae9a9b508ce266575244a8d6f32110d7b0b7557b77604869633bb49afbe20035",
+  "b95d2898370ebc542857746a316ce32fa5151c31f9b57915e308ee9d1de7db69127d919e984ea0747f5223821b596335",
+  "8f19359ae6ee508157492c06765b7df09e2e5ad591115742f2de9c08572bb2845cbf03fd7e23b7f031ed9c7564e52f39",
+  "abc914abe2926324b2c848e8a411a2b6df18cbe7758db8644145fefb0bf0a2d558a8c9946bd35e00c69d167aadf304c1",
+  "80755b3eb0dd4249cbefd20f177cee88e0761c066b71794825c9997b551f24051c352567ba6c01e57ac75dff763eaa17",
+  "82701eb98070728e1769525e73abff1783cedc364adb20c05c897a62f2ab2927f86f118dcb7819a7b218d8f3fee4bd7f",
+  "a1f229540474f4d6f1134761b92b788128c7ac8dc9b0c52d59493132679673032ac7db3fb3d79b46b13c1c41ee495bca",
+];
+
+// `hash_to_scalar` of the first message with the `H2S_` tag, and `messages_to_scalars` of each
+const HASH_TO_SCALAR: &str = "0f90cbee27beb214e6545becb8404640d3612da5d6758dffeccd77ed7169807c";
+const MESSAGE_SCALARS: [&str; 10] = [
+  "1cb5bb86114b34dc438a911617655a1db595abafac92f47c5001799cf624b430",
+  "154249d503c093ac2df516d4bb88b510d54fd97e8d7121aede420a25d9521952",
+  "0c7c4c85cdab32e6fdb0de267b16fa3212733d4e3a3f0d0f751657578b26fe22",
+  "4a196deafee5c23f630156ae13be3e46e53b7e39094d22877b8cba7f14640888",
+  "34c5ea4f2ba49117015a02c711bb173c11b06b3f1571b88a2952b93d0ed4cf7e",
+  "4045b39b83055cd57a4d0203e1660800fabe434004dbdc8730c21ce3f0048b08",
+  "064621da4377b6b1d05ecc37cf3b9dfc94b9498d7013dc5c4a82bf3bb1750743",
+  "34ac9196ace0a37e147e32319ea9b3d8cc7d21870d3c3ba071246859cca49b02",
+  "57eb93f417c43200e9784fa5ea5a59168d3dbc38df707a13bb597c871b2a5f74",
+  "08e3afeb2b4f2b5f907924ef42856616e6f2d5f1fb373736db1cca32707a7d16",
+];
+
+// proofs with the draft's mocked random scalars
+const MOCK_RANDOM_SCALARS_SEED: &[u8] = b"3.141592653589793238462643383279";
+const PRESENTATION_HEADER: &str = "bed231d880675ed101ead304512e043ade9958dd0241ea70b4b3957fba941501";
+const SINGLE_MESSAGE_PROOF: &str = "94916292a7a6bade28456c601d3af33fcf39278d6594b467e128a3f83686a104ef2b2fcf72df0215eeaf69262ffe8194a19fab31a82ddbe06908985abc4c9825788b8a1610942d12b7f5debbea8985296361206dbace7af0cc834c80f33e0aadaeea5597befbb651827b5eed5a66f1a959bb46cfd5ca1a817a14475960f69b32c54db7587b5ee3ab665fbd37b506830a49f21d592f5e634f47cee05a025a2f8f94e73a6c15f02301d1178a92873b6e8634bafe4983c3e15a663d64080678dbf29417519b78af042be2b3e1c4d08b8d520ffab008cbaaca5671a15b22c239b38e940cfeaa5e72104576a9ec4a6fad78c532381aeaa6fb56409cef56ee5c140d455feeb04426193c57086c9b6d397d9418";
+const ALL_MESSAGES_PROOF: &str = "b1f468aec2001c4f54cb56f707c6222a43e5803a25b2253e67b2210ab2ef9eab52db2d4b379935c4823281eaf767fd37b08ce80dc65de8f9769d27099ae649ad4c9b4bd2cc23edcba52073a298087d2495e6d57aaae051ef741adf1cbce65c64a73c8c97264177a76c4a03341956d2ae45ed3438ce598d5cda4f1bf9507fecef47855480b7b30b5e4052c92a4360110c67327365763f5aa9fb85ddcbc2975449b8c03db1216ca66b310f07d0ccf12ab460cdc6003b677fed36d0a23d0818a9d4d098d44f749e91008cf50e8567ef936704c8277b7710f41ab7e6e16408ab520edc290f9801349aee7b7b4e318e6a76e028e1dea911e2e7baec6a6a174da1a22362717fbae1cd961d7bf4adce1d31c2ab";
+const HALF_MESSAGES_PROOF: &str = "a2ed608e8e12ed21abc2bf154e462d744a367c7f1f969bdbf784a2a134c7db2d340394223a5397a3011b1c340ebc415199462ba6f31106d8a6da8b513b37a47afe93c9b3474d0d7a354b2edc1b88818b063332df774c141f7a07c48fe50d452f897739228c88afc797916dca01e8f03bd9c5375c7a7c59996e514bb952a436afd24457658acbaba5ddac2e693ac481356918cd38025d86b28650e909defe9604a7259f44386b861608be742af7775a2e71a6070e5836f5f54dc43c60096834a5b6da295bf8f081f72b7cdf7f3b4347fb3ff19edaa9e74055c8ba46dbcb7594fb2b06633bb5324192eb9be91be0d33e453b4d3127459de59a5e2193c900816f049a02cb9127dac894418105fa1641d5a206ec9c42177af9316f433417441478276ca0303da8f941bf2e0222a43251cf5c2bf6eac1961890aa740534e519c1767e1223392a3a286b0f4d91f7f25217a7862b8fcc1810cdcfddde2a01c80fcc90b632585fec12dc4ae8fea1918e9ddeb9414623a457e88f53f545841f9d5dcb1f8e160d1560770aa79d65e2eca8edeaecb73fb7e995608b820c4a64de6313a370ba05dc25ed7c1d185192084963652f2870341bdaa4b1a37f8c06348f38a4f80c5a2650a21d59f09e8305dcd3fc3ac30e2a";
+
+// RDFC-1.0 examples with unique and shared first degree hashes
+const UNIQUE_HASHES: &str = "\
+<http://example.com/#p> <http://example.com/#q> _:e0 .
+<http://example.com/#p> <http://example.com/#r> _:e1 .
+_:e0 <http://example.com/#s> <http://example.com/#u> .
+_:e1 <http://example.com/#t> <http://example.com/#u> .
+";
+const UNIQUE_HASHES_CANONICAL: &str = "\
+<http://example.com/#p> <http://example.com/#q> _:c14n0 .
+<http://example.com/#p> <http://example.com/#r> _:c14n1 .
+_:c14n0 <http://example.com/#s> <http://example.com/#u> .
+_:c14n1 <http://example.com/#t> <http://example.com/#u> .
+";
+const SHARED_HASHES: &str = "\
+<http://example.com/#p> <http://example.com/#q> _:e0 .
+<http://example.com/#p> <http://example.com/#q> _:e1 .
+_:e0 <http://example.com/#p> _:e2 .
+_:e1 <http://example.com/#p> _:e3 .
+_:e2 <http://example.com/#r> _:e3 .
+";
+const SHARED_HASHES_CANONICAL: &str = "\
+<http://example.com/#p> <http://example.com/#q> _:c14n2 .
+<http://example.com/#p> <http://example.com/#q> _:c14n3 .
+_:c14n0 <http://example.com/#r> _:c14n1 .
+_:c14n2 <http://example.com/#p> _:c14n1 .
+_:c14n3 <http://example.com/#p> _:c14n0 .
+";
+
+const CREDENTIAL: &str = r#"
+_:credential <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+_:credential <https://www.w3.org/2018/credentials#issuer> <did:example:issuer> .
+_:credential <https://www.w3.org/2018/credentials#credentialSubject> _:subject .
+_:subject <http://schema.org/name> "Alice \"Al\" Smith" .
+_:subject <http://schema.org/birthDate> "1990-01-01"^^<http://www.w3.org/2001/XMLSchema#date> .
+_:subject <http://schema.org/address> _:address .
+_:address <http://schema.org/addressCountry> "GB"@en .
+_:address <http://schema.org/postalCode> "AB1 2CD" .
+"#;
+const PROOF_CONFIG: &str = r#"
+_:proof <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> .
+_:proof <https://w3id.org/security#cryptosuite> "bbs-2023"^^<https://w3id.org/security#cryptosuiteString> .
+_:proof <https://w3id.org/security#verificationMethod> <did:example:issuer#key-1> .
+"#;
+
+fn hex_to_base64(hex: &str) -> String {
+  let bytes: Vec<u8> = (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect();
+  base64::encode(bytes)
+}
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+  base64::decode(hex_to_base64(hex)).unwrap()
+}
+
+fn messages() -> Vec<Vec<u8>> {
+  MESSAGES.iter().map(|message| hex_to_bytes(message)).collect()
+}
+
+/// The draft's `mocked_calculate_random_scalars`: random scalars from uniform bytes expanded from
+/// a fixed seed, which `proof_gen` reads 48 bytes at a time
+struct MockedRng {
+  bytes: Vec<u8>,
+  read: usize,
+}
+
+impl MockedRng {
+  fn new(count: usize) -> MockedRng {
+    let dst = ietf_bbs::dst(b"MOCK_RANDOM_SCALARS_DST_");
+    let bytes = ExpandMsgXmd::<sha2::Sha256>::expand_message(MOCK_RANDOM_SCALARS_SEED, &dst, 48 * count);
+    MockedRng { bytes, read: 0 }
+  }
+}
+
+impl RngCore for MockedRng {
+  fn next_u32(&mut self) -> u32 {
+    unimplemented!()
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    unimplemented!()
+  }
+
+  fn fill_bytes(&mut self, dest: &mut [u8]) {
+    dest.copy_from_slice(&self.bytes[self.read..self.read + dest.len()]);
+    self.read += dest.len();
+  }
+
+  fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+    self.fill_bytes(dest);
+    Ok(())
+  }
+}
+
+impl CryptoRng for MockedRng {}
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn canonize(document: &str) -> String {
+  call(rust_rdf_canonize, json!({ "document": document }))["document"].as_str().unwrap().to_string()
+}
+
+fn statement_index(canonical: &str, needle: &str) -> usize {
+  canonical.lines().position(|line| line.contains(needle)).unwrap()
+}
+
+#[test]
+fn ietf_sign_matches_single_message_fixture() {
+  let signature = call(rust_bbs_ietf_sign, json!({
+    "secret_key": hex_to_base64(SECRET_KEY),
+    "header": hex_to_base64(HEADER),
+    "messages": [hex_to_base64(MESSAGES[0])],
+  }));
+  assert_eq!(signature["signature"], hex_to_base64(SINGLE_MESSAGE_SIGNATURE));
+
+  let verified = call(rust_bbs_ietf_verify, json!({
+    "public_key": hex_to_base64(PUBLIC_KEY),
+    "signature": hex_to_base64(SINGLE_MESSAGE_SIGNATURE),
+    "header": hex_to_base64(HEADER),
+    "messages": [hex_to_base64(MESSAGES[0])],
+  }));
+  assert_eq!(verified["verified"], true);
+
+  let tampered = call(rust_bbs_ietf_verify, json!({
+    "public_key": hex_to_base64(PUBLIC_KEY),
+    "signature": hex_to_base64(SINGLE_MESSAGE_SIGNATURE),
+    "header": hex_to_base64(HEADER),
+    "messages": [hex_to_base64(MESSAGES[1])],
+  }));
+  assert_eq!(tampered["verified"], false);
+}
+
+#[test]
+fn ietf_generators_match_fixtures() {
+  assert_eq!(hex::encode(ietf_bbs::p1::<DefaultBackend>().to_compressed()), P1);
+
+  let (q_1, h) = ietf_bbs::message_generators::<DefaultBackend>(H.len());
+  assert_eq!(hex::encode(q_1.to_compressed()), Q_1);
+  let h: Vec<String> = h.iter().map(|generator| hex::encode(generator.to_compressed())).collect();
+  assert_eq!(h, H);
+}
+
+#[test]
+fn ietf_scalars_match_fixtures() {
+  type DefaultScalar = <DefaultBackend as CurveBackend>::Scalar;
+  let scalar: DefaultScalar = ietf_bbs::hash_to_scalar(&hex_to_bytes(MESSAGES[0]), &ietf_bbs::dst(b"H2S_"));
+  assert_eq!(hex::encode(scalar.to_bytes()), HASH_TO_SCALAR);
+
+  let scalars: Vec<String> = ietf_bbs::messages_to_scalars::<DefaultScalar>(&messages()).iter()
+    .map(|scalar| hex::encode(scalar.to_bytes()))
+    .collect();
+  assert_eq!(scalars, MESSAGE_SCALARS);
+}
+
+#[test]
+fn ietf_sign_matches_multi_message_fixture() {
+  let messages: Vec<String> = MESSAGES.iter().map(|message| hex_to_base64(message)).collect();
+  let signature = call(rust_bbs_ietf_sign, json!({
+    "secret_key": hex_to_base64(SECRET_KEY),
+    "header": hex_to_base64(HEADER),
+    "messages": messages,
+  }));
+  assert_eq!(signature["signature"], hex_to_base64(MULTI_MESSAGE_SIGNATURE));
+
+  let verified = call(rust_bbs_ietf_verify, json!({
+    "public_key": hex_to_base64(PUBLIC_KEY),
+    "signature": hex_to_base64(MULTI_MESSAGE_SIGNATURE),
+    "header": hex_to_base64(HEADER),
+    "messages": messages,
+  }));
+  assert_eq!(verified["verified"], true);
+}
+
+#[test]
+fn ietf_proofs_match_mocked_rng_fixtures() {
+  let messages = messages();
+  let public_key = hex_to_bytes(PUBLIC_KEY);
+  let (header, presentation_header) = (hex_to_bytes(HEADER), hex_to_bytes(PRESENTATION_HEADER));
+  let fixtures = [
+    (SINGLE_MESSAGE_SIGNATURE, 1, vec![0], SINGLE_MESSAGE_PROOF),
+    (MULTI_MESSAGE_SIGNATURE, 10, (0..10).collect(), ALL_MESSAGES_PROOF),
+    (MULTI_MESSAGE_SIGNATURE, 10, vec![0, 2, 4, 6], HALF_MESSAGES_PROOF),
+  ];
+
+  for (signature, message_count, revealed, expected) in fixtures.iter() {
+    let messages = &messages[..*message_count];
+    // 5 random scalars, and 1 more for each undisclosed message
+    let mut rng = MockedRng::new(5 + message_count - revealed.len());
+    let proof = ietf_bbs::proof_gen::<DefaultBackend, _>(
+      &public_key, &hex_to_bytes(signature), &header, &presentation_header, messages, revealed, &mut rng,
+    ).unwrap();
+    assert_eq!(hex::encode(&proof), *expected);
+
+    let revealed_messages: Vec<String> = revealed.iter().map(|index| base64::encode(&messages[*index])).collect();
+    let verified = call(rust_bbs_ietf_verify_proof, json!({
+      "public_key": hex_to_base64(PUBLIC_KEY),
+      "proof": hex_to_base64(expected),
+      "header": hex_to_base64(HEADER),
+      "presentation_header": hex_to_base64(PRESENTATION_HEADER),
+      "messages": revealed_messages,
+      "revealed": revealed,
+    }));
+    assert_eq!(verified["verified"], true, "{}", expected);
+  }
+}
+
+#[test]
+fn ietf_proof_discloses_selected_messages() {
+  let messages: Vec<String> = MESSAGES.iter().map(|message| hex_to_base64(message)).collect();
+  let signature = call(rust_bbs_ietf_sign, json!({
+    "secret_key": hex_to_base64(SECRET_KEY),
+    "public_key": hex_to_base64(PUBLIC_KEY),
+    "header": hex_to_base64(HEADER),
+    "messages": messages,
+  }));
+
+  let revealed = [0, 2, 4, 6];
+  let proof = call(rust_bbs_ietf_create_proof, json!({
+    "public_key": hex_to_base64(PUBLIC_KEY),
+    "signature": signature["signature"],
+    "header": hex_to_base64(HEADER),
+    "presentation_header": base64::encode("nonce"),
+    "messages": messages,
+    "revealed": revealed,
+  }));
+
+  let revealed_messages: Vec<&String> = revealed.iter().map(|index| &messages[*index]).collect();
+  let verify = |presentation_header: &str| call(rust_bbs_ietf_verify_proof, json!({
+    "public_key": hex_to_base64(PUBLIC_KEY),
+    "proof": proof["proof"],
+    "header": hex_to_base64(HEADER),
+    "presentation_header": base64::encode(presentation_header),
+    "messages": revealed_messages,
+    "revealed": revealed,
+  }))["verified"].clone();
+  assert_eq!(verify("nonce"), true);
+  assert_eq!(verify("other nonce"), false);
+}
+
+#[test]
+fn rdfc_matches_spec_examples() {
+  assert_eq!(canonize(UNIQUE_HASHES), UNIQUE_HASHES_CANONICAL);
+  assert_eq!(canonize(SHARED_HASHES), SHARED_HASHES_CANONICAL);
+}
+
+#[test]
+fn rdfc_ignores_labels_and_statement_order() {
+  let relabeled: Vec<String> = SHARED_HASHES.lines().rev()
+    .map(|line| line.replace("_:e", "_:node"))
+    .collect();
+  assert_eq!(canonize(&relabeled.join("\n")), SHARED_HASHES_CANONICAL);
+
+  let mut credential: Vec<&str> = CREDENTIAL.lines().collect();
+  credential.reverse();
+  assert_eq!(canonize(&credential.join("\n").replace("_:subject", "_:s")), canonize(CREDENTIAL));
+}
+
+#[test]
+fn bbs_2023_derived_proof_verifies() {
+  let canonical = canonize(CREDENTIAL);
+  let issuer = statement_index(&canonical, "credentials#issuer");
+  let name = statement_index(&canonical, "schema.org/name");
+  let country = statement_index(&canonical, "addressCountry");
+
+  let base_proof_context = json!({
+    "secret_key": hex_to_base64(SECRET_KEY),
+    "document": CREDENTIAL,
+    "proof_config": PROOF_CONFIG,
+    "mandatory_indexes": [issuer],
+    "hmac_key": base64::encode([7u8; 32]),
+  });
+  let base_proof = call(rust_bbs_2023_create_base_proof, base_proof_context.clone());
+  // BBS signatures are deterministic, so a base proof is too for a fixed HMAC key
+  assert_eq!(base_proof, call(rust_bbs_2023_create_base_proof, base_proof_context));
+  // index-based base proofs don't take the header of the spec's JSON Pointer based ones
+  let proof_value = base64::decode_config(&base_proof["proof_value"].as_str().unwrap()[1..], base64::URL_SAFE_NO_PAD).unwrap();
+  assert_eq!(proof_value[..3], [0xd9, 0x5d, 0x80]);
+
+  let derived = call(rust_bbs_2023_create_derived_proof, json!({
+    "document": CREDENTIAL,
+    "proof_value": base_proof["proof_value"],
+    "selective_indexes": [name, country],
+    "presentation_header": base64::encode("nonce"),
+  }));
+  let revealed = derived["document"].as_str().unwrap();
+  assert_eq!(revealed.lines().count(), 3);
+  assert!(!revealed.contains("birthDate") && !revealed.contains("postalCode"));
+
+  let verify = |document: &str, proof_config: &str| call(rust_bbs_2023_verify_derived_proof, json!({
+    "public_key": hex_to_base64(PUBLIC_KEY),
+    "document": document,
+    "proof_value": derived["proof_value"],
+    "proof_config": proof_config,
+  }))["verified"].clone();
+  assert_eq!(verify(revealed, PROOF_CONFIG), true);
+  assert_eq!(verify(&revealed.replace("GB", "FR"), PROOF_CONFIG), false);
+  assert_eq!(verify(revealed, &PROOF_CONFIG.replace("key-1", "key-2")), false);
+}
@@ -0,0 +1,209 @@
+//! CBOR contexts and results, and COSE envelopes for credentials and presentations.
+
+use bbs::rust_bbs::{
+  rust_bbs_cose_credential,
+  rust_bbs_cose_presentation,
+  rust_bbs_ietf_create_proof,
+  rust_bbs_ietf_sign,
+  rust_bbs_ietf_verify,
+  rust_bbs_ietf_verify_proof,
+  rust_bls_create_presentation,
+  rust_bls_generate_g2_key,
+  rust_bls_sign,
+  rust_bls_verify_presentation,
+  rust_call_cbor,
+};
+use ciborium::value::Value as Cbor;
+use serde_json::{Value, json};
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn to_cbor(value: &Cbor) -> Vec<u8> {
+  let mut bytes = Vec::new();
+  ciborium::ser::into_writer(value, &mut bytes).unwrap();
+  bytes
+}
+
+fn from_cbor(bytes: &[u8]) -> Vec<(Cbor, Cbor)> {
+  match ciborium::de::from_reader(bytes).unwrap() {
+    Cbor::Map(entries) => entries,
+    value => panic!("expected a CBOR map, got {:?}", value),
+  }
+}
+
+fn get<'a>(entries: &'a [(Cbor, Cbor)], key: &str) -> &'a Cbor {
+  entries.iter()
+    .find(|(name, _)| name.as_text() == Some(key))
+    .map(|(_, value)| value)
+    .unwrap_or_else(|| panic!("missing '{}'", key))
+}
+
+fn text(value: &str) -> Cbor {
+  Cbor::Text(value.to_string())
+}
+
+/// CBOR context of `entries`, which sets its encoding
+fn context(mut entries: Vec<(Cbor, Cbor)>) -> Vec<u8> {
+  entries.push((text("encoding"), text("cbor")));
+  to_cbor(&Cbor::Map(entries))
+}
+
+fn messages() -> Vec<Vec<u8>> {
+  vec![b"Alice".to_vec(), b"1990-01-01".to_vec(), b"GB".to_vec()]
+}
+
+#[test]
+fn cbor_context_signs_with_byte_string_results() {
+  let key = from_cbor(&rust_call_cbor("bls_generate_g2_key", &context(Vec::new())));
+  let public_key = get(&key, "public_key").clone();
+  assert!(public_key.is_bytes());
+
+  let sign = context(vec![
+    (text("secret_key"), get(&key, "secret_key").clone()),
+    (text("public_key"), public_key.clone()),
+    (text("messages"), Cbor::Array(messages().into_iter().map(Cbor::Bytes).collect())),
+  ]);
+  let signature = from_cbor(&rust_call_cbor("bls_sign", &sign));
+  assert!(get(&signature, "signature").is_bytes());
+
+  let verify = context(vec![
+    (text("public_key"), public_key),
+    (text("signature"), get(&signature, "signature").clone()),
+    (text("messages"), Cbor::Array(messages().into_iter().map(Cbor::Bytes).collect())),
+  ]);
+  let result = from_cbor(&rust_call_cbor("bls_verify", &verify));
+  assert_eq!(get(&result, "verified"), &Cbor::Bool(true));
+}
+
+#[test]
+fn cbor_context_must_set_its_encoding() {
+  let message = |context: &[u8]| {
+    let error = from_cbor(&rust_call_cbor("bls_generate_g2_key", context));
+    match get(get(&error, "error").as_map().unwrap(), "message") {
+      Cbor::Text(message) => message.clone(),
+      message => panic!("unexpected error message {:?}", message),
+    }
+  };
+  assert!(message(&to_cbor(&Cbor::Map(Vec::new()))).contains("Invalid CBOR context, expected 'encoding' to be 'cbor'"));
+  assert!(message(&to_cbor(&Cbor::Map(vec![(text("encoding"), text("hex"))]))).contains("expected 'encoding' to be 'cbor'"));
+  assert!(message(br#"{"encoding":"cbor"}"#).contains("Encoding 'cbor' is only for CBOR contexts"));
+}
+
+#[test]
+fn cbor_call_reports_errors_as_cbor() {
+  let error = from_cbor(&rust_call_cbor("bls_unknown", b"{}"));
+  let error = get(&error, "error").as_map().unwrap();
+  assert_eq!(get(error, "message"), &text("Unknown operation: bls_unknown"));
+
+  let error = from_cbor(&rust_call_cbor("bls_sign", &[]));
+  assert_eq!(get(get(&error, "error").as_map().unwrap(), "message"), &text("Context not set"));
+}
+
+#[test]
+fn cose_credential_presents_and_verifies() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let messages: Vec<String> = messages().iter().map(base64::encode).collect();
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+  }));
+  let credential = call(rust_bbs_cose_credential, json!({
+    "signature": signature["signature"],
+    "messages": messages,
+    "key_id": "issuer-key",
+  }))["credential"].clone();
+
+  let presentation = call(rust_bls_create_presentation, json!({
+    "public_key": key["public_key"],
+    "credential": credential,
+    "revealed": [0, 2],
+    "nonce": base64::encode("nonce"),
+  }));
+  let envelope = call(rust_bbs_cose_presentation, json!({ "presentation": presentation["presentation"] }));
+  assert_eq!(base64::decode(envelope["presentation"].as_str().unwrap()).unwrap()[0], 0xd2);
+
+  let result = call(rust_bls_verify_presentation, json!({
+    "public_key": key["public_key"],
+    "presentation": envelope["presentation"],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert_eq!(result["verified"], true);
+  assert_eq!(result["revealed_messages"]["2"], base64::encode("GB"));
+}
+
+#[test]
+fn cose_credential_cant_be_combined_with_messages() {
+  let credential = call(rust_bbs_cose_credential, json!({
+    "signature": base64::encode([0u8; 112]),
+    "messages": [base64::encode("Alice")],
+  }))["credential"].clone();
+
+  let error = rust_bls_create_presentation(json!({
+    "credential": credential,
+    "messages": [base64::encode("Alice")],
+    "revealed": [0],
+  }));
+  assert!(error.is_err());
+}
+
+#[test]
+fn cose_key_id_is_unprotected() {
+  let credential = call(rust_bbs_cose_credential, json!({
+    "signature": base64::encode([0u8; 112]),
+    "messages": [base64::encode("Alice")],
+    "key_id": "issuer-key",
+  }))["credential"].clone();
+  let envelope = base64::decode(credential.as_str().unwrap()).unwrap();
+
+  let parts = match ciborium::de::from_reader(&envelope[..]).unwrap() {
+    Cbor::Tag(18, sign1) => sign1.into_array().unwrap(),
+    value => panic!("expected a COSE_Sign1, got {:?}", value),
+  };
+  let protected = from_cbor(parts[0].as_bytes().unwrap());
+  assert!(protected.iter().all(|(label, _)| label.as_integer() != Some(4.into())));
+  assert_eq!(parts[1], Cbor::Map(vec![(Cbor::Integer(4.into()), Cbor::Bytes(b"issuer-key".to_vec()))]));
+}
+
+#[test]
+fn cose_ietf_credential_verifies_and_proves() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let messages: Vec<String> = messages().iter().map(base64::encode).collect();
+  let header = base64::encode("header");
+  let signature = call(rust_bbs_ietf_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "header": header,
+    "messages": messages,
+  }));
+  let credential = call(rust_bbs_cose_credential, json!({
+    "algorithm": "bbs_ietf",
+    "signature": signature["signature"],
+    "header": header,
+    "messages": messages,
+  }))["credential"].clone();
+
+  let verified = call(rust_bbs_ietf_verify, json!({ "public_key": key["public_key"], "credential": credential }));
+  assert_eq!(verified["verified"], true);
+
+  let proof = call(rust_bbs_ietf_create_proof, json!({
+    "public_key": key["public_key"],
+    "credential": credential,
+    "revealed": [1],
+  }));
+  let verified = call(rust_bbs_ietf_verify_proof, json!({
+    "public_key": key["public_key"],
+    "proof": proof["proof"],
+    "header": header,
+    "messages": [messages[1]],
+    "revealed": [1],
+  }));
+  assert_eq!(verified["verified"], true);
+
+  // the header is signed, so it can't be overridden, and a BBS+ operation rejects the credential
+  assert!(rust_bbs_ietf_verify(json!({ "public_key": key["public_key"], "credential": credential, "header": header })).is_err());
+  let error = rust_bls_create_presentation(json!({ "public_key": key["public_key"], "credential": credential, "revealed": [0] }));
+  assert!(format!("{:?}", error.unwrap_err()).contains("expected 'BBS+_BLS12381G2_BLAKE2B'"));
+}
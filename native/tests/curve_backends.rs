@@ -0,0 +1,215 @@
+//! The blst and pairing-plus backends agree byte for byte, and verify what the other produced,
+//! for the IETF BBS and BBS+ operations.
+//! Run with `--features blst`; the known-answer fixtures, recorded with pairing-plus, check the
+//! keys and operations of whichever backend is enabled.
+#![cfg(feature = "blst")]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+use bbs::rust_bbs::bbs_plus_backend::{self, BlindSignatureContext, PublicKey, Signature, SignatureProof};
+use bbs::rust_bbs::curve::{Blst, CurveBackend, Group, PairingPlus, Scalar};
+use bbs::rust_bbs::ietf_bbs;
+use bbs_plus::prelude::{PoKOfSignatureProofStatus, SecretKey};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+type BlstScalar = <Blst as CurveBackend>::Scalar;
+type PairingPlusScalar = <PairingPlus as CurveBackend>::Scalar;
+
+fn messages() -> Vec<Vec<u8>> {
+  ["Alice", "1990-01-01", "GB"].iter().map(|message| message.as_bytes().to_vec()).collect()
+}
+
+/// A secret key of each backend from the same uniform bytes, and its public key
+fn keys(seed: u64) -> (BlstScalar, PairingPlusScalar, Vec<u8>) {
+  let mut okm = [0u8; 48];
+  StdRng::seed_from_u64(seed).fill_bytes(&mut okm);
+  let (blst_key, pairing_plus_key) = (BlstScalar::from_okm(&okm), PairingPlusScalar::from_okm(&okm));
+  assert_eq!(blst_key.to_bytes(), pairing_plus_key.to_bytes());
+
+  let public_key = ietf_bbs::secret_key_to_public_key::<Blst>(&blst_key);
+  assert_eq!(public_key, ietf_bbs::secret_key_to_public_key::<PairingPlus>(&pairing_plus_key));
+  (blst_key, pairing_plus_key, public_key)
+}
+
+#[test]
+fn scalars_and_points_are_encoded_alike() {
+  for seed in 0..16 {
+    let (blst_key, pairing_plus_key, _) = keys(seed);
+    let inverse = blst_key.inverse().unwrap().to_bytes();
+    assert_eq!(inverse, pairing_plus_key.inverse().unwrap().to_bytes());
+    assert_eq!(
+      ietf_bbs::deserialize_scalar::<BlstScalar>(&inverse).unwrap().to_bytes(),
+      ietf_bbs::deserialize_scalar::<PairingPlusScalar>(&inverse).unwrap().to_bytes(),
+    );
+
+    let blst_point = <Blst as CurveBackend>::G1::generator().mul(&blst_key);
+    let pairing_plus_point = <PairingPlus as CurveBackend>::G1::generator().mul(&pairing_plus_key);
+    assert_eq!(blst_point.to_compressed(), pairing_plus_point.to_compressed());
+    assert_eq!(
+      <Blst as CurveBackend>::hash_to_g1(&inverse, b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_").to_compressed(),
+      <PairingPlus as CurveBackend>::hash_to_g1(&inverse, b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_").to_compressed(),
+    );
+  }
+
+  // both reject a scalar not below the group order, and a point off the curve
+  assert!(ietf_bbs::deserialize_scalar::<BlstScalar>(&[0xff; 32]).is_err());
+  assert!(ietf_bbs::deserialize_scalar::<PairingPlusScalar>(&[0xff; 32]).is_err());
+  let mut off_curve = <Blst as CurveBackend>::G1::generator().to_compressed();
+  off_curve[47] ^= 1;
+  assert!(<Blst as CurveBackend>::G1::from_compressed(&off_curve).is_none());
+  assert!(<PairingPlus as CurveBackend>::G1::from_compressed(&off_curve).is_none());
+}
+
+#[test]
+fn signatures_verify_across_backends() {
+  let messages = messages();
+  for seed in 0..4 {
+    let (blst_key, pairing_plus_key, public_key) = keys(seed);
+    let signature = ietf_bbs::sign::<Blst>(&blst_key, &public_key, b"header", &messages).unwrap();
+    assert_eq!(signature, ietf_bbs::sign::<PairingPlus>(&pairing_plus_key, &public_key, b"header", &messages).unwrap());
+
+    assert!(ietf_bbs::verify::<PairingPlus>(&public_key, &signature, b"header", &messages).unwrap());
+    assert!(ietf_bbs::verify::<Blst>(&public_key, &signature, b"header", &messages).unwrap());
+    assert!(!ietf_bbs::verify::<PairingPlus>(&public_key, &signature, b"other header", &messages).unwrap());
+    assert!(!ietf_bbs::verify::<Blst>(&public_key, &signature, b"other header", &messages).unwrap());
+  }
+}
+
+#[test]
+fn proofs_verify_across_backends() {
+  let messages = messages();
+  let (blst_key, _, public_key) = keys(0);
+  let signature = ietf_bbs::sign::<Blst>(&blst_key, &public_key, b"header", &messages).unwrap();
+  let disclosed = [messages[0].clone(), messages[2].clone()];
+
+  let proof_with = |seed: u64, blst: bool| {
+    let mut rng = StdRng::seed_from_u64(seed);
+    match blst {
+      true => ietf_bbs::proof_gen::<Blst, _>(&public_key, &signature, b"header", b"nonce", &messages, &[0, 2], &mut rng),
+      false => ietf_bbs::proof_gen::<PairingPlus, _>(&public_key, &signature, b"header", b"nonce", &messages, &[0, 2], &mut rng),
+    }
+    .unwrap()
+  };
+  for seed in 0..4 {
+    let proof = proof_with(seed, true);
+    assert_eq!(proof, proof_with(seed, false));
+
+    assert!(ietf_bbs::proof_verify::<PairingPlus>(&public_key, &proof, b"header", b"nonce", &disclosed, &[0, 2]).unwrap());
+    assert!(ietf_bbs::proof_verify::<Blst>(&public_key, &proof, b"header", b"nonce", &disclosed, &[0, 2]).unwrap());
+    assert!(!ietf_bbs::proof_verify::<PairingPlus>(&public_key, &proof, b"header", b"other nonce", &disclosed, &[0, 2]).unwrap());
+    assert!(!ietf_bbs::proof_verify::<Blst>(&public_key, &proof, b"header", b"other nonce", &disclosed, &[0, 2]).unwrap());
+  }
+}
+
+/// A BBS+ secret key, and the public key of each backend for the messages
+fn bbs_plus_keys(seed: u64) -> (SecretKey, PublicKey<Blst>, PublicKey<PairingPlus>) {
+  let (_, pairing_plus_key, _) = keys(seed);
+  let secret_key = SecretKey::from(pairing_plus_key.to_bytes());
+  let dpk = bbs_plus_backend::deterministic_public_key::<Blst>(&secret_key).unwrap();
+  assert_eq!(dpk, bbs_plus_backend::deterministic_public_key::<PairingPlus>(&secret_key).unwrap());
+
+  let blst_public_key = PublicKey::<Blst>::from_deterministic(&dpk, messages().len()).unwrap();
+  let pairing_plus_public_key = PublicKey::<PairingPlus>::from_deterministic(&dpk, messages().len()).unwrap();
+  assert_eq!(blst_public_key.to_bytes(), pairing_plus_public_key.to_bytes());
+  (secret_key, blst_public_key, pairing_plus_public_key)
+}
+
+fn message_scalars<S: Scalar>() -> Vec<S> {
+  messages().iter().map(|message| bbs_plus_backend::hash_to_scalar(message)).collect()
+}
+
+#[test]
+fn bbs_plus_signatures_verify_across_backends() {
+  let (blst_messages, pairing_plus_messages) = (message_scalars(), message_scalars());
+  for seed in 0..4 {
+    let (secret_key, blst_public_key, pairing_plus_public_key) = bbs_plus_keys(seed);
+    let signature = bbs_plus_backend::sign(&secret_key, &blst_public_key, &blst_messages, &mut StdRng::seed_from_u64(seed)).unwrap().to_bytes();
+    let pairing_plus_signature = bbs_plus_backend::sign(&secret_key, &pairing_plus_public_key, &pairing_plus_messages, &mut StdRng::seed_from_u64(seed)).unwrap();
+    assert_eq!(signature, pairing_plus_signature.to_bytes());
+
+    let blst_signature = Signature::<Blst>::try_from(signature.as_slice()).unwrap();
+    let pairing_plus_signature = Signature::<PairingPlus>::try_from(signature.as_slice()).unwrap();
+    assert!(blst_signature.verify(&blst_public_key, &blst_messages).unwrap());
+    assert!(pairing_plus_signature.verify(&pairing_plus_public_key, &pairing_plus_messages).unwrap());
+    assert!(!blst_signature.verify(&blst_public_key, &message_scalars::<BlstScalar>()[..1].repeat(3)).unwrap());
+    assert!(!pairing_plus_signature.verify(&pairing_plus_public_key, &message_scalars::<PairingPlusScalar>()[..1].repeat(3)).unwrap());
+  }
+}
+
+#[test]
+fn bbs_plus_proofs_verify_across_backends() {
+  let (secret_key, blst_public_key, pairing_plus_public_key) = bbs_plus_keys(0);
+  let (blst_messages, pairing_plus_messages) = (message_scalars(), message_scalars());
+  let signature = bbs_plus_backend::sign(&secret_key, &blst_public_key, &blst_messages, &mut StdRng::seed_from_u64(0)).unwrap().to_bytes();
+  let revealed: BTreeSet<usize> = [0, 2].iter().copied().collect();
+  let (blst_nonce, pairing_plus_nonce) = (bbs_plus_backend::hash_to_scalar(b"nonce"), bbs_plus_backend::hash_to_scalar(b"nonce"));
+
+  for seed in 0..4 {
+    let proof = bbs_plus_backend::create_proof(
+      &Signature::<Blst>::try_from(signature.as_slice()).unwrap(),
+      &blst_public_key,
+      &blst_messages,
+      &revealed,
+      &blst_nonce,
+      &mut StdRng::seed_from_u64(seed),
+    ).unwrap().to_bytes();
+    let pairing_plus_proof = bbs_plus_backend::create_proof(
+      &Signature::<PairingPlus>::try_from(signature.as_slice()).unwrap(),
+      &pairing_plus_public_key,
+      &pairing_plus_messages,
+      &revealed,
+      &pairing_plus_nonce,
+      &mut StdRng::seed_from_u64(seed),
+    ).unwrap();
+    assert_eq!(proof, pairing_plus_proof.to_bytes());
+
+    let blst_revealed: BTreeMap<usize, _> = revealed.iter().map(|index| (*index, blst_messages[*index])).collect();
+    let pairing_plus_revealed: BTreeMap<usize, _> = revealed.iter().map(|index| (*index, pairing_plus_messages[*index])).collect();
+    let blst_proof = SignatureProof::<Blst>::from_bytes(&proof).unwrap();
+    let pairing_plus_proof = SignatureProof::<PairingPlus>::from_bytes(&proof).unwrap();
+    assert_eq!(blst_proof.verify(&blst_public_key, &blst_revealed, &blst_nonce).unwrap(), PoKOfSignatureProofStatus::Success);
+    assert_eq!(pairing_plus_proof.verify(&pairing_plus_public_key, &pairing_plus_revealed, &pairing_plus_nonce).unwrap(), PoKOfSignatureProofStatus::Success);
+
+    let other_nonce = bbs_plus_backend::hash_to_scalar::<BlstScalar>(b"other nonce");
+    assert_ne!(blst_proof.verify(&blst_public_key, &blst_revealed, &other_nonce).unwrap(), PoKOfSignatureProofStatus::Success);
+    let other_nonce = bbs_plus_backend::hash_to_scalar::<PairingPlusScalar>(b"other nonce");
+    assert_ne!(pairing_plus_proof.verify(&pairing_plus_public_key, &pairing_plus_revealed, &other_nonce).unwrap(), PoKOfSignatureProofStatus::Success);
+  }
+}
+
+#[test]
+fn bbs_plus_blind_signatures_verify_across_backends() {
+  let (secret_key, blst_public_key, pairing_plus_public_key) = bbs_plus_keys(0);
+  let (blst_messages, pairing_plus_messages) = (message_scalars::<BlstScalar>(), message_scalars::<PairingPlusScalar>());
+  let blinded: BTreeSet<usize> = [1].iter().copied().collect();
+  let (blst_nonce, pairing_plus_nonce) = (bbs_plus_backend::hash_to_scalar(b"nonce"), bbs_plus_backend::hash_to_scalar(b"nonce"));
+
+  let mut rng = StdRng::seed_from_u64(0);
+  let blst_blinded = blinded.iter().map(|index| (*index, blst_messages[*index])).collect();
+  let (blst_context, blst_blinding_factor) = bbs_plus_backend::blind_signature_context(&blst_public_key, &blst_blinded, &blst_nonce, &mut rng).unwrap();
+  let mut rng = StdRng::seed_from_u64(0);
+  let pairing_plus_blinded = blinded.iter().map(|index| (*index, pairing_plus_messages[*index])).collect();
+  let (pairing_plus_context, pairing_plus_blinding_factor) = bbs_plus_backend::blind_signature_context(&pairing_plus_public_key, &pairing_plus_blinded, &pairing_plus_nonce, &mut rng).unwrap();
+  assert_eq!(blst_blinding_factor.to_bytes(), pairing_plus_blinding_factor.to_bytes());
+  assert_eq!(blst_context.commitment.to_compressed(), pairing_plus_context.commitment.to_compressed());
+  assert_eq!(blst_context.challenge_hash.to_bytes(), pairing_plus_context.challenge_hash.to_bytes());
+  let proof_of_hidden_messages = blst_context.proof_of_hidden_messages.to_bytes();
+  assert_eq!(proof_of_hidden_messages, pairing_plus_context.proof_of_hidden_messages.to_bytes());
+
+  // each backend verifies the other's commitment, signs it, and unblinds the other's blind signature
+  let blst_context = BlindSignatureContext::<Blst> {
+    commitment: <Blst as CurveBackend>::G1::from_compressed(&pairing_plus_context.commitment.to_compressed()).unwrap(),
+    challenge_hash: BlstScalar::from_bytes(&pairing_plus_context.challenge_hash.to_bytes()).unwrap(),
+    proof_of_hidden_messages: bbs_plus_backend::ProofG1::from_bytes(&proof_of_hidden_messages).unwrap(),
+  };
+  assert!(blst_context.verify(&blst_public_key, &blinded, &blst_nonce));
+  assert!(pairing_plus_context.verify(&pairing_plus_public_key, &blinded, &pairing_plus_nonce));
+  assert!(!blst_context.verify(&blst_public_key, &blinded, &bbs_plus_backend::hash_to_scalar(b"other nonce")));
+
+  let known: BTreeMap<usize, _> = [0, 2].iter().map(|index| (*index, pairing_plus_messages[*index])).collect();
+  let blind_signature = bbs_plus_backend::blind_sign(&secret_key, &pairing_plus_public_key, &pairing_plus_context.commitment, &known, &mut rng).unwrap().to_bytes();
+  let signature = Signature::<Blst>::try_from(blind_signature.as_slice()).unwrap().unblind(&blst_blinding_factor);
+  assert!(signature.verify(&blst_public_key, &blst_messages).unwrap());
+  let signature = Signature::<PairingPlus>::try_from(signature.to_bytes().as_slice()).unwrap();
+  assert!(signature.verify(&pairing_plus_public_key, &pairing_plus_messages).unwrap());
+}
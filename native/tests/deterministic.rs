@@ -0,0 +1,188 @@
+//! Reproducible signatures, proofs and keys from a context's `deterministic_seed`.
+#![cfg(debug_assertions)]
+
+use bbs::rust_bbs::{
+  rust_bbs_blind_sign,
+  rust_bbs_blind_signature_commitment,
+  rust_bbs_create_proof,
+  rust_bbs_ietf_create_proof,
+  rust_bbs_ietf_sign,
+  rust_bbs_ietf_verify_proof,
+  rust_bbs_sign,
+  rust_bbs_unblind_signature,
+  rust_bbs_verify,
+  rust_bbs_verify_blind_signature_proof,
+  rust_bbs_verify_proof,
+  rust_bls_export_encrypted_key,
+  rust_bls_generate_g2_key,
+  rust_bls_secret_key_to_bbs_key,
+};
+use serde_json::{Value, json};
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn error(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> String {
+  op(context).unwrap_err().to_string()
+}
+
+fn with_seed(mut context: Value, seed: &str) -> Value {
+  context["deterministic_seed"] = json!(base64::encode(seed));
+  context
+}
+
+fn messages() -> Vec<String> {
+  ["Alice", "Bob", "Carol"].iter().map(base64::encode).collect()
+}
+
+/// A G2 key, and its BBS key for the test messages
+fn keys() -> (Value, Value) {
+  let key = call(rust_bls_generate_g2_key, with_seed(json!({}), "key"));
+  let bbs_key = call(rust_bls_secret_key_to_bbs_key, json!({
+    "secret_key": key["secret_key"],
+    "message_count": messages().len(),
+  }));
+  (key, bbs_key)
+}
+
+#[test]
+fn seeded_keys_and_signatures_repeat() {
+  assert_eq!(keys(), keys());
+  let (key, bbs_key) = keys();
+  assert_ne!(key, call(rust_bls_generate_g2_key, with_seed(json!({}), "other key")));
+
+  let context = json!({
+    "secret_key": key["secret_key"],
+    "public_key": bbs_key["public_key"],
+    "messages": messages(),
+  });
+  let signature = call(rust_bbs_sign, with_seed(context.clone(), "signature"));
+  assert_eq!(signature, call(rust_bbs_sign, with_seed(context.clone(), "signature")));
+  assert_ne!(signature, call(rust_bbs_sign, with_seed(context.clone(), "other signature")));
+  assert_ne!(call(rust_bbs_sign, context.clone()), call(rust_bbs_sign, context));
+
+  let verified = call(rust_bbs_verify, json!({
+    "public_key": bbs_key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages(),
+  }));
+  assert_eq!(verified["verified"], true);
+}
+
+#[test]
+fn seeded_proofs_repeat_and_verify() {
+  let (key, bbs_key) = keys();
+  let signature = call(rust_bbs_sign, with_seed(json!({
+    "secret_key": key["secret_key"],
+    "public_key": bbs_key["public_key"],
+    "messages": messages(),
+  }), "signature"));
+
+  let context = json!({
+    "public_key": bbs_key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages(),
+    "revealed": [1],
+    "nonce": base64::encode("nonce"),
+  });
+  let proof = call(rust_bbs_create_proof, with_seed(context.clone(), "proof"));
+  assert_eq!(proof, call(rust_bbs_create_proof, with_seed(context.clone(), "proof")));
+  assert_ne!(proof, call(rust_bbs_create_proof, context));
+
+  let verification = call(rust_bbs_verify_proof, json!({
+    "public_key": bbs_key["public_key"],
+    "proof": proof["proof"],
+    "messages": [messages()[1]],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert_eq!(verification["verified"], true, "{}", verification);
+}
+
+#[test]
+fn seeded_blind_signatures_repeat_and_verify() {
+  let (key, bbs_key) = keys();
+  let commitment_context = json!({
+    "public_key": bbs_key["public_key"],
+    "messages": [messages()[0]],
+    "blinded": [0],
+    "nonce": base64::encode("nonce"),
+  });
+  let commitment = call(rust_bbs_blind_signature_commitment, with_seed(commitment_context.clone(), "commitment"));
+  assert_eq!(commitment, call(rust_bbs_blind_signature_commitment, with_seed(commitment_context, "commitment")));
+
+  let verification = call(rust_bbs_verify_blind_signature_proof, json!({
+    "public_key": bbs_key["public_key"],
+    "commitment": commitment["commitment"],
+    "challenge_hash": commitment["challenge_hash"],
+    "proof_of_hidden_messages": commitment["proof_of_hidden_messages"],
+    "blinded": [0],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert_eq!(verification["verified"], true);
+
+  let sign_context = json!({
+    "secret_key": key["secret_key"],
+    "public_key": bbs_key["public_key"],
+    "commitment": commitment["commitment"],
+    "messages": [messages()[1], messages()[2]],
+    "known": [1, 2],
+  });
+  let blind_signature = call(rust_bbs_blind_sign, with_seed(sign_context.clone(), "blind signature"));
+  assert_eq!(blind_signature, call(rust_bbs_blind_sign, with_seed(sign_context, "blind signature")));
+
+  let signature = call(rust_bbs_unblind_signature, json!({
+    "blind_signature": blind_signature["blind_signature"],
+    "blinding_factor": commitment["blinding_factor"],
+  }));
+  let verified = call(rust_bbs_verify, json!({
+    "public_key": bbs_key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages(),
+  }));
+  assert_eq!(verified["verified"], true);
+}
+
+#[test]
+fn seeded_ietf_proofs_and_key_exports_repeat() {
+  let key = call(rust_bls_generate_g2_key, with_seed(json!({}), "key"));
+  let signature = call(rust_bbs_ietf_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages(),
+  }));
+  let context = json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages(),
+    "revealed": [0, 2],
+  });
+  let proof = call(rust_bbs_ietf_create_proof, with_seed(context.clone(), "proof"));
+  assert_eq!(proof, call(rust_bbs_ietf_create_proof, with_seed(context, "proof")));
+
+  let verified = call(rust_bbs_ietf_verify_proof, json!({
+    "public_key": key["public_key"],
+    "proof": proof["proof"],
+    "messages": [messages()[0], messages()[2]],
+    "revealed": [0, 2],
+  }));
+  assert_eq!(verified["verified"], true);
+
+  let context = json!({
+    "key_type": "g2",
+    "public_key": key["public_key"],
+    "secret_key": key["secret_key"],
+    "password": "correct horse battery staple",
+  });
+  let encrypted_key = call(rust_bls_export_encrypted_key, with_seed(context.clone(), "export"));
+  assert_eq!(encrypted_key, call(rust_bls_export_encrypted_key, with_seed(context, "export")));
+}
+
+#[test]
+fn seed_must_be_base64() {
+  let message = error(rust_bls_generate_g2_key, json!({ "deterministic_seed": 1 }));
+  assert!(message.contains("Invalid 'deterministic_seed'"), "{}", message);
+
+  let message = error(rust_bls_generate_g2_key, json!({ "deterministic_seed": "%" }));
+  assert!(message.contains("Failed decoding base64 for: 'deterministic_seed'"), "{}", message);
+}
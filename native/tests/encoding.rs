@@ -0,0 +1,123 @@
+//! Byte properties given and returned in the context's 'encoding'.
+
+use bbs::rust_bbs::{
+  rust_bls_create_presentation,
+  rust_bls_generate_g2_key,
+  rust_bls_sign,
+  rust_bls_verify,
+  rust_bls_verify_presentation,
+  rust_jwp_issue,
+  rust_jwp_verify,
+};
+use serde_json::{Value, json};
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn error(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> String {
+  op(context).unwrap_err().to_string()
+}
+
+const SEED: &str = "H297BpoOgkfpXcxr1fJyQRiNx1+ZekeQ+OU/AYV/lVxaPXXhFBIbxeIU8kIAAX68cwQ=";
+
+fn messages() -> Vec<&'static [u8]> {
+  vec![b"Alice", b"1990-01-01", b"GB"]
+}
+
+#[test]
+fn hex_keys_sign_and_verify() {
+  let seed = hex::encode(base64::decode(SEED).unwrap());
+  let key = call(rust_bls_generate_g2_key, json!({ "seed": seed, "encoding": "hex" }));
+  let base64_key = call(rust_bls_generate_g2_key, json!({ "seed": SEED }));
+  assert_eq!(key["public_key"], hex::encode(base64::decode(base64_key["public_key"].as_str().unwrap()).unwrap()));
+
+  let messages: Vec<String> = messages().into_iter().map(hex::encode).collect();
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+    "encoding": "hex",
+  }));
+  assert!(hex::decode(signature["signature"].as_str().unwrap()).is_ok());
+
+  let result = call(rust_bls_verify, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "encoding": "hex",
+  }));
+  assert_eq!(result["verified"], true);
+}
+
+#[test]
+fn base64url_presentation_verifies() {
+  let encode = |bytes: &[u8]| base64::encode_config(bytes, base64::URL_SAFE_NO_PAD);
+  let key = call(rust_bls_generate_g2_key, json!({ "encoding": "base64url" }));
+  let messages: Vec<String> = messages().into_iter().map(encode).collect();
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+    "encoding": "base64url",
+  }));
+
+  let presentation = call(rust_bls_create_presentation, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [2],
+    "nonce": encode(b"nonce"),
+    "encoding": "base64url",
+  }));
+  assert_eq!(presentation["presentation"]["revealed_messages"]["2"], encode(b"GB"));
+
+  let result = call(rust_bls_verify_presentation, json!({
+    "public_key": key["public_key"],
+    "presentation": presentation["presentation"],
+    "nonce": encode(b"nonce"),
+    "encoding": "base64url",
+  }));
+  assert_eq!(result["verified"], true);
+  assert_eq!(result["revealed_messages"]["2"], encode(b"GB"));
+}
+
+#[test]
+fn jwp_text_properties_keep_their_encoding() {
+  let key = call(rust_bls_generate_g2_key, json!({ "encoding": "hex" }));
+  let jwp = call(rust_jwp_issue, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "header": { "kid": "issuer-key" },
+    "payloads": [hex::encode("Alice")],
+    "encoding": "hex",
+  }))["jwp"].clone();
+
+  let result = call(rust_jwp_verify, json!({ "public_key": key["public_key"], "jwp": jwp, "encoding": "hex" }));
+  assert_eq!(result["verified"], true);
+  assert_eq!(result["header"]["kid"], "issuer-key");
+  assert_eq!(result["payloads"], json!([hex::encode("Alice")]));
+}
+
+#[test]
+fn malformed_bytes_are_errors() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+
+  let message = error(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": ["not hex"],
+    "encoding": "hex",
+  }));
+  assert!(message.contains("Failed decoding hex for: 'messages'"), "{}", message);
+
+  let message = error(rust_bls_verify, json!({
+    "public_key": key["public_key"],
+    "signature": "not base64!",
+    "messages": [base64::encode("Alice")],
+  }));
+  assert!(message.contains("Failed decoding base64 for: 'signature'"), "{}", message);
+
+  let message = error(rust_bls_generate_g2_key, json!({ "encoding": "base58" }));
+  assert!(message.contains("Unsupported 'encoding'"), "{}", message);
+}
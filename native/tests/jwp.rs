@@ -0,0 +1,68 @@
+//! JSON Web Proof issuance, presentation and verification in both serializations.
+
+use bbs::rust_bbs::{rust_bls_generate_g2_key, rust_jwp_issue, rust_jwp_present, rust_jwp_verify};
+use serde_json::{Value, json};
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn issue(key: &Value, serialization: &str) -> Value {
+  call(rust_jwp_issue, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "header": { "kid": "issuer-key", "typ": "JPT" },
+    "payloads": [base64::encode("Alice"), base64::encode("1990-01-01"), base64::encode("GB")],
+    "serialization": serialization,
+  }))["jwp"].clone()
+}
+
+#[test]
+fn issued_compact_jwp_verifies() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let jwp = issue(&key, "compact");
+  assert_eq!(jwp.as_str().unwrap().split('.').count(), 3);
+
+  let result = call(rust_jwp_verify, json!({ "public_key": key["public_key"], "jwp": jwp }));
+  assert_eq!(result["verified"], true);
+  assert_eq!(result["header"], json!({ "alg": "BBS", "kid": "issuer-key", "typ": "JPT" }));
+  assert_eq!(result["payloads"][2], base64::encode("GB"));
+}
+
+#[test]
+fn presented_jwp_discloses_selected_payloads() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+
+  for serialization in &["compact", "json"] {
+    let presented = call(rust_jwp_present, json!({
+      "public_key": key["public_key"],
+      "jwp": issue(&key, serialization),
+      "revealed": [0, 2],
+      "nonce": "n-0S6_WzA2Mj",
+      "audience": "https://verifier.example",
+      "serialization": serialization,
+    }))["jwp"].clone();
+
+    let verify = |nonce: &str| call(rust_jwp_verify, json!({
+      "public_key": key["public_key"],
+      "jwp": presented,
+      "nonce": nonce,
+      "audience": "https://verifier.example",
+    }));
+    let result = verify("n-0S6_WzA2Mj");
+    assert_eq!(result["verified"], true);
+    assert_eq!(result["payloads"], json!([base64::encode("Alice"), null, base64::encode("GB")]));
+    assert_eq!(result["presentation_header"]["aud"], "https://verifier.example");
+    assert_eq!(verify("replayed")["verified"], false);
+  }
+}
+
+#[test]
+fn tampered_jwp_fails_to_verify() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let mut jwp = issue(&key, "json");
+  jwp["payloads"][1] = Value::from(base64::encode_config("2000-01-01", base64::URL_SAFE_NO_PAD));
+
+  let result = call(rust_jwp_verify, json!({ "public_key": key["public_key"], "jwp": jwp }));
+  assert_eq!(result["verified"], false);
+}
@@ -0,0 +1,463 @@
+//! Known-answer tests: every fixture under `tests/fixtures` names an operation, its context and
+//! the result or error it gives, so a change to any output format fails here without Node.
+//!
+//! `known_answers.json` is regenerated from fixed seeds with
+//! `cargo test --test known_answers -- --ignored`, after which its diff is the change to review.
+//! `legacy_proofs.json` holds the unversioned bitvector proofs of the TypeScript specs, and is
+//! never regenerated. The keystore and public key cache operations aren't covered, as their
+//! results depend on the state of the process.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+use bbs::rust_bbs::context_operation;
+use serde_json::{Value, json};
+
+fn fixtures_path(name: &str) -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+fn fixtures(name: &str) -> Vec<Value> {
+  let fixtures = fs::read_to_string(fixtures_path(name)).unwrap();
+  serde_json::from_str(&fixtures).unwrap()
+}
+
+/// Run an operation by its wrapper name, giving its result or error message
+fn run(operation: &str, context: Value) -> Result<Value, String> {
+  let operation = context_operation(operation).unwrap_or_else(|| panic!("Unknown operation: '{}'", operation));
+  match operation(context) {
+    Ok(result) => Ok(serde_json::from_str(&result).unwrap()),
+    Err(error) => Err(error.to_string()),
+  }
+}
+
+fn check_fixtures(name: &str) {
+  for fixture in fixtures(name) {
+    let context = fixture["context"].clone();
+    // release builds refuse a 'deterministic_seed'
+    if !cfg!(debug_assertions) && context.get("deterministic_seed").is_some() {
+      continue;
+    }
+
+    let operation = fixture["operation"].as_str().unwrap();
+    let outcome = run(operation, context);
+    match fixture.get("error") {
+      Some(error) => assert_eq!(outcome.err().as_deref(), error.as_str(), "{}", fixture["name"]),
+      None => assert_eq!(outcome.as_ref(), Ok(&fixture["result"]), "{}", fixture["name"]),
+    }
+  }
+}
+
+#[test]
+fn known_answers_are_given() {
+  check_fixtures("known_answers.json");
+}
+
+#[test]
+fn legacy_proofs_are_verified() {
+  check_fixtures("legacy_proofs.json");
+}
+
+/// Runs operations in turn, recording each as a fixture
+struct Recorder {
+  fixtures: Vec<Value>,
+}
+
+impl Recorder {
+  fn record(&mut self, name: &str, operation: &str, context: Value) -> Value {
+    let result = run(operation, context.clone()).unwrap_or_else(|error| panic!("{}: {}", name, error));
+    self.fixtures.push(json!({ "name": name, "operation": operation, "context": context, "result": result }));
+    result
+  }
+
+  fn record_error(&mut self, name: &str, operation: &str, context: Value) {
+    let error = run(operation, context.clone()).err().unwrap_or_else(|| panic!("{}: expected an error", name));
+    self.fixtures.push(json!({ "name": name, "operation": operation, "context": context, "error": error }));
+  }
+}
+
+fn seed(label: &str) -> String {
+  base64::encode(format!("known answer {}", label))
+}
+
+fn seeded(mut context: Value, label: &str) -> Value {
+  context["deterministic_seed"] = json!(seed(label));
+  context
+}
+
+fn messages() -> Vec<String> {
+  ["Alice", "1990-01-01", "GB"].iter().map(base64::encode).collect()
+}
+
+const CREDENTIAL: &str = r#"_:credential <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+_:credential <https://www.w3.org/2018/credentials#issuer> <did:example:issuer> .
+_:credential <https://www.w3.org/2018/credentials#credentialSubject> _:subject .
+_:subject <http://schema.org/name> "Alice" .
+_:subject <http://schema.org/birthDate> "1990-01-01"^^<http://www.w3.org/2001/XMLSchema#date> .
+_:subject <http://schema.org/addressCountry> "GB" .
+"#;
+const PROOF_CONFIG: &str = r#"_:proof <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> .
+_:proof <https://w3id.org/security#cryptosuite> "bbs-2023"^^<https://w3id.org/security#cryptosuiteString> .
+_:proof <https://w3id.org/security#verificationMethod> <did:example:issuer#key-1> .
+"#;
+
+fn record_keys(recorder: &mut Recorder) -> Value {
+  for key_type in &["g1", "g2"] {
+    recorder.record(&format!("{} key from a seed", key_type), &format!("bls_generate_{}_key", key_type), json!({
+      "seed": seed(key_type),
+    }));
+    recorder.record(&format!("blinded {} key from a seed", key_type), &format!("bls_generate_blinded_{}_key", key_type), seeded(json!({
+      "seed": seed(key_type),
+    }), "blinding"));
+  }
+  let key = recorder.record("issuer key from a seed", "bls_generate_g2_key", json!({ "seed": seed("issuer") }));
+
+  recorder.record("bbs key from a secret key", "bls_secret_key_to_bbs_key", json!({
+    "secret_key": key["secret_key"],
+    "message_count": 3,
+  }));
+  recorder.record("bbs key from a public key", "bls_public_key_to_bbs_key", json!({
+    "public_key": key["public_key"],
+    "message_count": 3,
+  }));
+  recorder.record_error("bbs key without a message count", "bls_public_key_to_bbs_key", json!({
+    "public_key": key["public_key"],
+  }));
+
+  let jwk = recorder.record("private jwk", "bls_key_to_jwk", json!({
+    "public_key": key["public_key"],
+    "secret_key": key["secret_key"],
+  }));
+  recorder.record("key from a private jwk", "bls_jwk_to_key", json!({ "jwk": jwk }));
+  let multikey = recorder.record("multikey", "bls_public_key_to_multikey", json!({ "public_key": key["public_key"] }));
+  recorder.record("public key from a multikey", "bls_multikey_to_public_key", json!({
+    "public_key_multibase": multikey["public_key_multibase"],
+  }));
+  let did = recorder.record("did:key", "bls_public_key_to_did_key", json!({ "public_key": key["public_key"] }));
+  recorder.record("resolved did:key", "bls_resolve_did_key", json!({ "did": did["did"] }));
+
+  let encrypted_key = recorder.record("encrypted key", "bls_export_encrypted_key", seeded(json!({
+    "key_type": "g2",
+    "public_key": key["public_key"],
+    "secret_key": key["secret_key"],
+    "password": "correct horse battery staple",
+  }), "export"));
+  recorder.record("decrypted key", "bls_import_encrypted_key", json!({
+    "encrypted_key": encrypted_key,
+    "password": "correct horse battery staple",
+  }));
+  recorder.record_error("key decrypted with the wrong password", "bls_import_encrypted_key", json!({
+    "encrypted_key": encrypted_key,
+    "password": "Tr0ub4dor&3",
+  }));
+  key
+}
+
+fn record_signatures_and_proofs(recorder: &mut Recorder, key: &Value) {
+  let bbs_key = recorder.record("bbs key for the signatures", "bls_public_key_to_bbs_key", json!({
+    "public_key": key["public_key"],
+    "message_count": 3,
+  }));
+  let nonce = base64::encode("nonce");
+
+  for (signer, public_key) in &[("bbs", &bbs_key["public_key"]), ("bls", &key["public_key"])] {
+    let signature = recorder.record(&format!("{} signature", signer), &format!("{}_sign", signer), seeded(json!({
+      "secret_key": key["secret_key"],
+      "public_key": public_key,
+      "messages": messages(),
+    }), "signature"));
+    recorder.record(&format!("{} signature verified", signer), &format!("{}_verify", signer), json!({
+      "public_key": public_key,
+      "signature": signature["signature"],
+      "messages": messages(),
+    }));
+    recorder.record(&format!("{} signature of other messages", signer), &format!("{}_verify", signer), json!({
+      "public_key": public_key,
+      "signature": signature["signature"],
+      "messages": [messages()[0], messages()[1], base64::encode("FR")],
+    }));
+    recorder.record_error(&format!("truncated {} signature", signer), &format!("{}_verify", signer), json!({
+      "public_key": public_key,
+      "signature": base64::encode(&base64::decode(signature["signature"].as_str().unwrap()).unwrap()[..100]),
+      "messages": messages(),
+    }));
+    recorder.record_error(&format!("{} signature off the curve", signer), &format!("{}_verify", signer), json!({
+      "public_key": public_key,
+      "signature": base64::encode([0xffu8; 112]),
+      "messages": messages(),
+    }));
+
+    let proof = recorder.record(&format!("{} proof", signer), &format!("{}_create_proof", signer), seeded(json!({
+      "public_key": public_key,
+      "signature": signature["signature"],
+      "messages": messages(),
+      "revealed": [0, 2],
+      "nonce": nonce,
+    }), "proof"));
+    recorder.record(&format!("{} proof verified", signer), &format!("{}_verify_proof", signer), json!({
+      "public_key": public_key,
+      "proof": proof["proof"],
+      "messages": [messages()[0], messages()[2]],
+      "nonce": nonce,
+    }));
+    recorder.record(&format!("{} proof with another nonce", signer), &format!("{}_verify_proof", signer), json!({
+      "public_key": public_key,
+      "proof": proof["proof"],
+      "messages": [messages()[0], messages()[2]],
+      "nonce": base64::encode("replayed"),
+    }));
+    let proof_bytes = base64::decode(proof["proof"].as_str().unwrap()).unwrap();
+    recorder.record(&format!("truncated {} proof", signer), &format!("{}_verify_proof", signer), json!({
+      "public_key": public_key,
+      "proof": base64::encode(&proof_bytes[..proof_bytes.len() / 2]),
+      "messages": [messages()[0], messages()[2]],
+      "nonce": nonce,
+    }));
+
+    let presentation = recorder.record(&format!("{} presentation", signer), &format!("{}_create_presentation", signer), seeded(json!({
+      "public_key": public_key,
+      "signature": signature["signature"],
+      "messages": messages(),
+      "revealed": [1],
+      "nonce": nonce,
+      "key_id": "issuer-key",
+    }), "presentation"));
+    recorder.record(&format!("{} presentation verified", signer), &format!("{}_verify_presentation", signer), json!({
+      "public_key": public_key,
+      "presentation": presentation["presentation"],
+      "nonce": nonce,
+    }));
+    let mut truncated_presentation = presentation["presentation"].clone();
+    let presentation_proof = base64::decode(presentation["presentation"]["proof"].as_str().unwrap()).unwrap();
+    truncated_presentation["proof"] = json!(base64::encode(&presentation_proof[..2]));
+    recorder.record(&format!("truncated {} presentation", signer), &format!("{}_verify_presentation", signer), json!({
+      "public_key": public_key,
+      "presentation": truncated_presentation,
+      "nonce": nonce,
+    }));
+    recorder.record(&format!("{} presentation for another nonce", signer), &format!("{}_verify_presentation", signer), json!({
+      "public_key": public_key,
+      "presentation": presentation["presentation"],
+      "nonce": base64::encode("another nonce"),
+    }));
+    let mut relabeled_presentation = presentation["presentation"].clone();
+    relabeled_presentation["revealed_messages"] = json!({ "2": presentation["presentation"]["revealed_messages"]["1"] });
+    recorder.record(&format!("{} presentation revealing other indices", signer), &format!("{}_verify_presentation", signer), json!({
+      "public_key": public_key,
+      "presentation": relabeled_presentation,
+      "nonce": nonce,
+    }));
+  }
+
+  let credential = recorder.record("cose credential", "bbs_cose_credential", json!({
+    "signature": recorder.fixtures.iter().find(|fixture| fixture["name"] == "bls signature").unwrap()["result"]["signature"],
+    "messages": messages(),
+    "key_id": "issuer-key",
+  }));
+  let presentation = recorder.record("presentation of a cose credential", "bls_create_presentation", seeded(json!({
+    "public_key": key["public_key"],
+    "credential": credential["credential"],
+    "revealed": [0],
+    "nonce": nonce,
+  }), "cose presentation"));
+  recorder.record("cose presentation", "bbs_cose_presentation", json!({ "presentation": presentation["presentation"] }));
+
+  let schema = json!(["name", "birth_date", "country"]);
+  let claims = json!({
+    "name": base64::encode("Alice"),
+    "birth_date": base64::encode("1990-01-01"),
+    "country": base64::encode("GB"),
+  });
+  recorder.record("schema hash", "bbs_schema_hash", json!({ "schema": schema }));
+  let signature = recorder.record("signature of claims", "bls_sign", seeded(json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "schema": schema,
+    "claims": claims,
+  }), "claims"));
+  recorder.record("signature of claims verified", "bls_verify", json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "schema": schema,
+    "claims": claims,
+  }));
+  recorder.record_error("signature of a claim not in the schema", "bls_sign", json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "schema": schema,
+    "claims": { "email": base64::encode("alice@example.com") },
+  }));
+}
+
+fn record_blind_signatures(recorder: &mut Recorder, key: &Value) {
+  let bbs_key = recorder.record("bbs key for the blind signatures", "bls_public_key_to_bbs_key", json!({
+    "public_key": key["public_key"],
+    "message_count": 3,
+  }));
+  let nonce = base64::encode("nonce");
+
+  let commitment = recorder.record("blind signature commitment", "bbs_blind_signature_commitment", seeded(json!({
+    "public_key": bbs_key["public_key"],
+    "messages": [messages()[0]],
+    "blinded": [0],
+    "nonce": nonce,
+  }), "commitment"));
+  recorder.record("blind signature commitment verified", "bbs_verify_blind_signature_proof", json!({
+    "public_key": bbs_key["public_key"],
+    "commitment": commitment["commitment"],
+    "challenge_hash": commitment["challenge_hash"],
+    "proof_of_hidden_messages": commitment["proof_of_hidden_messages"],
+    "blinded": [0],
+    "nonce": nonce,
+  }));
+  // claim names only select messages of a schema, and once panicked without one
+  recorder.record_error("blind signature commitment to a claim without a schema", "bbs_blind_signature_commitment", json!({
+    "public_key": bbs_key["public_key"],
+    "messages": [messages()[0]],
+    "blinded": ["name"],
+    "nonce": nonce,
+  }));
+  recorder.record_error("blind signature commitment to a claim verified without a schema", "bbs_verify_blind_signature_proof", json!({
+    "public_key": bbs_key["public_key"],
+    "commitment": commitment["commitment"],
+    "challenge_hash": commitment["challenge_hash"],
+    "proof_of_hidden_messages": commitment["proof_of_hidden_messages"],
+    "blinded": ["name"],
+    "nonce": nonce,
+  }));
+  let blind_signature = recorder.record("blind signature", "bbs_blind_sign", seeded(json!({
+    "secret_key": key["secret_key"],
+    "public_key": bbs_key["public_key"],
+    "commitment": commitment["commitment"],
+    "messages": [messages()[1], messages()[2]],
+    "known": [1, 2],
+  }), "blind signature"));
+  recorder.record("unblinded signature", "bbs_unblind_signature", json!({
+    "blind_signature": blind_signature["blind_signature"],
+    "blinding_factor": commitment["blinding_factor"],
+  }));
+  recorder.record_error("signature unblinded by an out of range blinding factor", "bbs_unblind_signature", json!({
+    "blind_signature": blind_signature["blind_signature"],
+    "blinding_factor": base64::encode([0xffu8; 32]),
+  }));
+
+  let commitment = recorder.record("bls blind signature commitment", "bls_blind_signature_commitment", seeded(json!({
+    "public_key": key["public_key"],
+    "messages": [messages()[0]],
+    "blinded": [0],
+    "known_message_count": 2,
+    "nonce": nonce,
+  }), "commitment"));
+  recorder.record("bls blind signature commitment verified", "bls_verify_blind_signature_proof", json!({
+    "public_key": key["public_key"],
+    "commitment": commitment["commitment"],
+    "challenge_hash": commitment["challenge_hash"],
+    "proof_of_hidden_messages": commitment["proof_of_hidden_messages"],
+    "blinded": [0],
+    "known_message_count": 2,
+    "nonce": nonce,
+  }));
+  recorder.record("bls blind signature", "bls_blind_sign", seeded(json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "commitment": commitment["commitment"],
+    "messages": [messages()[1], messages()[2]],
+    "known": [1, 2],
+    "blinded_message_count": 1,
+  }), "blind signature"));
+}
+
+fn record_ietf(recorder: &mut Recorder, key: &Value) {
+  let header = base64::encode("header");
+  let signature = recorder.record("ietf signature", "bbs_ietf_sign", json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "header": header,
+    "messages": messages(),
+  }));
+  recorder.record("ietf signature verified", "bbs_ietf_verify", json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "header": header,
+    "messages": messages(),
+  }));
+  let proof = recorder.record("ietf proof", "bbs_ietf_create_proof", seeded(json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "header": header,
+    "presentation_header": base64::encode("presentation header"),
+    "messages": messages(),
+    "revealed": [0, 2],
+  }), "ietf proof"));
+  recorder.record("ietf proof verified", "bbs_ietf_verify_proof", json!({
+    "public_key": key["public_key"],
+    "proof": proof["proof"],
+    "header": header,
+    "presentation_header": base64::encode("presentation header"),
+    "messages": [messages()[0], messages()[2]],
+    "revealed": [0, 2],
+  }));
+
+  let canonical = recorder.record("canonical n-quads", "rdf_canonize", json!({ "document": CREDENTIAL }));
+  let lines: Vec<&str> = canonical["document"].as_str().unwrap().lines().collect();
+  let index = |text: &str| lines.iter().position(|line| line.contains(text)).unwrap();
+  let base_proof = recorder.record("bbs-2023 base proof", "bbs_2023_create_base_proof", seeded(json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "document": CREDENTIAL,
+    "proof_config": PROOF_CONFIG,
+    "mandatory_indexes": [index("credentials#issuer")],
+  }), "base proof"));
+  let derived = recorder.record("bbs-2023 derived proof", "bbs_2023_create_derived_proof", seeded(json!({
+    "document": CREDENTIAL,
+    "proof_value": base_proof["proof_value"],
+    "selective_indexes": [index("schema.org/name")],
+    "presentation_header": base64::encode("nonce"),
+  }), "derived proof"));
+  recorder.record("bbs-2023 derived proof verified", "bbs_2023_verify_derived_proof", json!({
+    "public_key": key["public_key"],
+    "document": derived["document"],
+    "proof_value": derived["proof_value"],
+    "proof_config": PROOF_CONFIG,
+  }));
+
+  for serialization in &["compact", "json"] {
+    let issued = recorder.record(&format!("{} jwp", serialization), "jwp_issue", json!({
+      "secret_key": key["secret_key"],
+      "public_key": key["public_key"],
+      "header": { "kid": "issuer-key", "typ": "JPT" },
+      "payloads": messages(),
+      "serialization": serialization,
+    }));
+    let presented = recorder.record(&format!("{} jwp presentation", serialization), "jwp_present", seeded(json!({
+      "public_key": key["public_key"],
+      "jwp": issued["jwp"],
+      "revealed": [0, 2],
+      "nonce": "n-0S6_WzA2Mj",
+      "audience": "https://verifier.example",
+      "serialization": serialization,
+    }), "jwp presentation"));
+    recorder.record(&format!("{} jwp presentation verified", serialization), "jwp_verify", json!({
+      "public_key": key["public_key"],
+      "jwp": presented["jwp"],
+      "nonce": "n-0S6_WzA2Mj",
+      "audience": "https://verifier.example",
+    }));
+  }
+}
+
+#[test]
+#[ignore]
+fn regenerate_known_answers() {
+  let mut recorder = Recorder { fixtures: Vec::new() };
+  let key = record_keys(&mut recorder);
+  record_signatures_and_proofs(&mut recorder, &key);
+  record_blind_signatures(&mut recorder, &key);
+  record_ietf(&mut recorder, &key);
+  recorder.record("default limits", "bbs_set_limits", json!({}));
+
+  let names: BTreeSet<&str> = recorder.fixtures.iter().map(|fixture| fixture["name"].as_str().unwrap()).collect();
+  assert_eq!(names.len(), recorder.fixtures.len(), "fixture names must be unique");
+  let fixtures = serde_json::to_string_pretty(&recorder.fixtures).unwrap();
+  fs::write(fixtures_path("known_answers.json"), fixtures + "\n").unwrap();
+}
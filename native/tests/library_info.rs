@@ -0,0 +1,77 @@
+//! Library version and capability introspection.
+
+use bbs::rust_bbs::{
+  rust_bbs_library_info,
+  rust_bls_create_proof,
+  rust_bls_generate_g2_key,
+  rust_bls_sign,
+  rust_bls_verify_proof,
+};
+use serde_json::{Value, json};
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn info() -> Value {
+  serde_json::from_str(&rust_bbs_library_info().unwrap()).unwrap()
+}
+
+#[test]
+fn reports_version_and_features() {
+  let info = info();
+  assert_eq!(info["version"], env!("CARGO_PKG_VERSION"));
+
+  let features = info["features"].as_array().unwrap();
+  assert_eq!(features.contains(&json!("c")), cfg!(feature = "c"));
+  assert_eq!(features.contains(&json!("java")), cfg!(feature = "java"));
+  assert_eq!(features.contains(&json!("node")), cfg!(feature = "node"));
+  assert_eq!(features.contains(&json!("blst")), cfg!(feature = "blst"));
+  assert_eq!(info["curve_backend"], if cfg!(feature = "blst") { "blst" } else { "pairing-plus" });
+}
+
+#[test]
+fn reports_ciphersuites_and_limits() {
+  let info = info();
+  let ciphersuites = info["ciphersuites"].as_array().unwrap();
+  assert!(ciphersuites.contains(&json!("BBS+_BLS12381G2_BLAKE2B")));
+  assert!(ciphersuites.contains(&json!("BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_")));
+  assert_eq!(info["limits"]["public_key_cache_capacity"], 128);
+}
+
+#[test]
+fn written_proof_version_is_readable() {
+  let info = info();
+  let write = &info["formats"]["proof"]["write"];
+  assert!(info["formats"]["proof"]["read"].as_array().unwrap().contains(write));
+
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let messages = [base64::encode("Alice")];
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+  }));
+  let proof = call(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [0],
+  }));
+  let proof = base64::decode(proof["proof"].as_str().unwrap()).unwrap();
+  assert_eq!(&proof[..4], b"BBSP");
+  assert_eq!(json!(proof[4]), *write);
+
+  // version 1 envelopes were never released, so they are not read
+  assert!(!info["formats"]["proof"]["read"].as_array().unwrap().contains(&json!(1)));
+  let mut unreleased = proof;
+  unreleased[4] = 1;
+  let verification = call(rust_bls_verify_proof, json!({
+    "public_key": key["public_key"],
+    "proof": base64::encode(&unreleased),
+    "messages": messages,
+    "nonce": "",
+  }));
+  assert_eq!(verification["reason"], "malformed_proof");
+  assert_eq!(verification["detail"], "Unsupported proof version: 1");
+}
@@ -0,0 +1,285 @@
+//! Limits on the input of a single call, and their error code.
+
+use std::sync::{Mutex, MutexGuard};
+use bbs::rust_bbs::{
+  error_code,
+  parse_context,
+  rust_bbs_cose_credential,
+  rust_bbs_ietf_create_proof,
+  rust_bbs_ietf_sign,
+  rust_bbs_ietf_verify_proof,
+  rust_bbs_set_limits,
+  rust_bls_create_presentation,
+  rust_bls_verify_presentation,
+  rust_bls_create_proof,
+  rust_bls_generate_g2_key,
+  rust_bls_public_key_cache_warm,
+  rust_bls_sign,
+  rust_bls_verify_proof,
+  rust_call_cbor,
+  rust_jwp_issue,
+  rust_jwp_verify,
+  rust_rdf_canonize,
+};
+use ciborium::value::Value as Cbor;
+use serde_json::{Value, json};
+
+lazy_static::lazy_static! {
+  // limits are global, so tests changing them take turns
+  static ref LIMITS: Mutex<()> = Mutex::new(());
+}
+
+/// Hold the limits for a test, which are set back to their defaults when it ends
+struct Limits<'a> {
+  _guard: MutexGuard<'a, ()>,
+}
+
+impl Drop for Limits<'_> {
+  fn drop(&mut self) {
+    rust_bbs_set_limits(json!({
+      "max_message_count": 1024,
+      "max_message_size": 64 * 1024,
+      "max_context_size": 4 * 1024 * 1024,
+      "max_batch_size": 64,
+    })).unwrap();
+  }
+}
+
+fn set_limits(context: Value) -> Limits<'static> {
+  let guard = LIMITS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  rust_bbs_set_limits(context).unwrap();
+  Limits { _guard: guard }
+}
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn error(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> String {
+  op(context).unwrap_err().to_string()
+}
+
+fn messages(count: usize) -> Vec<String> {
+  (0..count).map(|i| base64::encode(format!("message {}", i))).collect()
+}
+
+#[test]
+fn message_count_and_size_are_limited() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let _limits = set_limits(json!({ "max_message_count": 2, "max_message_size": 16 }));
+
+  let message = error(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages(3),
+  }));
+  assert!(message.contains("Limit exceeded: 'messages' count is 3, more than the limit of 2"), "{}", message);
+  let sign_error = rust_bls_sign(json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages(3),
+  })).unwrap_err();
+  assert_eq!(error_code(&sign_error), Some("limit_exceeded"));
+
+  let message = error(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": [base64::encode([0u8; 17])],
+  }));
+  assert!(message.contains("Limit exceeded: 'messages' message size is 17"), "{}", message);
+
+  call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages(2),
+  }));
+}
+
+#[test]
+fn batch_size_is_limited() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let _limits = set_limits(json!({ "max_batch_size": 2 }));
+
+  let message = error(rust_bls_public_key_cache_warm, json!({
+    "public_key": key["public_key"],
+    "message_counts": [1, 2, 3],
+  }));
+  assert!(message.contains("Limit exceeded: 'message_counts' batch size is 3"), "{}", message);
+}
+
+#[test]
+fn proof_message_count_is_limited_before_deriving_a_key() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let messages = messages(3);
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+  }));
+  let proof = call(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [0],
+    "nonce": base64::encode("nonce"),
+  }));
+
+  let presentation = call(rust_bls_create_presentation, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [0],
+  }));
+
+  let _limits = set_limits(json!({ "max_message_count": 2 }));
+  let message = error(rust_bls_verify_proof, json!({
+    "public_key": key["public_key"],
+    "proof": proof["proof"],
+    "messages": [messages[0]],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Limit exceeded: message count is 3, more than the limit of 2"), "{}", message);
+
+  // refused, rather than reported as a malformed proof
+  let message = error(rust_bls_verify_presentation, json!({
+    "public_key": key["public_key"],
+    "presentation": presentation["presentation"],
+  }));
+  assert!(message.contains("Limit exceeded: message count is 3, more than the limit of 2"), "{}", message);
+}
+
+#[test]
+fn ietf_proof_message_count_is_limited_before_deriving_generators() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let messages = messages(3);
+  let signature = call(rust_bbs_ietf_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+  }));
+  let proof = call(rust_bbs_ietf_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [0],
+  }));
+
+  // the verifier's context holds one message, but the proof is over three
+  let _limits = set_limits(json!({ "max_message_count": 2 }));
+  let message = error(rust_bbs_ietf_verify_proof, json!({
+    "public_key": key["public_key"],
+    "proof": proof["proof"],
+    "messages": [messages[0]],
+    "revealed": [0],
+  }));
+  assert!(message.contains("Limit exceeded: message count is 3, more than the limit of 2"), "{}", message);
+}
+
+#[test]
+fn nested_messages_are_limited() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let jwp = call(rust_jwp_issue, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "payloads": messages(3),
+  }))["jwp"].clone();
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages(3),
+  }));
+  let credential = call(rust_bbs_cose_credential, json!({
+    "signature": signature["signature"],
+    "messages": messages(3),
+  }))["credential"].clone();
+
+  let _limits = set_limits(json!({ "max_message_count": 2 }));
+  let message = error(rust_jwp_verify, json!({ "public_key": key["public_key"], "jwp": jwp }));
+  assert!(message.contains("Limit exceeded: 'jwp' payload count is 3"), "{}", message);
+
+  let message = error(rust_bls_create_presentation, json!({
+    "public_key": key["public_key"],
+    "credential": credential,
+    "revealed": [0],
+  }));
+  assert!(message.contains("Limit exceeded: 'credential' message count is 3"), "{}", message);
+
+  let message = error(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "document": { "name": "Alice", "birth_date": "1990-01-01", "country": "GB" },
+  }));
+  assert!(message.contains("Limit exceeded: 'document' node count is 4"), "{}", message);
+
+  let message = error(rust_rdf_canonize, json!({
+    "document": "<urn:a> <urn:p> \"1\" .\n<urn:a> <urn:p> \"2\" .\n<urn:a> <urn:p> \"3\" .\n",
+  }));
+  assert!(message.contains("Limit exceeded: 'document' statement count is 3"), "{}", message);
+}
+
+#[test]
+fn context_size_is_limited_before_parsing() {
+  let _limits = set_limits(json!({ "max_context_size": 16 }));
+
+  let message = parse_context(b"{ \"messages\": [] }").unwrap_err();
+  assert!(message.starts_with("Limit exceeded: context size is 18"), "{}", message);
+
+  let result: Cbor = ciborium::de::from_reader(&rust_call_cbor("bls_generate_g2_key", b"{ \"seed\": \"c2VlZA==\" }")[..]).unwrap();
+  let error = result.as_map().unwrap().iter()
+    .find(|(name, _)| name.as_text() == Some("error"))
+    .map(|(_, error)| error.as_map().unwrap())
+    .unwrap();
+  let code = error.iter().find(|(name, _)| name.as_text() == Some("code")).map(|(_, code)| code.as_text());
+  assert_eq!(code, Some(Some("limit_exceeded")));
+}
+
+fn cbor_error_code(operation: &str, context: Value) -> Option<String> {
+  let result: Cbor = ciborium::de::from_reader(&rust_call_cbor(operation, context.to_string().as_bytes())[..]).unwrap();
+  let error = result.as_map().unwrap().iter()
+    .find(|(name, _)| name.as_text() == Some("error"))
+    .map(|(_, error)| error.as_map().unwrap().clone())
+    .unwrap();
+  error.iter().find(|(name, _)| name.as_text() == Some("code")).map(|(_, code)| code.as_text().unwrap().to_string())
+}
+
+#[test]
+fn only_limit_errors_have_the_limit_code() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let _limits = set_limits(json!({ "max_message_count": 2 }));
+
+  // the message repeats the unknown property's name, which isn't taken as the limit's
+  let context = json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages(1),
+    "Limit exceeded: messages": 1,
+  });
+  let error = rust_bls_sign(context.clone()).unwrap_err();
+  assert!(error.to_string().contains("Limit exceeded: messages"), "{}", error);
+  assert_eq!(error_code(&error), None);
+  assert_eq!(cbor_error_code("bls_sign", context), None);
+
+  let code = cbor_error_code("bls_sign", json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages(3),
+  }));
+  assert_eq!(code.as_deref(), Some("limit_exceeded"));
+}
+
+#[test]
+fn limits_are_positive() {
+  let _limits = set_limits(json!({}));
+
+  let message = error(rust_bbs_set_limits, json!({ "max_message_count": 0 }));
+  assert!(message.contains("Invalid 'max_message_count', expected a positive integer"), "{}", message);
+
+  let limits = call(rust_bbs_set_limits, json!({ "max_batch_size": 8 }));
+  assert_eq!(limits, json!({
+    "max_message_count": 1024,
+    "max_message_size": 64 * 1024,
+    "max_context_size": 4 * 1024 * 1024,
+    "max_batch_size": 8,
+  }));
+  assert_eq!(error_code("Failed to sign messages"), None);
+}
@@ -0,0 +1,251 @@
+//! Contexts checked against each operation's request type.
+
+use bbs::rust_bbs::{
+  rust_bbs_blind_sign,
+  rust_bbs_ietf_sign,
+  rust_bbs_blind_signature_commitment,
+  rust_bbs_verify_blind_signature_proof,
+  rust_bls_create_proof,
+  rust_bls_generate_g2_key,
+  rust_bls_key_to_jwk,
+  rust_bls_keystore_import,
+  rust_bls_public_key_cache_warm,
+  rust_bls_public_key_to_bbs_key,
+  rust_bls_secret_key_to_bbs_key,
+  rust_bls_sign,
+  rust_bls_verify_proof,
+};
+use serde_json::{Value, json};
+
+fn call(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> Value {
+  serde_json::from_str(&op(context).unwrap()).unwrap()
+}
+
+fn error(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>, context: Value) -> String {
+  op(context).unwrap_err().to_string()
+}
+
+fn signed() -> (Value, Vec<String>, Value) {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let messages: Vec<String> = ["Alice", "1990-01-01", "GB"].iter().map(base64::encode).collect();
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": messages,
+  }));
+  (key, messages, signature)
+}
+
+#[test]
+fn unknown_properties_are_errors() {
+  let (key, messages, signature) = signed();
+  let message = error(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revaled": [0],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Unknown property: 'revaled', expected one of"), "{}", message);
+  assert!(message.contains("'revealed'"), "{}", message);
+
+  let message = error(rust_bls_generate_g2_key, json!([]));
+  assert!(message.contains("Invalid context, expected a JSON object"), "{}", message);
+}
+
+#[test]
+fn negative_indices_are_errors() {
+  let (key, messages, signature) = signed();
+  let message = error(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [0, -1],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Invalid 'revealed[1]'"), "{}", message);
+
+  let message = error(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [4294967296u64],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Invalid 'revealed[0]'"), "{}", message);
+}
+
+#[test]
+fn mistyped_properties_are_errors() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+
+  let message = error(rust_bls_secret_key_to_bbs_key, json!({ "secret_key": key["secret_key"], "message_count": "3" }));
+  assert!(message.contains("Invalid 'message_count'"), "{}", message);
+
+  let message = error(rust_bls_public_key_cache_warm, json!({ "public_key": key["public_key"], "message_counts": [1, "3"] }));
+  assert!(message.contains("Invalid 'message_counts[1]'"), "{}", message);
+
+  let message = error(rust_bls_secret_key_to_bbs_key, json!({ "secret_key": key["secret_key"] }));
+  assert!(message.contains("Property not set: 'message_count'"), "{}", message);
+}
+
+#[test]
+fn checked_contexts_still_run() {
+  let (key, messages, signature) = signed();
+  let proof = call(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "revealed": [2, 0],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(proof["proof"].is_string());
+}
+
+#[test]
+fn message_indices_past_the_last_are_errors() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let bbs_key = call(rust_bls_public_key_to_bbs_key, json!({ "public_key": key["public_key"], "message_count": 3 }));
+  let commitment = call(rust_bbs_blind_signature_commitment, json!({
+    "public_key": bbs_key["public_key"],
+    "messages": [base64::encode("Alice")],
+    "blinded": [0],
+    "nonce": base64::encode("nonce"),
+  }));
+
+  let message = error(rust_bbs_blind_signature_commitment, json!({
+    "public_key": bbs_key["public_key"],
+    "messages": [base64::encode("Alice")],
+    "blinded": [3],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Index is out of bounds. Must be between 0 and 2: found 3"), "{}", message);
+
+  let message = error(rust_bbs_verify_blind_signature_proof, json!({
+    "public_key": bbs_key["public_key"],
+    "commitment": commitment["commitment"],
+    "challenge_hash": commitment["challenge_hash"],
+    "proof_of_hidden_messages": commitment["proof_of_hidden_messages"],
+    "blinded": [3],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Index is out of bounds. Must be between 0 and 2: found 3"), "{}", message);
+
+  let message = error(rust_bbs_blind_sign, json!({
+    "secret_key": key["secret_key"],
+    "public_key": bbs_key["public_key"],
+    "commitment": commitment["commitment"],
+    "messages": [base64::encode("1990-01-01"), base64::encode("GB")],
+    "known": [1, 3],
+  }));
+  assert!(message.contains("Index is out of bounds. Must be between 0 and 2: found 3"), "{}", message);
+}
+
+/// Check signing by `op` rejects another key's public key given with the secret key
+fn rejects_other_public_keys(op: fn(Value) -> Result<String, bbs_plus::errors::BBSError>) {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let other_key = call(rust_bls_generate_g2_key, json!({}));
+  let messages = vec![base64::encode("Alice")];
+  let mismatch = "Invalid 'public_key', expected the public key of 'secret_key'";
+
+  let message = error(op, json!({
+    "secret_key": key["secret_key"],
+    "public_key": other_key["public_key"],
+    "messages": messages,
+  }));
+  assert!(message.contains(mismatch), "{}", message);
+
+  // a private JWK's 'x' is its public key
+  let mut jwk = call(rust_bls_key_to_jwk, json!({ "public_key": key["public_key"], "secret_key": key["secret_key"] }));
+  jwk["x"] = call(rust_bls_key_to_jwk, json!({ "public_key": other_key["public_key"] }))["x"].clone();
+  let message = error(op, json!({ "secret_key": jwk, "messages": messages }));
+  assert!(message.contains(mismatch), "{}", message);
+
+  let stored = call(rust_bls_keystore_import, json!({ "secret_key": key["secret_key"] }));
+  let message = error(op, json!({
+    "key_handle": stored["key_handle"],
+    "public_key": other_key["public_key"],
+    "messages": messages,
+  }));
+  assert!(message.contains(mismatch), "{}", message);
+}
+
+#[test]
+fn public_keys_of_other_secret_keys_are_errors() {
+  rejects_other_public_keys(rust_bls_sign);
+  rejects_other_public_keys(rust_bbs_ietf_sign);
+}
+
+#[test]
+fn conflicting_message_selectors_are_errors() {
+  let key = call(rust_bls_generate_g2_key, json!({}));
+  let schema = json!(["name", "country"]);
+  let claims = json!({ "name": base64::encode("Alice"), "country": base64::encode("GB") });
+  let messages = vec![base64::encode("Alice"), base64::encode("GB")];
+
+  let message = error(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "claims": claims,
+    "messages": messages,
+  }));
+  assert!(message.contains("Property 'claims' requires 'schema'"), "{}", message);
+
+  let message = error(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "schema": schema,
+    "claims": claims,
+    "messages": messages,
+  }));
+  assert!(message.contains("Only one of 'claims' and 'messages' can be set"), "{}", message);
+
+  let message = error(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "schema": schema,
+    "document": { "name": "Alice" },
+  }));
+  assert!(message.contains("Property 'document' can't be used with 'schema'"), "{}", message);
+
+  let signature = call(rust_bls_sign, json!({
+    "secret_key": key["secret_key"],
+    "schema": schema,
+    "claims": claims,
+  }));
+  let message = error(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "messages": messages,
+    "reveal": ["name"],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Property 'reveal' requires 'schema' or 'document'"), "{}", message);
+
+  let message = error(rust_bls_create_proof, json!({
+    "public_key": key["public_key"],
+    "signature": signature["signature"],
+    "schema": schema,
+    "claims": claims,
+    "reveal": ["name"],
+    "revealed": [1],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Only one of 'reveal' and 'revealed' can be set"), "{}", message);
+
+  let message = error(rust_bls_verify_proof, json!({
+    "public_key": key["public_key"],
+    "proof": signature["signature"],
+    "claims": { "name": base64::encode("Alice") },
+    "messages": [base64::encode("Alice")],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Property 'claims' requires 'schema'"), "{}", message);
+
+  let message = error(rust_bbs_blind_signature_commitment, json!({
+    "public_key": call(rust_bls_public_key_to_bbs_key, json!({ "public_key": key["public_key"], "message_count": 2 }))["public_key"],
+    "schema": schema,
+    "claims": { "name": base64::encode("Alice") },
+    "messages": [base64::encode("Alice")],
+    "blinded": ["name"],
+    "nonce": base64::encode("nonce"),
+  }));
+  assert!(message.contains("Only one of 'claims' and 'messages' can be set"), "{}", message);
+}
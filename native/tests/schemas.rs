@@ -0,0 +1,73 @@
+//! JSON Schemas of operation contexts and results.
+
+use bbs::rust_bbs::{
+  rust_bbs_get_schema,
+  rust_bls_generate_g2_key,
+  rust_bls_sign,
+};
+use serde_json::{Value, json};
+
+fn schema(operation: &str) -> Value {
+  serde_json::from_str(&rust_bbs_get_schema(operation).unwrap()).unwrap()
+}
+
+fn property_names(schema: &Value) -> Vec<&str> {
+  schema["properties"].as_object().unwrap().keys().map(String::as_str).collect()
+}
+
+#[test]
+fn request_schema_describes_context() {
+  let schemas = schema("bls_sign");
+  assert_eq!(schemas["operation"], "bls_sign");
+
+  let request = &schemas["request"];
+  assert_eq!(request["$schema"], "https://json-schema.org/draft/2020-12/schema");
+  assert_eq!(request["additionalProperties"], false);
+  assert_eq!(request["properties"]["messages"]["anyOf"][0]["items"], json!({ "type": "string" }));
+  assert_eq!(request["properties"]["encoding"]["enum"], json!(["base64", "base64url", "hex", "cbor"]));
+  assert_eq!(request["required"], json!([]));
+
+  let request = &schema("bls_create_proof")["request"];
+  assert_eq!(request["required"], json!(["public_key"]));
+  assert_eq!(request["properties"]["revealed"]["anyOf"][0]["items"]["maximum"], u32::MAX);
+}
+
+#[test]
+fn response_schema_describes_result() {
+  let key = rust_bls_generate_g2_key(json!({})).unwrap();
+  let key: Value = serde_json::from_str(&key).unwrap();
+  let signature: Value = serde_json::from_str(&rust_bls_sign(json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": [base64::encode("Alice")],
+  })).unwrap()).unwrap();
+
+  let response = &schema("bls_sign")["response"];
+  assert_eq!(response["required"], json!(["signature"]));
+  assert_eq!(property_names(response), signature.as_object().unwrap().keys().map(String::as_str).collect::<Vec<&str>>());
+
+  let response = &schema("bls_generate_g2_key")["response"];
+  assert_eq!(property_names(response), key.as_object().unwrap().keys().map(String::as_str).collect::<Vec<&str>>());
+
+  let response = &schema("bls_resolve_did_key")["response"];
+  assert!(property_names(response).contains(&"type"));
+
+  let response = &schema("bbs_verify_proof")["response"];
+  assert!(response["properties"]["reason"]["enum"].as_array().unwrap().contains(&json!("challenge_mismatch")));
+}
+
+#[test]
+fn nested_result_types_have_schemas() {
+  let presentation = &schema("bls_create_presentation")["response"]["properties"]["presentation"];
+  assert_eq!(presentation["required"], json!(["version", "proof", "revealed_messages"]));
+  assert_eq!(presentation["properties"]["message_format"]["enum"], json!(["json_pointer"]));
+
+  let encrypted_key = &schema("bls_export_encrypted_key")["response"];
+  assert_eq!(encrypted_key["properties"]["kdf"]["additionalProperties"], false);
+}
+
+#[test]
+fn unknown_operation_is_an_error() {
+  let message = rust_bbs_get_schema("bls_unknown").unwrap_err().to_string();
+  assert!(message.contains("Unknown operation: bls_unknown"), "{}", message);
+}
@@ -0,0 +1,198 @@
+//! Checks that secret keys, blinding factors and seeds are wiped before their memory is freed.
+//!
+//! Every heap block freed whilst scanning is enabled is searched for the secret values,
+//! so any buffer dropped without being zeroized fails the test.
+
+use bbs::rust_bbs::{
+  rust_bls_generate_blinded_g2_key,
+  rust_bls_generate_g2_key,
+  rust_bls_secret_key_to_bbs_key,
+  rust_bls_sign,
+  rust_bbs_unblind_signature,
+};
+use serde_json::{Value, json};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use zeroize::Zeroizing;
+
+const MAX_MARKERS: usize = 8;
+
+struct ScanningAllocator;
+
+static SCANNING: AtomicBool = AtomicBool::new(false);
+static FOUND: AtomicBool = AtomicBool::new(false);
+static MARKER_PTRS: [AtomicPtr<u8>; MAX_MARKERS] = [
+  AtomicPtr::new(std::ptr::null_mut()), AtomicPtr::new(std::ptr::null_mut()),
+  AtomicPtr::new(std::ptr::null_mut()), AtomicPtr::new(std::ptr::null_mut()),
+  AtomicPtr::new(std::ptr::null_mut()), AtomicPtr::new(std::ptr::null_mut()),
+  AtomicPtr::new(std::ptr::null_mut()), AtomicPtr::new(std::ptr::null_mut()),
+];
+static MARKER_LENS: [AtomicUsize; MAX_MARKERS] = [
+  AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0),
+  AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0),
+];
+
+// tests share the allocator state, so run them one at a time
+static SERIAL: Mutex<()> = Mutex::new(());
+
+unsafe impl GlobalAlloc for ScanningAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    if SCANNING.load(Ordering::SeqCst) {
+      let block = std::slice::from_raw_parts(ptr, layout.size());
+      for (marker_ptr, marker_len) in MARKER_PTRS.iter().zip(&MARKER_LENS) {
+        let length = marker_len.load(Ordering::SeqCst);
+        if length == 0 {
+          continue;
+        }
+        let marker = std::slice::from_raw_parts(marker_ptr.load(Ordering::SeqCst), length);
+        if block.windows(length).any(|window| window == marker) {
+          FOUND.store(true, Ordering::SeqCst);
+        }
+      }
+    }
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static ALLOCATOR: ScanningAllocator = ScanningAllocator;
+
+/// Run `f` whilst checking freed memory for any of `secrets`, returning whether one was found
+fn leaves_secrets_in_freed_memory<F: FnOnce()>(secrets: &[&[u8]], f: F) -> bool {
+  assert!(secrets.len() <= MAX_MARKERS);
+  for (i, secret) in secrets.iter().enumerate() {
+    assert!(!secret.is_empty());
+    MARKER_PTRS[i].store(secret.as_ptr() as *mut u8, Ordering::SeqCst);
+    MARKER_LENS[i].store(secret.len(), Ordering::SeqCst);
+  }
+  FOUND.store(false, Ordering::SeqCst);
+
+  SCANNING.store(true, Ordering::SeqCst);
+  f();
+  SCANNING.store(false, Ordering::SeqCst);
+
+  for length in &MARKER_LENS {
+    length.store(0, Ordering::SeqCst);
+  }
+  FOUND.load(Ordering::SeqCst)
+}
+
+fn parse(output: &str) -> Value {
+  serde_json::from_str(output).unwrap()
+}
+
+fn property(output: &Value, name: &str) -> String {
+  output[name].as_str().unwrap().to_string()
+}
+
+#[test]
+fn scanning_allocator_detects_unwiped_secret() {
+  let _serial = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let secret = b"not wiped before being freed".to_vec();
+
+  assert!(leaves_secrets_in_freed_memory(&[&secret], || {
+    drop(secret.clone());
+  }));
+  assert!(!leaves_secrets_in_freed_memory(&[&secret], || {
+    drop(Zeroizing::new(secret.clone()));
+  }));
+}
+
+#[test]
+fn key_generation_wipes_seed_and_secret_key() {
+  let _serial = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let seed = b"zeroization test seed, at least 32 bytes long".to_vec();
+  let seed_b64 = base64::encode(&seed);
+
+  let expected = Zeroizing::new(rust_bls_generate_g2_key(json!({ "seed": seed_b64 })).unwrap());
+  let secret_key_b64 = property(&parse(&expected), "secret_key");
+  let secret_key = base64::decode(&secret_key_b64).unwrap();
+
+  let context = json!({ "seed": seed_b64 });
+  let leaked = leaves_secrets_in_freed_memory(&[&seed, seed_b64.as_bytes(), &secret_key, secret_key_b64.as_bytes()], || {
+    let output = Zeroizing::new(rust_bls_generate_g2_key(context).unwrap());
+    assert_eq!(*output, *expected);
+  });
+  assert!(!leaked, "seed or secret key left in freed memory");
+}
+
+#[test]
+fn blinded_key_generation_wipes_blinding_factor() {
+  let _serial = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let seed = b"zeroization test seed, at least 32 bytes long".to_vec();
+  let seed_b64 = base64::encode(&seed);
+
+  let expected = Zeroizing::new(rust_bls_generate_blinded_g2_key(json!({ "seed": seed_b64 })).unwrap());
+  let key = parse(&expected);
+  let secret_key_b64 = property(&key, "secret_key");
+  let blinding_factor_b64 = property(&key, "blinding_factor");
+  let secret_key = base64::decode(&secret_key_b64).unwrap();
+  let blinding_factor = base64::decode(&blinding_factor_b64).unwrap();
+
+  let context = json!({ "seed": seed_b64 });
+  let secrets: [&[u8]; 6] = [
+    &seed,
+    seed_b64.as_bytes(),
+    &secret_key,
+    &blinding_factor,
+    secret_key_b64.as_bytes(),
+    blinding_factor_b64.as_bytes(),
+  ];
+  let leaked = leaves_secrets_in_freed_memory(&secrets, || {
+    let output = Zeroizing::new(rust_bls_generate_blinded_g2_key(context).unwrap());
+    assert_eq!(*output, *expected);
+  });
+  assert!(!leaked, "seed, secret key or blinding factor left in freed memory");
+}
+
+#[test]
+fn signing_wipes_secret_key() {
+  let _serial = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let key = parse(&rust_bls_generate_g2_key(json!({})).unwrap());
+  let secret_key_b64 = property(&key, "secret_key");
+  let secret_key = base64::decode(&secret_key_b64).unwrap();
+
+  let sign_context = json!({
+    "secret_key": secret_key_b64,
+    "public_key": key["public_key"],
+    "messages": [base64::encode("message")],
+  });
+  let bbs_key_context = json!({
+    "secret_key": secret_key_b64,
+    "message_count": 1,
+  });
+
+  let leaked = leaves_secrets_in_freed_memory(&[&secret_key, secret_key_b64.as_bytes()], || {
+    rust_bls_sign(sign_context).unwrap();
+    rust_bls_secret_key_to_bbs_key(bbs_key_context).unwrap();
+  });
+  assert!(!leaked, "secret key left in freed memory");
+}
+
+#[test]
+fn unblinding_wipes_blinding_factor() {
+  let _serial = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let blinding_factor = vec![7u8; 32];
+  let blinding_factor_b64 = base64::encode(&blinding_factor);
+  let key = parse(&rust_bls_generate_g2_key(json!({})).unwrap());
+  let signature = parse(&rust_bls_sign(json!({
+    "secret_key": key["secret_key"],
+    "public_key": key["public_key"],
+    "messages": [base64::encode("message")],
+  })).unwrap());
+
+  let context = json!({
+    "blind_signature": signature["signature"],
+    "blinding_factor": blinding_factor_b64,
+  });
+
+  let leaked = leaves_secrets_in_freed_memory(&[&blinding_factor, blinding_factor_b64.as_bytes()], || {
+    rust_bbs_unblind_signature(context).unwrap();
+  });
+  assert!(!leaked, "blinding factor left in freed memory");
+}
@@ -21,6 +21,9 @@ describe('NEON NodeJS Interface:', () => {
   it('should export the expected items', () => {
     expect(Object.keys(bbs)).toEqual([
       'bbs_blind_signature_size',
+      'call_cbor',
+      'bbs_get_schema',
+      'bbs_library_info',
       'bbs_blinding_factor_size',
       'bls_public_key_g1_size',
       'bls_public_key_g2_size',
@@ -34,24 +37,60 @@ describe('NEON NodeJS Interface:', () => {
       'bls_public_key_to_bbs_key',
       'bbs_sign',
       'bls_sign',
+      'bbs_ietf_sign',
+      'bbs_ietf_verify',
+      'bbs_ietf_create_proof',
+      'bbs_ietf_verify_proof',
+      'rdf_canonize',
+      'bbs_2023_create_base_proof',
+      'bbs_2023_create_derived_proof',
+      'bbs_2023_verify_derived_proof',
+      'jwp_issue',
+      'jwp_present',
+      'jwp_verify',
       'bbs_verify',
       'bls_verify',
       'bbs_create_proof',
       'bls_create_proof',
       'bbs_verify_proof',
       'bls_verify_proof',
+      'bbs_create_presentation',
+      'bls_create_presentation',
+      'bbs_verify_presentation',
+      'bls_verify_presentation',
+      'bbs_cose_credential',
+      'bbs_cose_presentation',
+      'bbs_schema_hash',
       'bbs_blind_signature_commitment',
       'bls_blind_signature_commitment',
       'bbs_verify_blind_signature_proof',
       'bls_verify_blind_signature_proof',
       'bbs_blind_sign',
       'bls_blind_sign',
-      'bbs_get_unblinded_signature'
+      'bbs_get_unblinded_signature',
+      'bls_public_key_cache_warm',
+      'bls_public_key_cache_clear',
+      'bls_keystore_import',
+      'bls_keystore_generate',
+      'bls_keystore_public_key',
+      'bls_keystore_destroy',
+      'bls_export_encrypted_key',
+      'bls_import_encrypted_key',
+      'bls_key_to_jwk',
+      'bls_jwk_to_key',
+      'bls_public_key_to_multikey',
+      'bls_multikey_to_public_key',
+      'bls_public_key_to_did_key',
+      'bls_resolve_did_key',
+      'bbs_set_limits'
     ])
   })
 
   it('should export foreign function interface functions', () => {
     expect(typeof bbs.bbs_blind_signature_size).toBe('function')
+    expect(typeof bbs.call_cbor).toBe('function')
+    expect(typeof bbs.bbs_get_schema).toBe('function')
+    expect(typeof bbs.bbs_library_info).toBe('function')
     expect(typeof bbs.bbs_blinding_factor_size).toBe('function')
     expect(typeof bbs.bls_public_key_g1_size).toBe('function')
     expect(typeof bbs.bls_public_key_g2_size).toBe('function')
@@ -65,12 +104,30 @@ describe('NEON NodeJS Interface:', () => {
     expect(typeof bbs.bls_public_key_to_bbs_key).toBe('function')
     expect(typeof bbs.bbs_sign).toBe('function')
     expect(typeof bbs.bls_sign).toBe('function')
+    expect(typeof bbs.bbs_ietf_sign).toBe('function')
+    expect(typeof bbs.bbs_ietf_verify).toBe('function')
+    expect(typeof bbs.bbs_ietf_create_proof).toBe('function')
+    expect(typeof bbs.bbs_ietf_verify_proof).toBe('function')
+    expect(typeof bbs.rdf_canonize).toBe('function')
+    expect(typeof bbs.bbs_2023_create_base_proof).toBe('function')
+    expect(typeof bbs.bbs_2023_create_derived_proof).toBe('function')
+    expect(typeof bbs.bbs_2023_verify_derived_proof).toBe('function')
+    expect(typeof bbs.jwp_issue).toBe('function')
+    expect(typeof bbs.jwp_present).toBe('function')
+    expect(typeof bbs.jwp_verify).toBe('function')
     expect(typeof bbs.bbs_verify).toBe('function')
     expect(typeof bbs.bls_verify).toBe('function')
     expect(typeof bbs.bbs_create_proof).toBe('function')
     expect(typeof bbs.bls_create_proof).toBe('function')
     expect(typeof bbs.bbs_verify_proof).toBe('function')
     expect(typeof bbs.bls_verify_proof).toBe('function')
+    expect(typeof bbs.bbs_create_presentation).toBe('function')
+    expect(typeof bbs.bls_create_presentation).toBe('function')
+    expect(typeof bbs.bbs_verify_presentation).toBe('function')
+    expect(typeof bbs.bls_verify_presentation).toBe('function')
+    expect(typeof bbs.bbs_cose_credential).toBe('function')
+    expect(typeof bbs.bbs_cose_presentation).toBe('function')
+    expect(typeof bbs.bbs_schema_hash).toBe('function')
     expect(typeof bbs.bbs_blind_signature_commitment).toBe('function')
     expect(typeof bbs.bls_blind_signature_commitment).toBe('function')
     expect(typeof bbs.bbs_verify_blind_signature_proof).toBe('function')
@@ -78,6 +135,21 @@ describe('NEON NodeJS Interface:', () => {
     expect(typeof bbs.bbs_blind_sign).toBe('function')
     expect(typeof bbs.bls_blind_sign).toBe('function')
     expect(typeof bbs.bbs_get_unblinded_signature).toBe('function')
+    expect(typeof bbs.bls_public_key_cache_warm).toBe('function')
+    expect(typeof bbs.bls_public_key_cache_clear).toBe('function')
+    expect(typeof bbs.bls_keystore_import).toBe('function')
+    expect(typeof bbs.bls_keystore_generate).toBe('function')
+    expect(typeof bbs.bls_keystore_public_key).toBe('function')
+    expect(typeof bbs.bls_keystore_destroy).toBe('function')
+    expect(typeof bbs.bls_export_encrypted_key).toBe('function')
+    expect(typeof bbs.bls_import_encrypted_key).toBe('function')
+    expect(typeof bbs.bls_key_to_jwk).toBe('function')
+    expect(typeof bbs.bls_jwk_to_key).toBe('function')
+    expect(typeof bbs.bls_public_key_to_multikey).toBe('function')
+    expect(typeof bbs.bls_multikey_to_public_key).toBe('function')
+    expect(typeof bbs.bls_public_key_to_did_key).toBe('function')
+    expect(typeof bbs.bls_resolve_did_key).toBe('function')
+    expect(typeof bbs.bbs_set_limits).toBe('function')
   })
 
   describe('Functions', () => {
@@ -473,13 +545,13 @@ describe('NEON NodeJS Interface:', () => {
         it('where 1 message revealed', () => {
           const { proof } = wrapFFI(bbs.bbs_create_proof, { signature: standardSignature.signature, public_key: bbsPublicKey.public_key, messages, revealed: [ 1 ], nonce })
 
-          expect(Buffer.from(proof, 'base64').length).toBe(447)
+          expect(Buffer.from(proof, 'base64').length).toBe(452)
         })
 
         it('where 3 messages revealed', () => {
           const { proof } = wrapFFI(bbs.bbs_create_proof, { signature: standardSignature.signature, public_key: bbsPublicKey.public_key, messages, revealed: [ 0, 1, 2 ], nonce })
 
-          expect(Buffer.from(proof, 'base64').length).toBe(383)
+          expect(Buffer.from(proof, 'base64').length).toBe(388)
         })
 
         it('where signature contains blinded messages', () => {
@@ -493,7 +565,7 @@ describe('NEON NodeJS Interface:', () => {
           // generate proof with blinded & unblinded messages
           const { proof } = wrapFFI(bbs.bbs_create_proof, { signature, public_key: bbsPublicKey.public_key, messages, revealed: [ 0, 1, 2 ], nonce })
 
-          expect(Buffer.from(proof, 'base64').length).toBe(383)
+          expect(Buffer.from(proof, 'base64').length).toBe(388)
         })
 
       })
@@ -728,6 +800,869 @@ describe('NEON NodeJS Interface:', () => {
 
     })
 
+    describe('bls_public_key_cache_warm()', () => {
+      let blsKey
+
+      beforeAll(() => {
+        bbs.bls_public_key_cache_clear()
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+      })
+
+      it('should cache a BBS public key for each message count', () => {
+        const { cache_size } = wrapFFI(bbs.bls_public_key_cache_warm, { public_key: blsKey.public_key, message_counts: [ 1, 3 ] })
+
+        expect(cache_size).toBe(2)
+      })
+
+      it('should derive the same BBS public key once cached', () => {
+        wrapFFI(bbs.bls_public_key_cache_warm, { public_key: blsKey.public_key, message_counts: [ 3 ] })
+
+        const cached = wrapFFI(bbs.bls_public_key_to_bbs_key, { message_count: 3, public_key: blsKey.public_key })
+        bbs.bls_public_key_cache_clear()
+        const derived = wrapFFI(bbs.bls_public_key_to_bbs_key, { message_count: 3, public_key: blsKey.public_key })
+
+        expect(cached.public_key).toBe(derived.public_key)
+      })
+
+      it('should fail where "message_counts" contains zero', () => {
+        const result = wrapFFI(bbs.bls_public_key_cache_warm, { public_key: blsKey.public_key, message_counts: [ 0 ] })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_public_key_cache_clear()', () => {
+
+      it('should return the number of cleared entries', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        bbs.bls_public_key_cache_clear()
+        wrapFFI(bbs.bls_public_key_cache_warm, { public_key: blsKey.public_key, message_counts: [ 1, 2, 3 ] })
+
+        expect(bbs.bls_public_key_cache_clear()).toBe(3)
+        expect(bbs.bls_public_key_cache_clear()).toBe(0)
+      })
+
+    })
+
+    describe('bls_keystore_import()', () => {
+      let blsKey
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+      })
+
+      it('should return a key handle and the matching public key', () => {
+        const { key_handle, public_key } = wrapFFI(bbs.bls_keystore_import, { secret_key: blsKey.secret_key })
+
+        expect(typeof key_handle).toBe('number')
+        expect(public_key).toBe(blsKey.public_key)
+      })
+
+      it('should fail where "secret_key" has an invalid length', () => {
+        const result = wrapFFI(bbs.bls_keystore_import, { secret_key: 'AAAA' })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_keystore_generate()', () => {
+
+      it('should generate the same public key as bls_generate_g2_key() for a seed', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const { key_handle, public_key, secret_key } = wrapFFI(bbs.bls_keystore_generate, { seed })
+
+        expect(typeof key_handle).toBe('number')
+        expect(public_key).toBe(blsKey.public_key)
+        expect(secret_key).toBeUndefined()
+      })
+
+      it('should return a new key handle for each key', () => {
+        const first = wrapFFI(bbs.bls_keystore_generate, {})
+        const second = wrapFFI(bbs.bls_keystore_generate, {})
+
+        expect(first.key_handle).not.toBe(second.key_handle)
+        expect(first.public_key).not.toBe(second.public_key)
+      })
+
+    })
+
+    describe('bls_keystore_public_key()', () => {
+
+      it('should return the public key for a key handle', () => {
+        const { key_handle, public_key } = wrapFFI(bbs.bls_keystore_generate, { seed })
+
+        expect(wrapFFI(bbs.bls_keystore_public_key, { key_handle }).public_key).toBe(public_key)
+      })
+
+      it('should fail for an unknown key handle', () => {
+        const result = wrapFFI(bbs.bls_keystore_public_key, { key_handle: 0 })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('signing by key handle', () => {
+      let blsKey, key_handle
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        key_handle = wrapFFI(bbs.bls_keystore_import, { secret_key: blsKey.secret_key }).key_handle
+      })
+
+      it('should derive the same BBS public key as the secret key', () => {
+        const bySecretKey = wrapFFI(bbs.bls_secret_key_to_bbs_key, { secret_key: blsKey.secret_key, message_count: 3 })
+        const byHandle = wrapFFI(bbs.bls_secret_key_to_bbs_key, { key_handle, message_count: 3 })
+
+        expect(byHandle.public_key).toBe(bySecretKey.public_key)
+      })
+
+      it('should sign messages verifiable with the public key', () => {
+        const { signature } = wrapFFI(bbs.bls_sign, { key_handle, messages })
+
+        const { verified } = wrapFFI(bbs.bls_verify, { public_key: blsKey.public_key, signature, messages })
+
+        expect(verified).toBe(true)
+      })
+
+      it('should blind sign messages', () => {
+        const bbsPublicKey = wrapFFI(bbs.bls_public_key_to_bbs_key, { public_key: blsKey.public_key, message_count: 3 })
+        const { commitment, blinding_factor } = wrapFFI(bbs.bbs_blind_signature_commitment, { public_key: bbsPublicKey.public_key, messages: [ messages[0] ], blinded: [ 0 ], nonce })
+
+        const { blind_signature } = wrapFFI(bbs.bbs_blind_sign, { commitment, public_key: bbsPublicKey.public_key, key_handle, messages: [ messages[1], messages[2] ], known: [ 1, 2 ] })
+        const { signature } = wrapFFI(bbs.bbs_get_unblinded_signature, { blind_signature, blinding_factor })
+
+        const { verified } = wrapFFI(bbs.bbs_verify, { public_key: bbsPublicKey.public_key, signature, messages })
+
+        expect(verified).toBe(true)
+      })
+
+      it('should fail where both "key_handle" and "secret_key" are set', () => {
+        const result = wrapFFI(bbs.bls_sign, { key_handle, secret_key: blsKey.secret_key, messages })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_keystore_destroy()', () => {
+
+      it('should remove the key, so it can no longer sign', () => {
+        const { key_handle } = wrapFFI(bbs.bls_keystore_generate, {})
+
+        expect(wrapFFI(bbs.bls_keystore_destroy, { key_handle }).destroyed).toBe(true)
+        expect(wrapFFI(bbs.bls_keystore_destroy, { key_handle }).destroyed).toBe(false)
+        expect(wrapFFI(bbs.bls_sign, { key_handle, messages }).error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_export_encrypted_key()', () => {
+      let blsKey
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+      })
+
+      it('should export a versioned, self-describing encrypted key', () => {
+        const encryptedKey = wrapFFI(bbs.bls_export_encrypted_key, { key_type: 'g2', public_key: blsKey.public_key, secret_key: blsKey.secret_key, password: 'correct horse' })
+
+        expect(encryptedKey.version).toBe(1)
+        expect(encryptedKey.key_type).toBe('g2')
+        expect(encryptedKey.public_key).toBe(blsKey.public_key)
+        expect(encryptedKey.kdf.name).toBe('argon2id')
+        expect(encryptedKey.cipher.name).toBe('chacha20-poly1305')
+        expect(JSON.stringify(encryptedKey)).not.toContain(blsKey.secret_key)
+      })
+
+      it('should fail where "public_key" does not match "key_type"', () => {
+        const result = wrapFFI(bbs.bls_export_encrypted_key, { key_type: 'g1', public_key: blsKey.public_key, secret_key: blsKey.secret_key, password: 'correct horse' })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+      it('should fail where "password" is not set', () => {
+        const result = wrapFFI(bbs.bls_export_encrypted_key, { key_type: 'g2', public_key: blsKey.public_key, secret_key: blsKey.secret_key })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_import_encrypted_key()', () => {
+
+      it('should import an exported G2 key', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const encrypted_key = wrapFFI(bbs.bls_export_encrypted_key, { key_type: 'g2', ...blsKey, password: 'correct horse' })
+
+        const imported = wrapFFI(bbs.bls_import_encrypted_key, { encrypted_key, password: 'correct horse' })
+
+        expect(imported).toEqual({ key_type: 'g2', public_key: blsKey.public_key, secret_key: blsKey.secret_key })
+      })
+
+      it('should import an exported blinded G1 key, with its blinding factor', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_blinded_g1_key, { seed })
+        const encrypted_key = wrapFFI(bbs.bls_export_encrypted_key, { key_type: 'blinded_g1', ...blsKey, password: 'correct horse' })
+
+        const imported = wrapFFI(bbs.bls_import_encrypted_key, { encrypted_key, password: 'correct horse' })
+
+        expect(imported).toEqual({ key_type: 'blinded_g1', ...blsKey })
+      })
+
+      it('should fail with an incorrect password', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const encrypted_key = wrapFFI(bbs.bls_export_encrypted_key, { key_type: 'g2', ...blsKey, password: 'correct horse' })
+
+        const result = wrapFFI(bbs.bls_import_encrypted_key, { encrypted_key, password: 'battery staple' })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+      it('should fail where the public key has been swapped', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const otherKey = wrapFFI(bbs.bls_generate_g2_key, {})
+        const encrypted_key = wrapFFI(bbs.bls_export_encrypted_key, { key_type: 'g2', ...blsKey, password: 'correct horse' })
+
+        const result = wrapFFI(bbs.bls_import_encrypted_key, { encrypted_key: { ...encrypted_key, public_key: otherKey.public_key }, password: 'correct horse' })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_key_to_jwk()', () => {
+
+      it('should convert a G2 key pair to a private JWK', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+
+        const jwk = wrapFFI(bbs.bls_key_to_jwk, blsKey)
+
+        expect(jwk.kty).toBe('OKP')
+        expect(jwk.crv).toBe('BLS12381G2')
+        expect(Buffer.from(jwk.x, 'base64url').toString('base64')).toBe(blsKey.public_key)
+        expect(Buffer.from(jwk.d, 'base64url').toString('base64')).toBe(blsKey.secret_key)
+      })
+
+      it('should convert a G1 public key to a public JWK', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g1_key, { seed })
+
+        const jwk = wrapFFI(bbs.bls_key_to_jwk, { public_key: blsKey.public_key })
+
+        expect(jwk.crv).toBe('BLS12381G1')
+        expect(jwk.d).toBeUndefined()
+      })
+
+    })
+
+    describe('bls_jwk_to_key()', () => {
+
+      it('should convert a JWK back to the key pair', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const jwk = wrapFFI(bbs.bls_key_to_jwk, blsKey)
+
+        expect(wrapFFI(bbs.bls_jwk_to_key, { jwk })).toEqual(blsKey)
+      })
+
+      it('should fail for a JWK of another key type', () => {
+        const result = wrapFFI(bbs.bls_jwk_to_key, { jwk: { kty: 'RSA', n: 'AQAB' } })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('JWK keys in contexts', () => {
+
+      it('should sign with a private JWK, and verify with a public JWK', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const privateJwk = wrapFFI(bbs.bls_key_to_jwk, blsKey)
+        const publicJwk = wrapFFI(bbs.bls_key_to_jwk, { public_key: blsKey.public_key })
+
+        const { signature } = wrapFFI(bbs.bls_sign, { secret_key: privateJwk, messages })
+        const { verified } = wrapFFI(bbs.bls_verify, { public_key: publicJwk, signature, messages })
+
+        expect(verified).toBe(true)
+      })
+
+      it('should create and verify proofs with a public JWK', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const publicJwk = { ...wrapFFI(bbs.bls_key_to_jwk, { public_key: blsKey.public_key }), kty: 'EC', crv: 'BLS12381_G2' }
+        const { signature } = wrapFFI(bbs.bls_sign, { ...blsKey, messages })
+
+        const { proof } = wrapFFI(bbs.bls_create_proof, { signature, public_key: publicJwk, messages, revealed: [ 0 ], nonce })
+        const { verified } = wrapFFI(bbs.bls_verify_proof, { proof, public_key: publicJwk, messages: [ messages[0] ], nonce })
+
+        expect(verified).toBe(true)
+      })
+
+    })
+
+    describe('bls_public_key_to_multikey()', () => {
+
+      it('should encode G1 and G2 public keys with their multicodec prefixes', () => {
+        const g2Key = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const g1Key = wrapFFI(bbs.bls_generate_g1_key, { seed })
+
+        expect(wrapFFI(bbs.bls_public_key_to_multikey, { public_key: g2Key.public_key }).public_key_multibase).toMatch(/^zUC7/)
+        expect(wrapFFI(bbs.bls_public_key_to_multikey, { public_key: g1Key.public_key }).public_key_multibase).toMatch(/^z3t/)
+      })
+
+    })
+
+    describe('bls_multikey_to_public_key()', () => {
+
+      it('should decode a Multikey to the public key', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const { public_key_multibase } = wrapFFI(bbs.bls_public_key_to_multikey, { public_key: blsKey.public_key })
+
+        expect(wrapFFI(bbs.bls_multikey_to_public_key, { public_key_multibase })).toEqual({ key_type: 'g2', public_key: blsKey.public_key })
+      })
+
+      it('should fail for a Multikey of another key type', () => {
+        const result = wrapFFI(bbs.bls_multikey_to_public_key, { public_key_multibase: 'z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK' })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_public_key_to_did_key()', () => {
+
+      it('should create a did:key and key ID for a G2 public key', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const { public_key_multibase } = wrapFFI(bbs.bls_public_key_to_multikey, { public_key: blsKey.public_key })
+
+        const { did, key_id } = wrapFFI(bbs.bls_public_key_to_did_key, { public_key: blsKey.public_key })
+
+        expect(did).toBe(`did:key:${public_key_multibase}`)
+        expect(key_id).toBe(`${did}#${public_key_multibase}`)
+      })
+
+      it('should fail for a G1 public key', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g1_key, { seed })
+
+        const result = wrapFFI(bbs.bls_public_key_to_did_key, { public_key: blsKey.public_key })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bls_resolve_did_key()', () => {
+      let blsKey, didKey
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        didKey = wrapFFI(bbs.bls_public_key_to_did_key, { public_key: blsKey.public_key })
+      })
+
+      it('should resolve a did:key or key ID to its public key', () => {
+        expect(wrapFFI(bbs.bls_resolve_did_key, { did: didKey.did }).public_key).toBe(blsKey.public_key)
+        expect(wrapFFI(bbs.bls_resolve_did_key, { did: didKey.key_id }).public_key).toBe(blsKey.public_key)
+      })
+
+      it('should accept a did:key as "public_key" in contexts', () => {
+        const { signature } = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: didKey.did, messages })
+        const { verified } = wrapFFI(bbs.bls_verify, { public_key: didKey.key_id, signature, messages })
+
+        expect(verified).toBe(true)
+      })
+
+      it('should fail where the key ID fragment does not match the did:key', () => {
+        const result = wrapFFI(bbs.bls_resolve_did_key, { did: `${didKey.did}#zUC7` })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('proof envelope', () => {
+      let blsKey, signature
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        signature = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages })
+      })
+
+      it('should prefix proofs with the envelope magic, version and ciphersuite', () => {
+        const { proof } = wrapFFI(bbs.bls_create_proof, { signature: signature.signature, public_key: blsKey.public_key, messages, revealed: [ 1 ], nonce })
+
+        expect([ ...Buffer.from(proof, 'base64').subarray(0, 6) ]).toEqual([ ...Buffer.from('BBSP'), 2, 1 ])
+      })
+
+      it('should verify a legacy proof without an envelope', () => {
+        const { proof } = wrapFFI(bbs.bls_create_proof, { signature: signature.signature, public_key: blsKey.public_key, messages, revealed: [ 1 ], nonce })
+        // with fewer than 8 messages, only the message count differs from the legacy layout
+        const legacyProof = Buffer.concat([ Buffer.from([ 0, messages.length ]), Buffer.from(proof, 'base64').subarray(7) ]).toString('base64')
+
+        const { verified } = wrapFFI(bbs.bls_verify_proof, { proof: legacyProof, public_key: blsKey.public_key, messages: [ messages[1] ], nonce })
+
+        expect(verified).toBe(true)
+      })
+
+      it('should NOT verify a legacy proof revealing an index beyond the message count', () => {
+        const { proof } = wrapFFI(bbs.bls_create_proof, { signature: signature.signature, public_key: blsKey.public_key, messages, revealed: [ 1 ], nonce })
+        const legacyProof = Buffer.concat([ Buffer.from([ 0, messages.length ]), Buffer.from(proof, 'base64').subarray(7) ])
+        legacyProof[2] |= 0x80
+
+        const result = wrapFFI(bbs.bls_verify_proof, { proof: legacyProof.toString('base64'), public_key: blsKey.public_key, messages: [ messages[1] ], nonce })
+
+        expect(result).toMatchObject({ verified: false, reason: 'malformed_proof' })
+      })
+
+      it('should fail to create a proof revealing an index beyond the message count', () => {
+        const result = wrapFFI(bbs.bls_create_proof, { signature: signature.signature, public_key: blsKey.public_key, messages, revealed: [ messages.length ], nonce })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+      it('should NOT verify an unsupported proof version', () => {
+        const { proof } = wrapFFI(bbs.bls_create_proof, { signature: signature.signature, public_key: blsKey.public_key, messages, revealed: [ 1 ], nonce })
+        const proofBytes = Buffer.from(proof, 'base64')
+        proofBytes[4] = 0xff
+
+        const result = wrapFFI(bbs.bls_verify_proof, { proof: proofBytes.toString('base64'), public_key: blsKey.public_key, messages: [ messages[1] ], nonce })
+
+        expect(result).toMatchObject({ verified: false, reason: 'malformed_proof' })
+      })
+
+    })
+
+    describe('bls_create_presentation()', () => {
+      const presentationNonce = crypto.randomBytes(32).toString('base64')
+      let blsKey, signature
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        signature = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages })
+      })
+
+      it('should bundle the proof with its revealed messages and nonce', () => {
+        const { presentation } = wrapFFI(bbs.bls_create_presentation, { signature: signature.signature, public_key: blsKey.public_key, messages, revealed: [ 2, 0 ], nonce: presentationNonce })
+
+        expect(presentation.version).toBe(1)
+        expect(typeof presentation.proof).toBe('string')
+        expect(presentation.revealed_messages).toEqual({ 0: messages[0], 2: messages[2] })
+        expect(presentation.nonce).toBe(presentationNonce)
+        expect(presentation.key_id).toBeUndefined()
+      })
+
+      it('should identify a did:key public key by its key ID', () => {
+        const { did, key_id } = wrapFFI(bbs.bls_public_key_to_did_key, { public_key: blsKey.public_key })
+
+        const { presentation } = wrapFFI(bbs.bls_create_presentation, { signature: signature.signature, public_key: did, messages, revealed: [ 1 ], nonce: presentationNonce })
+
+        expect(presentation.key_id).toBe(key_id)
+      })
+
+    })
+
+    describe('bls_verify_presentation()', () => {
+      const presentationNonce = crypto.randomBytes(32).toString('base64')
+      let blsKey, presentation
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const { signature } = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages })
+        presentation = wrapFFI(bbs.bls_create_presentation, { signature, public_key: blsKey.public_key, messages, revealed: [ 0, 2 ], nonce: presentationNonce }).presentation
+      })
+
+      it('should verify a presentation and return its revealed messages', () => {
+        const result = wrapFFI(bbs.bls_verify_presentation, { presentation, public_key: blsKey.public_key, nonce: presentationNonce })
+
+        expect(result.verified).toBe(true)
+        expect(result.revealed_messages).toEqual({ 0: messages[0], 2: messages[2] })
+      })
+
+      it('should verify a presentation given as a JSON string', () => {
+        const { verified } = wrapFFI(bbs.bls_verify_presentation, { presentation: JSON.stringify(presentation), public_key: blsKey.public_key })
+
+        expect(verified).toBe(true)
+      })
+
+      it('should NOT verify a presentation with a substituted message', () => {
+        const tampered = { ...presentation, revealed_messages: { ...presentation.revealed_messages, 2: messages[1] } }
+
+        const result = wrapFFI(bbs.bls_verify_presentation, { presentation: tampered, public_key: blsKey.public_key })
+
+        expect(result).toMatchObject({ verified: false, reason: 'challenge_mismatch' })
+        expect(result.revealed_messages).toBeUndefined()
+      })
+
+      it('should NOT verify where revealed message indices differ from the proof', () => {
+        const tampered = { ...presentation, revealed_messages: { ...presentation.revealed_messages, 1: messages[1] } }
+
+        const result = wrapFFI(bbs.bls_verify_presentation, { presentation: tampered, public_key: blsKey.public_key })
+
+        expect(result).toMatchObject({ verified: false, reason: 'revealed_mismatch' })
+        expect(result.revealed_messages).toBeUndefined()
+      })
+
+      it('should NOT verify where the nonce differs from the expected nonce', () => {
+        const result = wrapFFI(bbs.bls_verify_presentation, { presentation, public_key: blsKey.public_key, nonce: crypto.randomBytes(32).toString('base64') })
+
+        expect(result).toMatchObject({ verified: false, reason: 'nonce_mismatch' })
+        expect(result.revealed_messages).toBeUndefined()
+      })
+
+    })
+
+    describe('verify proof result', () => {
+      const proofNonce = crypto.randomBytes(32).toString('base64')
+      let blsKey, bbsPublicKey, proof
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        bbsPublicKey = wrapFFI(bbs.bls_public_key_to_bbs_key, { public_key: blsKey.public_key, message_count: messages.length })
+        const { signature } = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages })
+        proof = wrapFFI(bbs.bls_create_proof, { signature, public_key: blsKey.public_key, messages, revealed: [ 0, 2 ], nonce: proofNonce }).proof
+      })
+
+      it('should include the revealed indices, message count and nonce', () => {
+        const result = wrapFFI(bbs.bls_verify_proof, { proof, public_key: blsKey.public_key, messages: [ messages[0], messages[2] ], nonce: proofNonce })
+
+        expect(result).toEqual({ verified: true, version: 2, message_count: messages.length, revealed: [ 0, 2 ], nonce: proofNonce })
+      })
+
+      it('should give the reason "malformed_proof"', () => {
+        const result = wrapFFI(bbs.bls_verify_proof, { proof: Buffer.from('BBSP').toString('base64'), public_key: blsKey.public_key, messages, nonce: proofNonce })
+
+        expect(result).toMatchObject({ verified: false, reason: 'malformed_proof' })
+      })
+
+      it('should give the reason "message_count_mismatch"', () => {
+        const result = wrapFFI(bbs.bls_verify_proof, { proof, public_key: blsKey.public_key, messages: [ messages[0] ], nonce: proofNonce })
+
+        expect(result).toMatchObject({ verified: false, reason: 'message_count_mismatch', revealed: [ 0, 2 ] })
+      })
+
+      it('should give the reason "key_message_count_mismatch"', () => {
+        const otherPublicKey = wrapFFI(bbs.bls_public_key_to_bbs_key, { public_key: blsKey.public_key, message_count: messages.length + 1 })
+
+        const result = wrapFFI(bbs.bbs_verify_proof, { proof, public_key: otherPublicKey.public_key, messages: [ messages[0], messages[2] ], nonce: proofNonce })
+
+        expect(result).toMatchObject({ verified: false, reason: 'key_message_count_mismatch', message_count: messages.length })
+      })
+
+      it('should give the reason "challenge_mismatch"', () => {
+        const result = wrapFFI(bbs.bbs_verify_proof, { proof, public_key: bbsPublicKey.public_key, messages: [ messages[0], messages[1] ], nonce: proofNonce })
+
+        expect(result).toMatchObject({ verified: false, reason: 'challenge_mismatch' })
+        expect(typeof result.detail).toBe('string')
+      })
+
+    })
+
+    describe('named claims', () => {
+      const schema = [ 'name', 'age', 'email' ]
+      const claims = { name: Buffer.from('alice').toString('base64'), age: Buffer.from('30').toString('base64'), email: Buffer.from('alice@example.com').toString('base64') }
+      const proofNonce = crypto.randomBytes(32).toString('base64')
+      let blsKey, signature
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        signature = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, schema, claims }).signature
+      })
+
+      it('should hash the schema as the first of its messages', () => {
+        const result = wrapFFI(bbs.bbs_schema_hash, { schema })
+
+        expect(result.message_count).toBe(schema.length + 1)
+        expect(Buffer.from(result.schema_hash, 'base64').length).toBe(32)
+        expect(wrapFFI(bbs.bbs_schema_hash, { schema: [ 'age', 'name', 'email' ] }).schema_hash).not.toBe(result.schema_hash)
+      })
+
+      it('should sign and verify claims by name', () => {
+        const result = wrapFFI(bbs.bls_verify, { public_key: blsKey.public_key, signature, schema, claims })
+
+        expect(result.verified).toBe(true)
+      })
+
+      it('should not verify with a reordered schema', () => {
+        const result = wrapFFI(bbs.bls_verify, { public_key: blsKey.public_key, signature, schema: [ 'age', 'name', 'email' ], claims })
+
+        expect(result.verified).toBe(false)
+      })
+
+      it('should reveal claims by name', () => {
+        const { proof } = wrapFFI(bbs.bls_create_proof, { signature, public_key: blsKey.public_key, schema, claims, reveal: [ 'email' ], nonce: proofNonce })
+
+        const result = wrapFFI(bbs.bls_verify_proof, { proof, public_key: blsKey.public_key, schema, claims: { email: claims.email }, nonce: proofNonce })
+
+        expect(result).toMatchObject({ verified: true, revealed: [ 0, 3 ] })
+      })
+
+      it('should name the claims revealed by a presentation', () => {
+        const { presentation } = wrapFFI(bbs.bls_create_presentation, { signature, public_key: blsKey.public_key, schema, claims, reveal: [ 'name', 'email' ], nonce: proofNonce })
+
+        const result = wrapFFI(bbs.bls_verify_presentation, { presentation, public_key: blsKey.public_key, schema })
+
+        expect(result.verified).toBe(true)
+        expect(result.claims).toEqual({ name: claims.name, email: claims.email })
+      })
+
+      it('should fail to sign where a claim is missing', () => {
+        const result = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, schema, claims: { name: claims.name } })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+      it('should fail to sign a claim not in the schema', () => {
+        const result = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, schema, claims: { ...claims, phone: claims.age } })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+      it('should blind sign claims by name', () => {
+        const commitment = wrapFFI(bbs.bls_blind_signature_commitment, { public_key: blsKey.public_key, schema, claims: { email: claims.email }, blinded: [ 'email' ], nonce: proofNonce })
+        const { blind_signature } = wrapFFI(bbs.bls_blind_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, schema, claims: { name: claims.name, age: claims.age }, known: [ 'name', 'age' ], commitment: commitment.commitment })
+        const unblinded = wrapFFI(bbs.bbs_unblind_signature, { blind_signature, blinding_factor: commitment.blinding_factor })
+
+        const result = wrapFFI(bbs.bls_verify, { public_key: blsKey.public_key, signature: unblinded.signature, schema, claims })
+
+        expect(result.verified).toBe(true)
+      })
+
+    })
+
+    describe('JSON documents', () => {
+      const document = { name: 'Alice', address: { city: 'Paris', country: 'FR' }, degrees: [ { title: 'BSc' }, { title: 'MSc' } ] }
+      const proofNonce = crypto.randomBytes(32).toString('base64')
+      let blsKey, signature
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        signature = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, document }).signature
+      })
+
+      it('should sign and verify a whole document', () => {
+        const result = wrapFFI(bbs.bls_verify, { public_key: blsKey.public_key, signature, document })
+
+        expect(result.verified).toBe(true)
+      })
+
+      it('should not verify a changed document', () => {
+        const result = wrapFFI(bbs.bls_verify, { public_key: blsKey.public_key, signature, document: { ...document, name: 'Bob' } })
+
+        expect(result.verified).toBe(false)
+      })
+
+      it('should rebuild the document disclosed by JSON Pointers', () => {
+        const { presentation } = wrapFFI(bbs.bls_create_presentation, { signature, public_key: blsKey.public_key, document, reveal: [ '/address/city', '/degrees/1' ], nonce: proofNonce })
+
+        const result = wrapFFI(bbs.bls_verify_presentation, { presentation, public_key: blsKey.public_key, nonce: proofNonce })
+
+        expect(result.verified).toBe(true)
+        expect(result.document).toEqual({ address: { city: 'Paris' }, degrees: [ null, { title: 'MSc' } ] })
+      })
+
+      it('should fail to reveal a JSON Pointer not in the document', () => {
+        const result = wrapFFI(bbs.bls_create_proof, { signature, public_key: blsKey.public_key, document, reveal: [ '/email' ], nonce: proofNonce })
+
+        expect(result.error.name).toBe('RustError')
+      })
+
+    })
+
+    describe('bbs-2023 over N-Quads', () => {
+      const credential = [
+        '_:credential <https://www.w3.org/2018/credentials#issuer> <did:example:issuer> .',
+        '_:credential <https://www.w3.org/2018/credentials#credentialSubject> _:subject .',
+        '_:subject <http://schema.org/name> "Alice" .',
+        '_:subject <http://schema.org/birthDate> "1990-01-01" .',
+        ''
+      ].join('\n')
+      const proofConfig = '_:proof <https://w3id.org/security#cryptosuite> "bbs-2023" .\n'
+      const presentationHeader = crypto.randomBytes(32).toString('base64')
+      let blsKey, canonical
+
+      const statementIndex = (needle) => canonical.split('\n').findIndex((line) => line.includes(needle))
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        canonical = wrapFFI(bbs.rdf_canonize, { document: credential }).document
+      })
+
+      it('should sign and verify with IETF BBS', () => {
+        const messages = [ Buffer.from('first').toString('base64'), Buffer.from('second').toString('base64') ]
+        const { signature } = wrapFFI(bbs.bbs_ietf_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages })
+
+        const result = wrapFFI(bbs.bbs_ietf_verify, { public_key: blsKey.public_key, signature, messages })
+
+        expect(result.verified).toBe(true)
+      })
+
+      it('should verify a derived proof over the revealed statements', () => {
+        const { proof_value } = wrapFFI(bbs.bbs_2023_create_base_proof, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, document: credential, proof_config: proofConfig, mandatory_indexes: [ statementIndex('issuer') ] })
+        const derived = wrapFFI(bbs.bbs_2023_create_derived_proof, { document: credential, proof_value, selective_indexes: [ statementIndex('name') ], presentation_header: presentationHeader })
+
+        const result = wrapFFI(bbs.bbs_2023_verify_derived_proof, { public_key: blsKey.public_key, document: derived.document, proof_value: derived.proof_value, proof_config: proofConfig })
+
+        expect(result.verified).toBe(true)
+        expect(derived.document).not.toContain('birthDate')
+      })
+
+    })
+
+    describe('JSON Web Proofs', () => {
+      const payloads = [ 'Alice', '1990-01-01', 'GB' ].map((payload) => Buffer.from(payload).toString('base64'))
+      const nonce = crypto.randomBytes(16).toString('hex')
+      let blsKey, issued
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        issued = wrapFFI(bbs.jwp_issue, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, header: { kid: 'issuer-key' }, payloads }).jwp
+      })
+
+      it('should verify an issued JWP', () => {
+        const result = wrapFFI(bbs.jwp_verify, { public_key: blsKey.public_key, jwp: issued })
+
+        expect(result.verified).toBe(true)
+        expect(result.header).toEqual({ alg: 'BBS', kid: 'issuer-key' })
+      })
+
+      it('should verify a presented JWP bound to a nonce', () => {
+        const { jwp } = wrapFFI(bbs.jwp_present, { public_key: blsKey.public_key, jwp: issued, revealed: [ 0 ], nonce, serialization: 'json' })
+
+        const result = wrapFFI(bbs.jwp_verify, { public_key: blsKey.public_key, jwp, nonce })
+
+        expect(result.verified).toBe(true)
+        expect(result.payloads).toEqual([ payloads[0], null, null ])
+      })
+
+    })
+
+    describe('CBOR and COSE', () => {
+      const coseMessages = [ 'Alice', '1990-01-01', 'GB' ].map((message) => Buffer.from(message).toString('base64'))
+      let blsKey, credential
+
+      beforeAll(() => {
+        blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const { signature } = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages: coseMessages })
+        credential = wrapFFI(bbs.bbs_cose_credential, { signature, messages: coseMessages }).credential
+      })
+
+      it('should return a CBOR map from call_cbor', () => {
+        const result = Buffer.from(bbs.call_cbor('bls_public_key_to_bbs_key', objectToArrayBuffer({ public_key: blsKey.public_key, message_count: 3 })))
+
+        expect(result[0] & 0xe0).toBe(0xa0)
+      })
+
+      it('should verify a COSE presentation of a COSE credential', () => {
+        const { presentation } = wrapFFI(bbs.bls_create_presentation, { public_key: blsKey.public_key, credential, revealed: [ 2 ], nonce: seed })
+        const envelope = wrapFFI(bbs.bbs_cose_presentation, { presentation }).presentation
+
+        const result = wrapFFI(bbs.bls_verify_presentation, { public_key: blsKey.public_key, presentation: envelope, nonce: seed })
+
+        expect(result.verified).toBe(true)
+        expect(result.revealed_messages).toEqual({ 2: coseMessages[2] })
+      })
+
+    })
+
+    describe('Context encodings', () => {
+
+      it('should sign and verify with hex bytes', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed: Buffer.from(seed, 'base64').toString('hex'), encoding: 'hex' })
+        const hexMessages = [ 'Alice', 'GB' ].map((message) => Buffer.from(message).toString('hex'))
+        const { signature } = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages: hexMessages, encoding: 'hex' })
+
+        const result = wrapFFI(bbs.bls_verify, { public_key: blsKey.public_key, signature, messages: hexMessages, encoding: 'hex' })
+
+        expect(signature).toMatch(/^[0-9a-f]+$/)
+        expect(result.verified).toBe(true)
+      })
+
+      it('should return an error for malformed bytes', () => {
+        const result = wrapFFI(bbs.bls_generate_g2_key, { seed: 'not hex', encoding: 'hex' })
+
+        expect(result.error.detail).toContain("Failed decoding hex for: 'seed'")
+      })
+
+    })
+
+    describe('Context checks', () => {
+
+      it('should return an error for an unknown property', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, {})
+        const result = wrapFFI(bbs.bls_secret_key_to_bbs_key, { secret_key: blsKey.secret_key, message_count: 1, mesage_count: 1 })
+
+        expect(result.error.detail).toContain("Unknown property: 'mesage_count'")
+      })
+
+      it('should return an error for a negative index', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, {})
+        const messages = [ 'Alice', 'GB' ].map((message) => Buffer.from(message).toString('base64'))
+        const { signature } = wrapFFI(bbs.bls_sign, { secret_key: blsKey.secret_key, public_key: blsKey.public_key, messages })
+
+        const result = wrapFFI(bbs.bls_create_proof, { public_key: blsKey.public_key, signature, messages, revealed: [ -1 ], nonce: seed })
+
+        expect(result.error.detail).toContain("Invalid 'revealed[0]'")
+      })
+
+    })
+
+    describe('JSON Schemas', () => {
+
+      it('should get the request and response schemas of an operation', () => {
+        const schemas = JSON.parse(bbs.bbs_get_schema('bls_sign'))
+
+        expect(schemas.operation).toBe('bls_sign')
+        expect(schemas.request.additionalProperties).toBe(false)
+        expect(Object.keys(schemas.request.properties)).toContain('messages')
+        expect(schemas.response.required).toEqual([ 'signature' ])
+      })
+
+      it('should return an error for an unknown operation', () => {
+        const result = JSON.parse(bbs.bbs_get_schema('bls_unknown'))
+
+        expect(result.error.detail).toContain('Unknown operation: bls_unknown')
+      })
+
+    })
+
+    describe('Library info', () => {
+
+      it('should report the version, formats and limits', () => {
+        const info = JSON.parse(bbs.bbs_library_info())
+
+        expect(info.version).toMatch(/^\d+\.\d+\.\d+/)
+        expect(info.features).toContain('node')
+        expect(info.formats.proof.read).toContain(info.formats.proof.write)
+        expect(info.limits.public_key_cache_capacity).toBeGreaterThan(0)
+      })
+
+    })
+
+    describe('Limits', () => {
+
+      afterEach(() => {
+        wrapFFI(bbs.bbs_set_limits, { max_message_count: 1024 })
+      })
+
+      it('should return a limit_exceeded code for too many messages', () => {
+        const blsKey = wrapFFI(bbs.bls_generate_g2_key, { seed })
+        const limits = wrapFFI(bbs.bbs_set_limits, { max_message_count: 2 })
+        expect(limits.max_message_count).toBe(2)
+
+        const result = wrapFFI(bbs.bls_sign, { ...blsKey, messages })
+
+        expect(result.error.name).toBe('RustError')
+        expect(result.error.code).toBe('limit_exceeded')
+        expect(result.error.detail).toContain("'messages' count is 3")
+      })
+
+      it('should reject a limit of zero', () => {
+        const result = wrapFFI(bbs.bbs_set_limits, { max_batch_size: 0 })
+
+        expect(result.error.code).toBeUndefined()
+        expect(result.error.detail).toContain("Invalid 'max_batch_size'")
+      })
+
+    })
+
   })
 
 })
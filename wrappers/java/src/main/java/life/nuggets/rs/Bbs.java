@@ -24,10 +24,28 @@ public class Bbs {
   private static native String bls_public_key_to_bbs_key(byte[] context);
   private static native String bbs_sign(byte[] context);
   private static native String bls_sign(byte[] context);
+  private static native String bbs_ietf_sign(byte[] context);
+  private static native String bbs_ietf_verify(byte[] context);
+  private static native String bbs_ietf_create_proof(byte[] context);
+  private static native String bbs_ietf_verify_proof(byte[] context);
+  private static native String rdf_canonize(byte[] context);
+  private static native String bbs_2023_create_base_proof(byte[] context);
+  private static native String bbs_2023_create_derived_proof(byte[] context);
+  private static native String bbs_2023_verify_derived_proof(byte[] context);
+  private static native String jwp_issue(byte[] context);
+  private static native String jwp_present(byte[] context);
+  private static native String jwp_verify(byte[] context);
   private static native String bbs_create_proof(byte[] context);
   private static native String bls_create_proof(byte[] context);
   private static native String bbs_verify_proof(byte[] context);
   private static native String bls_verify_proof(byte[] context);
+  private static native String bbs_create_presentation(byte[] context);
+  private static native String bls_create_presentation(byte[] context);
+  private static native String bbs_verify_presentation(byte[] context);
+  private static native String bls_verify_presentation(byte[] context);
+  private static native String bbs_cose_credential(byte[] context);
+  private static native String bbs_cose_presentation(byte[] context);
+  private static native String bbs_schema_hash(byte[] context);
   private static native String bbs_blind_signature_commitment(byte[] context);
   private static native String bls_blind_signature_commitment(byte[] context);
   private static native String bbs_verify_blind_signature_proof(byte[] context);
@@ -37,6 +55,24 @@ public class Bbs {
   private static native String bbs_get_unblinded_signature(byte[] context);
   private static native String bbs_verify(byte[] context);
   private static native String bls_verify(byte[] context);
+  private static native String bls_public_key_cache_warm(byte[] context);
+  private static native int bls_public_key_cache_clear();
+  private static native String bls_keystore_import(byte[] context);
+  private static native String bls_keystore_generate(byte[] context);
+  private static native String bls_keystore_public_key(byte[] context);
+  private static native String bls_keystore_destroy(byte[] context);
+  private static native String bls_export_encrypted_key(byte[] context);
+  private static native String bls_import_encrypted_key(byte[] context);
+  private static native String bls_key_to_jwk(byte[] context);
+  private static native String bls_jwk_to_key(byte[] context);
+  private static native String bls_public_key_to_multikey(byte[] context);
+  private static native String bls_multikey_to_public_key(byte[] context);
+  private static native String bls_public_key_to_did_key(byte[] context);
+  private static native String bls_resolve_did_key(byte[] context);
+  private static native String bbs_set_limits(byte[] context);
+  private static native byte[] call_cbor(String operation, byte[] context);
+  private static native String bbs_get_schema(String operation);
+  private static native String bbs_library_info();
 
   public static void main(String[] args) {
 
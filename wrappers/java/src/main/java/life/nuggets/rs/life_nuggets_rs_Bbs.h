@@ -71,6 +71,94 @@ JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1sign
 JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1sign
   (JNIEnv *, jclass, jbyteArray);
 
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_ietf_sign
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1ietf_1sign
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_ietf_verify
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1ietf_1verify
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_ietf_create_proof
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1ietf_1create_1proof
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_ietf_verify_proof
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1ietf_1verify_1proof
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    rdf_canonize
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_rdf_1canonize
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_2023_create_base_proof
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_12023_1create_1base_1proof
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_2023_create_derived_proof
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_12023_1create_1derived_1proof
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_2023_verify_derived_proof
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_12023_1verify_1derived_1proof
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    jwp_issue
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_jwp_1issue
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    jwp_present
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_jwp_1present
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    jwp_verify
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_jwp_1verify
+  (JNIEnv *, jclass, jbyteArray);
+
 /*
  * Class:     life_nuggets_rs_Bbs
  * Method:    bbs_create_proof
@@ -103,6 +191,62 @@ JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1verify_1proof
 JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1verify_1proof
   (JNIEnv *, jclass, jbyteArray);
 
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_create_presentation
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1create_1presentation
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_create_presentation
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1create_1presentation
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_verify_presentation
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1verify_1presentation
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_verify_presentation
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1verify_1presentation
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_cose_credential
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1cose_1credential
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_cose_presentation
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1cose_1presentation
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_schema_hash
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1schema_1hash
+  (JNIEnv *, jclass, jbyteArray);
+
 /*
  * Class:     life_nuggets_rs_Bbs
  * Method:    bbs_blind_signature_commitment
@@ -175,6 +319,150 @@ JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1verify
 JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1verify
   (JNIEnv *, jclass, jbyteArray);
 
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_public_key_cache_warm
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1public_1key_1cache_1warm
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_public_key_cache_clear
+ * Signature: ()I
+ */
+JNIEXPORT jint JNICALL Java_life_nuggets_rs_Bbs_bls_1public_1key_1cache_1clear
+  (JNIEnv *, jclass);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_keystore_import
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1keystore_1import
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_keystore_generate
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1keystore_1generate
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_keystore_public_key
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1keystore_1public_1key
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_keystore_destroy
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1keystore_1destroy
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_export_encrypted_key
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1export_1encrypted_1key
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_import_encrypted_key
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1import_1encrypted_1key
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_key_to_jwk
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1key_1to_1jwk
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_jwk_to_key
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1jwk_1to_1key
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_public_key_to_multikey
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1public_1key_1to_1multikey
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_multikey_to_public_key
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1multikey_1to_1public_1key
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_public_key_to_did_key
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1public_1key_1to_1did_1key
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bls_resolve_did_key
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bls_1resolve_1did_1key
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_set_limits
+ * Signature: ([B)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1set_1limits
+  (JNIEnv *, jclass, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    call_cbor
+ * Signature: (Ljava/lang/String;[B)[B
+ */
+JNIEXPORT jbyteArray JNICALL Java_life_nuggets_rs_Bbs_call_1cbor
+  (JNIEnv *, jclass, jstring, jbyteArray);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_get_schema
+ * Signature: (Ljava/lang/String;)Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1get_1schema
+  (JNIEnv *, jclass, jstring);
+
+/*
+ * Class:     life_nuggets_rs_Bbs
+ * Method:    bbs_library_info
+ * Signature: ()Ljava/lang/String;
+ */
+JNIEXPORT jstring JNICALL Java_life_nuggets_rs_Bbs_bbs_1library_1info
+  (JNIEnv *, jclass);
+
 #ifdef __cplusplus
 }
 #endif